@@ -1,27 +1,850 @@
-/// 스텁 모듈들 - CLI 도구
+//! 스텁 모듈들 - CLI 도구
 
 pub mod args {
     use std::path::PathBuf;
 
-    #[derive(Debug)]
+    pub const USAGE: &str = "\
+usage:
+  cli --input IN.wav --output OUT.wav [--mode chain|declick|denoise|dehum|rumble]
+      [--preset NAME | --preset-file FILE] [--drywet 0..1] [--report] [--verbose]
+      declick: [--sensitivity 0..1] [--max-click-ms MS]
+      denoise: [--reduction 1..4] [--floor-db DB] [--smoothing 0..0.95]
+               [--noise-start SEC --noise-end SEC]  (learn profile, else auto)
+      dehum: [--hum-hz auto|50|60|HZ] [--harmonics 1..8] [--hum-q Q]
+      rumble: [--cutoff HZ]
+  cli preset list [--dir DIR]
+  cli preset show <NAME|FILE> [--format toml|json] [--dir DIR]
+  cli preset validate <FILE>...
+  cli preset export <NAME|FILE> <OUT.toml|OUT.json> [--dir DIR]
+  cli preset import <FILE> [--name NAME] [--dir DIR] [--force]
+      --force      같은 이름의 사용자 프리셋 덮어쓰기";
+
+    /// 처리 모드
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum Mode {
+        /// 프리셋 체인 (AI 플러그인과 같은 PresetChain, 오프라인 High 품질)
+        #[default]
+        Chain,
+        /// 클릭/크래클 제거
+        Declick,
+        /// 히스/광대역 잡음 제거
+        Denoise,
+        /// 50/60Hz 험 제거
+        Dehum,
+        /// 서브소닉 럼블 제거
+        Rumble,
+    }
+
+    impl Mode {
+        fn parse(name: &str) -> Result<Self, String> {
+            match name {
+                "chain" => Ok(Mode::Chain),
+                "declick" => Ok(Mode::Declick),
+                "denoise" => Ok(Mode::Denoise),
+                "dehum" => Ok(Mode::Dehum),
+                "rumble" => Ok(Mode::Rumble),
+                other => Err(format!("unknown mode '{other}'")),
+            }
+        }
+    }
+
+    #[derive(Debug, Default)]
     pub struct Args {
         pub input: Option<PathBuf>,
         pub output: Option<PathBuf>,
+        pub mode: Mode,
         pub preset: Option<String>,
+        pub preset_file: Option<PathBuf>,
         pub drywet: Option<f32>,
+        pub sensitivity: Option<f32>,
+        pub max_click_ms: Option<f32>,
+        pub reduction: Option<f32>,
+        pub floor_db: Option<f32>,
+        pub smoothing: Option<f32>,
+        /// 잡음 프로파일 학습 구간 (초)
+        pub noise_region: Option<(f32, f32)>,
+        /// 험 기본 주파수 (None이면 자동 검출)
+        pub hum_hz: Option<f32>,
+        pub harmonics: Option<usize>,
+        pub hum_q: Option<f32>,
+        pub cutoff: Option<f32>,
         pub report: bool,
         pub verbose: bool,
     }
+
+    #[derive(Debug)]
+    pub enum Command {
+        Process(Args),
+        Preset(PresetCommand),
+        Help,
+    }
+
+    /// `cli preset ...` 하위 명령
+    /// dir: 사용자 프리셋 디렉터리 (없으면 PresetBank::user_dir())
+    #[derive(Debug)]
+    pub enum PresetCommand {
+        List { dir: Option<PathBuf> },
+        Show { preset: String, format: Option<String>, dir: Option<PathBuf> },
+        Validate { paths: Vec<PathBuf> },
+        Export { preset: String, output: PathBuf, dir: Option<PathBuf> },
+        /// force: 같은 슬러그의 사용자 프리셋 덮어쓰기
+        Import { path: PathBuf, name: Option<String>, dir: Option<PathBuf>, force: bool },
+    }
+
+    /// 명령줄 파싱 (프로그램 이름 제외)
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+        let mut args = args.into_iter().peekable();
+        match args.peek().map(String::as_str) {
+            Some("preset") => {
+                args.next();
+                parse_preset(args).map(Command::Preset)
+            }
+            Some("help" | "-h" | "--help") => Ok(Command::Help),
+            _ => parse_process(args).map(Command::Process),
+        }
+    }
+
+    fn parse_process(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+        let mut parsed = Args::default();
+        let (mut noise_start, mut noise_end) = (None, None);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => parsed.input = Some(value(&arg, &mut args)?.into()),
+                "--output" | "-o" => parsed.output = Some(value(&arg, &mut args)?.into()),
+                "--preset" | "-p" => parsed.preset = Some(value(&arg, &mut args)?),
+                "--preset-file" => parsed.preset_file = Some(value(&arg, &mut args)?.into()),
+                "--mode" | "-m" => parsed.mode = Mode::parse(&value(&arg, &mut args)?)?,
+                "--drywet" => parsed.drywet = Some(number(&arg, &mut args)?.clamp(0.0, 1.0)),
+                "--sensitivity" => {
+                    parsed.sensitivity = Some(number(&arg, &mut args)?.clamp(0.0, 1.0))
+                }
+                "--max-click-ms" => parsed.max_click_ms = Some(number(&arg, &mut args)?.max(0.0)),
+                "--reduction" => parsed.reduction = Some(number(&arg, &mut args)?),
+                "--floor-db" => parsed.floor_db = Some(number(&arg, &mut args)?),
+                "--smoothing" => parsed.smoothing = Some(number(&arg, &mut args)?),
+                "--noise-start" => noise_start = Some(number(&arg, &mut args)?.max(0.0)),
+                "--noise-end" => noise_end = Some(number(&arg, &mut args)?.max(0.0)),
+                "--hum-hz" => {
+                    let raw = value(&arg, &mut args)?;
+                    parsed.hum_hz = match raw.as_str() {
+                        "auto" => None,
+                        hz => Some(
+                            hz.parse()
+                                .map_err(|_| format!("{arg} expects auto or Hz, got '{hz}'"))?,
+                        ),
+                    }
+                }
+                "--harmonics" => {
+                    parsed.harmonics = Some(number(&arg, &mut args)?.max(1.0) as usize)
+                }
+                "--hum-q" => parsed.hum_q = Some(number(&arg, &mut args)?),
+                "--cutoff" => parsed.cutoff = Some(number(&arg, &mut args)?),
+                "--report" => parsed.report = true,
+                "--verbose" | "-v" => parsed.verbose = true,
+                other => return Err(format!("unknown argument '{other}'")),
+            }
+        }
+        parsed.noise_region = match (noise_start, noise_end) {
+            (None, None) => None,
+            (Some(start), Some(end)) if end > start => Some((start, end)),
+            (Some(_), Some(_)) => return Err("--noise-end must be after --noise-start".into()),
+            _ => return Err("--noise-start and --noise-end must be given together".into()),
+        };
+        Ok(parsed)
+    }
+
+    fn parse_preset(args: impl Iterator<Item = String>) -> Result<PresetCommand, String> {
+        let mut positional = Vec::new();
+        let mut dir = None;
+        let mut format = None;
+        let mut name = None;
+        let mut force = false;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--dir" => dir = Some(PathBuf::from(value(&arg, &mut args)?)),
+                "--format" => format = Some(value(&arg, &mut args)?),
+                "--name" => name = Some(value(&arg, &mut args)?),
+                "--force" => force = true,
+                flag if flag.starts_with("--") => return Err(format!("unknown argument '{flag}'")),
+                _ => positional.push(arg),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        let action = positional
+            .next()
+            .ok_or("preset: expected list|show|validate|export|import")?;
+        if force && action != "import" {
+            return Err(format!("preset {action}: --force only applies to import"));
+        }
+        let mut required = |what: &str| {
+            positional
+                .next()
+                .ok_or_else(|| format!("preset {action}: missing {what}"))
+        };
+
+        let command = match action.as_str() {
+            "list" => PresetCommand::List { dir },
+            "show" => PresetCommand::Show {
+                preset: required("preset name or file")?,
+                format,
+                dir,
+            },
+            "validate" => {
+                let first = required("preset file")?;
+                let mut paths = vec![PathBuf::from(first)];
+                paths.extend(positional.map(PathBuf::from));
+                return Ok(PresetCommand::Validate { paths });
+            }
+            "export" => PresetCommand::Export {
+                preset: required("preset name or file")?,
+                output: required("output file")?.into(),
+                dir,
+            },
+            "import" => PresetCommand::Import {
+                path: required("preset file")?.into(),
+                name,
+                dir,
+                force,
+            },
+            other => return Err(format!("unknown preset command '{other}'")),
+        };
+        Ok(command)
+    }
+
+    fn value(flag: &str, args: &mut impl Iterator<Item = String>) -> Result<String, String> {
+        args.next().ok_or_else(|| format!("{flag} expects a value"))
+    }
+
+    fn number(flag: &str, args: &mut impl Iterator<Item = String>) -> Result<f32, String> {
+        let raw = value(flag, args)?;
+        raw.parse::<f32>()
+            .map_err(|_| format!("{flag} expects a number, got '{raw}'"))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::path::Path;
+
+        fn parse_args(line: &str) -> Result<Command, String> {
+            parse(line.split_whitespace().map(String::from))
+        }
+
+        #[test]
+        fn test_parse_process_args() {
+            let Ok(Command::Process(args)) = parse_args(
+                "-i in.wav -o out.wav --mode denoise --drywet 2 --noise-start 0.5 --noise-end 1.5 --hum-hz 60 --verbose",
+            ) else {
+                panic!("expected process command");
+            };
+            assert_eq!(args.input, Some(PathBuf::from("in.wav")));
+            assert_eq!(args.output, Some(PathBuf::from("out.wav")));
+            assert_eq!(args.mode, Mode::Denoise);
+            assert_eq!(args.drywet, Some(1.0));
+            assert_eq!(args.noise_region, Some((0.5, 1.5)));
+            assert_eq!(args.hum_hz, Some(60.0));
+            assert!(args.verbose);
+
+            let Ok(Command::Process(args)) = parse_args("--hum-hz auto --harmonics 0") else {
+                panic!("expected process command");
+            };
+            assert_eq!(args.mode, Mode::Chain);
+            assert_eq!(args.hum_hz, None);
+            assert_eq!(args.harmonics, Some(1));
+        }
+
+        #[test]
+        fn test_parse_errors() {
+            assert!(matches!(parse_args("--help"), Ok(Command::Help)));
+            for line in [
+                "--mode reverb",
+                "--drywet loud",
+                "--input",
+                "--bogus",
+                "--noise-start 1",
+                "--noise-start 2 --noise-end 1",
+                "--hum-hz mains",
+            ] {
+                assert!(parse_args(line).is_err(), "{line}");
+            }
+        }
+
+        #[test]
+        fn test_parse_preset_commands() {
+            assert!(matches!(
+                parse_args("preset list --dir presets"),
+                Ok(Command::Preset(PresetCommand::List { dir: Some(dir) })) if dir == Path::new("presets")
+            ));
+            assert!(matches!(
+                parse_args("preset show warm --format json"),
+                Ok(Command::Preset(PresetCommand::Show { preset, format: Some(format), dir: None }))
+                    if preset == "warm" && format == "json"
+            ));
+            assert!(matches!(
+                parse_args("preset validate a.toml b.json"),
+                Ok(Command::Preset(PresetCommand::Validate { paths })) if paths.len() == 2
+            ));
+            assert!(matches!(
+                parse_args("preset export vinyl out.toml"),
+                Ok(Command::Preset(PresetCommand::Export { preset, output, .. }))
+                    if preset == "vinyl" && output == Path::new("out.toml")
+            ));
+            assert!(matches!(
+                parse_args("preset import dusty.json --name Dusty"),
+                Ok(Command::Preset(PresetCommand::Import { name: Some(name), force: false, .. }))
+                    if name == "Dusty"
+            ));
+            assert!(matches!(
+                parse_args("preset import dusty.json --force"),
+                Ok(Command::Preset(PresetCommand::Import { force: true, .. }))
+            ));
+
+            for line in ["preset", "preset show", "preset export warm", "preset rename", "preset list --force"] {
+                assert!(parse_args(line).is_err(), "{line}");
+            }
+        }
+    }
 }
 
 pub mod presets {
-    pub fn get_preset_drive(name: &str) -> f32 {
-        match name {
-            "vinyl" => 8.0,
-            "warm" => 5.0,
-            "clean" => 2.0,
-            _ => 4.0,
+    use crate::args::{Args, PresetCommand};
+    use dsp_core::preset_file::{PresetEntry, PresetSource};
+    use dsp_core::{ChainPreset, PresetBank, PresetError, PresetFile, PresetFormat};
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+
+    /// 내장 프리셋 조회 (알 수 없는 이름은 내장 프리셋 목록과 함께 에러)
+    pub fn get_preset(name: &str) -> Result<ChainPreset, PresetError> {
+        PresetBank::builtin().get(name).map(|entry| entry.chain)
+    }
+
+    pub fn get_preset_drive(name: &str) -> Result<f32, PresetError> {
+        get_preset(name).map(|preset| preset.drive_db)
+    }
+
+    /// 사용자 프리셋 파일 (.toml / .json) 로드
+    pub fn load_preset_file(path: &Path) -> Result<ChainPreset, PresetError> {
+        PresetFile::load(path).map(|file| file.chain)
+    }
+
+    /// --preset-file 이 있으면 파일, 없으면 --preset 이름 (내장 + 사용자 프리셋)
+    /// 알 수 없는 이름은 UnknownPreset (기본 프리셋으로 대체하지 않음)
+    pub fn resolve(args: &Args) -> Result<ChainPreset, PresetError> {
+        match (&args.preset_file, &args.preset) {
+            (Some(path), _) => load_preset_file(path),
+            (None, Some(name)) => {
+                let (bank, _) = PresetBank::with_user_presets();
+                bank.get(name).map(|entry| entry.chain)
+            }
+            (None, None) => Ok(ChainPreset::default()),
+        }
+    }
+
+    /// `cli preset ...` 실행
+    pub fn run(command: &PresetCommand, out: &mut impl Write) -> Result<(), Box<dyn std::error::Error>> {
+        match command {
+            PresetCommand::List { dir } => {
+                let (bank, errors) = load_bank(dir.as_deref());
+                for entry in bank.entries() {
+                    match &entry.source {
+                        PresetSource::Builtin => writeln!(out, "{:<24} builtin", entry.name)?,
+                        PresetSource::File(path) => {
+                            writeln!(out, "{:<24} {}", entry.name, path.display())?
+                        }
+                    }
+                }
+                for (path, err) in errors {
+                    writeln!(out, "warning: skipped {}: {err}", path.display())?;
+                }
+            }
+            PresetCommand::Show { preset, format, dir } => {
+                let file = find_preset(preset, dir.as_deref())?;
+                let format = match format.as_deref() {
+                    None | Some("toml") => PresetFormat::Toml,
+                    Some("json") => PresetFormat::Json,
+                    Some(other) => return Err(format!("unknown format '{other}'").into()),
+                };
+                writeln!(out, "{}", file.to_string(format)?.trim_end())?;
+            }
+            PresetCommand::Validate { paths } => {
+                let mut failed = 0;
+                for path in paths {
+                    match PresetFile::load_with_report(path) {
+                        Ok((file, report)) => {
+                            writeln!(out, "ok: {} ({})", path.display(), file.name)?;
+                            if let Some(version) = report.migrated_from {
+                                writeln!(out, "  migrated from schema v{version}")?;
+                            }
+                            for field in &report.clamped {
+                                writeln!(out, "  warning: '{field}' out of range, clamped")?;
+                            }
+                            for key in &report.unknown_keys {
+                                writeln!(out, "  warning: unknown key '{key}' ignored")?;
+                            }
+                        }
+                        Err(err) => {
+                            failed += 1;
+                            writeln!(out, "error: {}: {err}", path.display())?;
+                        }
+                    }
+                }
+                if failed > 0 {
+                    return Err(format!("{failed} preset file(s) failed validation").into());
+                }
+            }
+            PresetCommand::Export { preset, output, dir } => {
+                let file = find_preset(preset, dir.as_deref())?;
+                file.save(output)?;
+                writeln!(out, "exported '{}' to {}", file.name, output.display())?;
+            }
+            PresetCommand::Import {
+                path,
+                name,
+                dir,
+                force,
+            } => {
+                let mut file = PresetFile::load(path)?;
+                if let Some(name) = name {
+                    file.name = name.clone();
+                }
+                let dir = target_dir(dir.as_deref())?;
+                let installed = match PresetBank::install(&file, &dir, *force) {
+                    Err(PresetError::AlreadyExists(path)) => {
+                        let hint = "pass --force to overwrite";
+                        return Err(format!("{} already exists ({hint})", path.display()).into());
+                    }
+                    result => result?,
+                };
+                writeln!(out, "imported '{}' to {}", file.name, installed.display())?;
+            }
         }
+        Ok(())
+    }
+
+    fn target_dir(dir: Option<&Path>) -> Result<PathBuf, String> {
+        dir.map(Path::to_path_buf)
+            .or_else(PresetBank::user_dir)
+            .ok_or_else(|| "no preset directory: pass --dir or set RUSTROVER_PRESET_DIR".to_string())
+    }
+
+    fn load_bank(dir: Option<&Path>) -> (PresetBank, Vec<(PathBuf, PresetError)>) {
+        match dir {
+            Some(dir) => {
+                let mut bank = PresetBank::builtin();
+                let errors = bank.load_dir(dir);
+                (bank, errors)
+            }
+            None => PresetBank::with_user_presets(),
+        }
+    }
+
+    /// 파일 경로이면 로드, 아니면 뱅크에서 이름으로 조회
+    fn find_preset(preset: &str, dir: Option<&Path>) -> Result<PresetFile, Box<dyn std::error::Error>> {
+        let path = Path::new(preset);
+        if path.is_file() {
+            return Ok(PresetFile::load(path)?);
+        }
+        let (bank, _) = load_bank(dir);
+        let PresetEntry { name, chain, .. } = bank.get(preset)?;
+        Ok(PresetFile::new(name.clone(), *chain))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /// 테스트별 임시 프리셋 디렉터리 (기본 사용자 디렉터리는 건드리지 않음)
+        fn scratch_dir(name: &str) -> PathBuf {
+            let dir = std::env::temp_dir().join(format!("cli-presets-{name}-{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            dir
+        }
+
+        fn run_to_string(command: &PresetCommand) -> Result<String, String> {
+            let mut out = Vec::new();
+            run(command, &mut out).map_err(|err| err.to_string())?;
+            Ok(String::from_utf8(out).unwrap())
+        }
+
+        #[test]
+        fn test_preset_file_parsing() {
+            let dir = scratch_dir("parse");
+            let path = dir.join("hot.toml");
+            std::fs::write(&path, "version = 1\nname = \"Hot\"\ndrive_db = 9\n").unwrap();
+            let chain = load_preset_file(&path).unwrap();
+            assert_eq!(chain.drive_db, 9.0);
+            assert_eq!(chain.lowpass_hz, ChainPreset::default().lowpass_hz);
+
+            let args = Args {
+                preset_file: Some(path),
+                preset: Some("vinyl".into()),
+                ..Args::default()
+            };
+            assert_eq!(resolve(&args).unwrap().drive_db, 9.0);
+
+            std::fs::write(dir.join("broken.json"), "{ \"drive_db\": 1 }").unwrap();
+            assert!(load_preset_file(&dir.join("broken.json")).is_err());
+            assert!(load_preset_file(&dir.join("missing.toml")).is_err());
+            std::fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_unknown_preset_name_is_an_error() {
+            assert_eq!(get_preset("Vinyl").unwrap(), ChainPreset::vinyl());
+            assert_eq!(get_preset_drive("warm").unwrap(), ChainPreset::warm().drive_db);
+            let err = get_preset("vinly").unwrap_err().to_string();
+            assert_eq!(err, "unknown preset 'vinly' (known: vinyl, warm, clean)");
+            let args = Args {
+                preset: Some("vinly".into()),
+                ..Args::default()
+            };
+            assert!(matches!(resolve(&args), Err(PresetError::UnknownPreset { .. })));
+
+            // 사용자 프리셋 디렉터리의 이름도 목록에 포함
+            let dir = scratch_dir("unknown");
+            let dusty = PresetFile::new("Dusty", ChainPreset::vinyl());
+            PresetBank::install(&dusty, &dir, false).unwrap();
+            let command = PresetCommand::Show {
+                preset: "dustyy".into(),
+                format: None,
+                dir: Some(dir.clone()),
+            };
+            let err = run_to_string(&command).unwrap_err();
+            assert!(err.contains("unknown preset 'dustyy'") && err.contains("Dusty"), "{err}");
+            std::fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_list_and_show() {
+            let dir = scratch_dir("list");
+            let dusty = PresetFile::new("Dusty", ChainPreset::vinyl());
+            PresetBank::install(&dusty, &dir, false).unwrap();
+            std::fs::write(dir.join("bad.toml"), "name = ").unwrap();
+
+            let listing = run_to_string(&PresetCommand::List { dir: Some(dir.clone()) }).unwrap();
+            assert!(listing.lines().any(|line| line.starts_with("warm") && line.ends_with("builtin")));
+            assert!(listing.contains(&dir.join("dusty.toml").display().to_string()));
+            assert!(listing.contains("warning: skipped"));
+
+            let shown = run_to_string(&PresetCommand::Show {
+                preset: "dusty".into(),
+                format: Some("json".into()),
+                dir: Some(dir.clone()),
+            })
+            .unwrap();
+            let (file, _) = PresetFile::parse(&shown, PresetFormat::Json).unwrap();
+            assert_eq!(file, PresetFile::new("Dusty", ChainPreset::vinyl()));
+
+            for (preset, format) in [("dusty", Some("yaml")), ("nope", None)] {
+                let command = PresetCommand::Show {
+                    preset: preset.into(),
+                    format: format.map(String::from),
+                    dir: Some(dir.clone()),
+                };
+                assert!(run_to_string(&command).is_err(), "{preset}");
+            }
+            std::fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_validate() {
+            let dir = scratch_dir("validate");
+            let good = dir.join("good.toml");
+            std::fs::write(&good, "name = \"Good\"\nhardness = 4.0\nextra = 1\n").unwrap();
+            let report = run_to_string(&PresetCommand::Validate { paths: vec![good.clone()] }).unwrap();
+            assert!(report.contains("ok:"));
+            assert!(report.contains("'hardness' out of range"));
+            assert!(report.contains("unknown key 'extra'"));
+
+            let bad = dir.join("bad.json");
+            std::fs::write(&bad, "{}").unwrap();
+            let err = run_to_string(&PresetCommand::Validate { paths: vec![good, bad] }).unwrap_err();
+            assert!(err.contains("1 preset file(s) failed"), "{err}");
+            std::fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_export_then_import() {
+            let dir = scratch_dir("export");
+            let exported = dir.join("warm.json");
+            run_to_string(&PresetCommand::Export {
+                preset: "warm".into(),
+                output: exported.clone(),
+                dir: Some(dir.clone()),
+            })
+            .unwrap();
+            assert_eq!(load_preset_file(&exported).unwrap(), ChainPreset::warm());
+
+            let user_dir = dir.join("user");
+            let import = |force| PresetCommand::Import {
+                path: exported.clone(),
+                name: Some("My Warm".into()),
+                dir: Some(user_dir.clone()),
+                force,
+            };
+            let message = run_to_string(&import(false)).unwrap();
+            let installed = user_dir.join("my-warm.toml");
+            assert!(message.contains(&installed.display().to_string()), "{message}");
+
+            let mut bank = PresetBank::builtin();
+            assert!(bank.load_dir(&user_dir).is_empty());
+            let entry = bank.find("my-warm").unwrap();
+            assert_eq!(entry.name, "My Warm");
+            assert_eq!(entry.chain, ChainPreset::warm());
+            assert_eq!(entry.source, PresetSource::File(installed.clone()));
+
+            // 같은 슬러그: --force 없이는 기존 사용자 프리셋을 덮어쓰지 않음
+            let edited = PresetFile::new("My Warm", ChainPreset::vinyl());
+            edited.save(&installed).unwrap();
+            let err = run_to_string(&import(false)).unwrap_err();
+            assert!(err.contains("already exists"), "{err}");
+            assert_eq!(load_preset_file(&installed).unwrap(), ChainPreset::vinyl());
+            run_to_string(&import(true)).unwrap();
+            assert_eq!(load_preset_file(&installed).unwrap(), ChainPreset::warm());
+
+            let missing = PresetCommand::Import {
+                path: dir.join("missing.toml"),
+                name: None,
+                dir: Some(user_dir),
+                force: false,
+            };
+            assert!(run_to_string(&missing).is_err());
+            std::fs::remove_dir_all(dir).unwrap();
+        }
+    }
+}
+
+pub mod audio {
+    use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
+    use std::path::Path;
+
+    /// 디인터리브된 오디오 (채널별 버퍼)
+    pub struct AudioBuffer {
+        pub spec: WavSpec,
+        pub channels: Vec<Vec<f32>>,
+    }
+
+    impl AudioBuffer {
+        pub fn sample_rate(&self) -> f32 {
+            self.spec.sample_rate as f32
+        }
+    }
+
+    /// WAV 읽기 (정수 포맷은 -1.0..1.0 으로 정규화)
+    pub fn read_wav(path: &Path) -> Result<AudioBuffer, hound::Error> {
+        let mut reader = WavReader::open(path)?;
+        let spec = reader.spec();
+        let interleaved: Vec<f32> = match spec.sample_format {
+            SampleFormat::Float => reader.samples::<f32>().collect::<Result<_, _>>()?,
+            SampleFormat::Int => {
+                let scale = 1.0 / (1i64 << (spec.bits_per_sample - 1)) as f32;
+                reader
+                    .samples::<i32>()
+                    .map(|s| s.map(|v| v as f32 * scale))
+                    .collect::<Result<_, _>>()?
+            }
+        };
+
+        let count = spec.channels.max(1) as usize;
+        let mut channels = vec![Vec::with_capacity(interleaved.len() / count); count];
+        for frame in interleaved.chunks(count) {
+            for (channel, &sample) in channels.iter_mut().zip(frame) {
+                channel.push(sample);
+            }
+        }
+        Ok(AudioBuffer { spec, channels })
+    }
+
+    /// 입력과 같은 포맷으로 WAV 쓰기 (정수 포맷은 클리핑)
+    pub fn write_wav(path: &Path, audio: &AudioBuffer) -> Result<(), hound::Error> {
+        let mut writer = WavWriter::create(path, audio.spec)?;
+        let frames = audio.channels.first().map_or(0, Vec::len);
+        let max = (1i64 << (audio.spec.bits_per_sample - 1)) as f32;
+        for n in 0..frames {
+            for channel in &audio.channels {
+                match audio.spec.sample_format {
+                    SampleFormat::Float => writer.write_sample(channel[n])?,
+                    SampleFormat::Int => {
+                        let v = (channel[n] * max).round().clamp(-max, max - 1.0);
+                        writer.write_sample(v as i32)?
+                    }
+                }
+            }
+        }
+        writer.finalize()
+    }
+}
+
+pub mod process {
+    use crate::args::{Args, Mode};
+    use crate::{audio, presets, report};
+    use dsp_core::{
+        ChainPreset, ChainRamps, Declicker, HumRemover, NoiseProfileMode, NoiseReducer,
+        PresetChain, Processor, Quality, RumbleFilter,
+    };
+    use std::io::Write;
+
+    /// 체인 처리 블록 크기 (Velocity 판정 단위, 플러그인 버퍼에 해당)
+    const CHAIN_BLOCK: usize = 512;
+
+    /// 입력 WAV를 모드에 따라 처리해 출력 WAV로 저장
+    pub fn run(args: &Args, out: &mut impl Write) -> Result<(), Box<dyn std::error::Error>> {
+        let input = args.input.as_deref().ok_or("--input is required")?;
+        let output = args.output.as_deref().ok_or("--output is required")?;
+
+        let mut audio = audio::read_wav(input)?;
+        let sample_rate = audio.sample_rate();
+        if args.verbose {
+            writeln!(
+                out,
+                "{}: {} ch, {} Hz, {} frames, mode {:?}",
+                input.display(),
+                audio.channels.len(),
+                audio.spec.sample_rate,
+                audio.channels.first().map_or(0, Vec::len),
+                args.mode
+            )?;
+        }
+
+        match args.mode {
+            Mode::Chain => {
+                let preset = presets::resolve(args)?;
+                let preset = ChainPreset {
+                    dry_wet: args.drywet.unwrap_or(preset.dry_wet),
+                    ..preset
+                };
+                let ramps = ChainRamps::from_preset(&preset);
+                for channel in &mut audio.channels {
+                    let mut chain = PresetChain::new(sample_rate, &preset);
+                    chain.set_quality(Quality::High);
+                    run_blocks_with_latency(channel, chain.latency_samples(), |block| {
+                        chain.process_block(block, &ramps, None);
+                    });
+                }
+            }
+            Mode::Declick => {
+                let mut total = 0;
+                for channel in &mut audio.channels {
+                    let mut declicker = Declicker::new(
+                        sample_rate,
+                        args.sensitivity.unwrap_or(0.5),
+                        args.max_click_ms.unwrap_or(1.0),
+                    );
+                    run_with_latency(channel, declicker.latency_samples(), |x| declicker.process(x));
+                    total += declicker.clicks_repaired();
+                }
+                if args.verbose {
+                    writeln!(out, "repaired {total} click(s)")?;
+                }
+            }
+            Mode::Denoise => {
+                for channel in &mut audio.channels {
+                    let mut reducer = NoiseReducer::new(
+                        args.reduction.unwrap_or(2.0),
+                        args.floor_db.unwrap_or(-20.0),
+                        args.smoothing.unwrap_or(0.5),
+                    );
+                    if let Some((start, end)) = args.noise_region {
+                        let start = ((start * sample_rate) as usize).min(channel.len());
+                        let end = ((end * sample_rate) as usize).min(channel.len());
+                        reducer.set_profile_mode(NoiseProfileMode::Learn);
+                        for &x in &channel[start..end] {
+                            reducer.process(x);
+                        }
+                        reducer.set_profile_mode(NoiseProfileMode::Hold);
+                        reducer.reset();
+                        if reducer.learned_frames() == 0 {
+                            return Err("noise region is too short to learn a profile".into());
+                        }
+                    }
+                    run_with_latency(channel, reducer.latency_samples(), |x| reducer.process(x));
+                }
+                if args.verbose {
+                    match args.noise_region {
+                        Some((start, end)) => writeln!(out, "noise profile from {start}s-{end}s")?,
+                        None => writeln!(out, "noise profile: automatic tracking")?,
+                    }
+                }
+            }
+            Mode::Dehum => {
+                for (index, channel) in audio.channels.iter_mut().enumerate() {
+                    let mut remover = HumRemover::new(
+                        sample_rate,
+                        args.harmonics.unwrap_or(4),
+                        args.hum_q.unwrap_or(30.0),
+                    );
+                    remover.set_fundamental(args.hum_hz);
+                    if args.hum_hz.is_none() {
+                        // 오프라인: 전체를 먼저 분석해 처음부터 노치 적용
+                        for &x in channel.iter() {
+                            remover.process(x);
+                        }
+                        remover.reset();
+                    }
+                    match remover.fundamental() {
+                        Some(hz) => {
+                            for sample in channel.iter_mut() {
+                                *sample = remover.process(*sample);
+                            }
+                            if args.verbose {
+                                writeln!(out, "channel {index}: hum at {hz:.2} Hz")?;
+                            }
+                        }
+                        None if args.verbose => writeln!(out, "channel {index}: no hum detected")?,
+                        None => {}
+                    }
+                }
+            }
+            Mode::Rumble => {
+                for channel in &mut audio.channels {
+                    let mut filter = RumbleFilter::new(sample_rate, args.cutoff.unwrap_or(20.0));
+                    for sample in channel.iter_mut() {
+                        *sample = filter.process(*sample);
+                    }
+                }
+            }
+        }
+
+        audio::write_wav(output, &audio)?;
+        if args.report {
+            let mono: Vec<f32> = audio.channels.iter().flatten().copied().collect();
+            writeln!(out, "{}", report::generate_report(&mono))?;
+        }
+        Ok(())
+    }
+
+    /// 블록 단위 프로세서를 오프라인으로 실행하고 지연을 제거해 정렬
+    fn run_blocks_with_latency(
+        channel: &mut [f32],
+        latency: usize,
+        mut process: impl FnMut(&mut [f32]),
+    ) {
+        let mut padded: Vec<f32> = channel
+            .iter()
+            .copied()
+            .chain(core::iter::repeat_n(0.0, latency))
+            .collect();
+        for block in padded.chunks_mut(CHAIN_BLOCK) {
+            process(block);
+        }
+        channel.copy_from_slice(&padded[latency..]);
+    }
+
+    /// 지연이 있는 프로세서를 오프라인으로 실행하고 지연을 제거해 정렬
+    fn run_with_latency(channel: &mut [f32], latency: usize, mut process: impl FnMut(f32) -> f32) {
+        let tail = core::iter::repeat_n(0.0, latency);
+        let aligned: Vec<f32> = channel
+            .iter()
+            .copied()
+            .chain(tail)
+            .map(&mut process)
+            .skip(latency)
+            .collect();
+        channel.copy_from_slice(&aligned);
     }
 }
 
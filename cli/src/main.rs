@@ -1,3 +1,33 @@
-fn main() {
-    println!("CLI tool for audio DSP processing");
+use cli::args::{self, Command};
+use cli::{presets, process};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let command = match args::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{}", args::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match command {
+        Command::Help => println!("{}", args::USAGE),
+        Command::Process(args) if args.input.is_none() => {
+            println!("CLI tool for audio DSP processing\n\n{}", args::USAGE)
+        }
+        Command::Process(args) => {
+            if let Err(err) = process::run(&args, &mut std::io::stdout()) {
+                eprintln!("error: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Preset(preset_command) => {
+            if let Err(err) = presets::run(&preset_command, &mut std::io::stdout()) {
+                eprintln!("error: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
 }
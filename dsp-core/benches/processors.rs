@@ -0,0 +1,204 @@
+//! 프로세서별 블록 처리 벤치마크 (샘플레이트 x 블록 크기)
+//! cargo bench -p dsp-core --bench processors
+//!
+//! 벤치마크 ID는 `<프로세서>/<샘플레이트>/<블록 크기>` 형식:
+//! `cargo xtask bench-compare`가 이 형식으로 ns/sample과 실시간 배율을 계산한다.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use dsp_core::{
+    process_block, soft_clip, BiquadWrapper, ChainPreset, ChainRamps, Declicker, DelayLine,
+    DspParams, Fir, HumRemover, LevelMeter, Limiter, MagneticEQ, NoiseReducer, Oversampler,
+    ParallelFilterAdvanced, PresetChain, RIAAEQAdvanced, RumbleFilter, TapeChain,
+    VelocityAnalyzer,
+};
+use std::time::Duration;
+
+const SAMPLE_RATES: [u32; 3] = [44100, 48000, 96000];
+const BLOCK_SIZES: [usize; 3] = [64, 512, 2048];
+
+/// 음악 대용 신호: 정현파 두 개 + 결정적 잡음
+fn program(sample_rate: u32, len: usize) -> Vec<f32> {
+    let mut seed = 0x1234_5678u32;
+    (0..len)
+        .map(|n| {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let noise = (seed >> 8) as f32 / (1 << 24) as f32 - 0.5;
+            let t = n as f32 / sample_rate as f32;
+            0.4 * (2.0 * std::f32::consts::PI * 220.0 * t).sin()
+                + 0.2 * (2.0 * std::f32::consts::PI * 3300.0 * t).sin()
+                + 0.05 * noise
+        })
+        .collect()
+}
+
+/// 블록 단위 처리 벤치마크: 매 반복마다 같은 입력 블록을 output에 처리
+fn bench_blocks<S>(
+    c: &mut Criterion,
+    name: &str,
+    make: impl Fn(u32) -> S,
+    mut process: impl FnMut(&mut S, &[f32], &mut [f32]),
+) {
+    let mut group = c.benchmark_group(name);
+    group
+        .warm_up_time(Duration::from_millis(500))
+        .measurement_time(Duration::from_secs(1));
+    for sample_rate in SAMPLE_RATES {
+        for block in BLOCK_SIZES {
+            let input = program(sample_rate, block);
+            let mut output = vec![0.0; block];
+            let mut state = make(sample_rate);
+            group.throughput(Throughput::Elements(block as u64));
+            group.bench_function(
+                BenchmarkId::from_parameter(format!("{sample_rate}/{block}")),
+                |b| {
+                    b.iter(|| {
+                        process(&mut state, black_box(&input), &mut output);
+                        black_box(&output);
+                    })
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+/// 샘플 단위 프로세서용
+fn bench_samples<S>(
+    c: &mut Criterion,
+    name: &str,
+    make: impl Fn(u32) -> S,
+    mut process: impl FnMut(&mut S, f32) -> f32,
+) {
+    bench_blocks(c, name, make, |state, input, output| {
+        for (y, &x) in output.iter_mut().zip(input) {
+            *y = process(state, x);
+        }
+    });
+}
+
+fn bench_filters(c: &mut Criterion) {
+    bench_samples(
+        c,
+        "biquad_lowpass",
+        |sr| BiquadWrapper::<f32>::lowpass(8000.0 / sr as f32, 0.707),
+        |f, x| f.process(x),
+    );
+    bench_samples(c, "riaa_f32", RIAAEQAdvanced::<f32>::new, |f, x| {
+        f.process(x)
+    });
+    bench_samples(c, "riaa_f64", RIAAEQAdvanced::<f64>::new, |f, x| {
+        f.process(x as f64) as f32
+    });
+    bench_samples(
+        c,
+        "parallel_filter",
+        |_| ParallelFilterAdvanced::<f32>::new(0.5),
+        |f, x| f.process(x),
+    );
+    bench_samples(
+        c,
+        "fir_lowpass_127",
+        |sr| Fir::<127>::lowpass(8000.0 / sr as f32),
+        |f, x| f.process(x),
+    );
+    bench_samples(
+        c,
+        "delay_line",
+        |_| DelayLine::<64>::new(32),
+        |d, x| d.process(x),
+    );
+}
+
+fn bench_dynamics(c: &mut Criterion) {
+    bench_samples(
+        c,
+        "magnetic_eq",
+        |_| MagneticEQ::<f32>::new(0.5, 0.5),
+        |eq, x| eq.process(x),
+    );
+    bench_samples(
+        c,
+        "limiter",
+        |_| Limiter::<f32>::new(0.9, 0.1),
+        |l, x| l.process(x),
+    );
+    bench_samples(
+        c,
+        "oversampler_4x",
+        |_| Oversampler::new(4),
+        |o, x| o.process(x, soft_clip),
+    );
+    bench_blocks(
+        c,
+        "legacy_chain",
+        |sr| (sr as f32, DspParams::new(6.0)),
+        |(sr, params), input, output| process_block(input, output, *sr, params),
+    );
+    bench_blocks(
+        c,
+        "preset_chain",
+        |sr| PresetChain::new(sr as f32, &ChainPreset::vinyl()),
+        |chain, input, output| {
+            output.copy_from_slice(input);
+            let ramps = ChainRamps::from_preset(chain.preset());
+            chain.process_block(output, &ramps, None);
+        },
+    );
+    bench_samples(
+        c,
+        "tape_chain",
+        |sr| TapeChain::new(sr as f32),
+        |t, x| t.process(x),
+    );
+}
+
+fn bench_restoration(c: &mut Criterion) {
+    bench_samples(
+        c,
+        "declicker",
+        |sr| Declicker::new(sr as f32, 0.5, 1.0),
+        |d, x| d.process(x),
+    );
+    bench_samples(
+        c,
+        "noise_reducer",
+        |_| NoiseReducer::new(2.0, -20.0, 0.5),
+        |n, x| n.process(x),
+    );
+    bench_samples(
+        c,
+        "hum_remover",
+        |sr| HumRemover::new(sr as f32, 4, 30.0),
+        |h, x| h.process(x),
+    );
+    bench_samples(
+        c,
+        "rumble_filter",
+        |sr| RumbleFilter::new(sr as f32, 20.0),
+        |r, x| r.process(x),
+    );
+}
+
+fn bench_analysis(c: &mut Criterion) {
+    bench_blocks(
+        c,
+        "level_meter",
+        |sr| LevelMeter::new(sr as f32),
+        |m, input, _| m.measure([input]),
+    );
+    bench_blocks(
+        c,
+        "velocity_analyzer",
+        |_| (),
+        |_, input, output| output[0] = VelocityAnalyzer::calculate_velocity(input),
+    );
+}
+
+criterion_group!(
+    benches,
+    bench_filters,
+    bench_dynamics,
+    bench_restoration,
+    bench_analysis
+);
+criterion_main!(benches);
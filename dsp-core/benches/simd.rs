@@ -0,0 +1,99 @@
+//! 스칼라 경로 대비 SIMD 경로 벤치마크
+//! cargo bench -p dsp-core --bench simd
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dsp_core::{
+    peak_and_sum_squares, soft_clip, soft_clip_block, BiquadX4, MagneticEQ, RIAAEQAdvanced,
+};
+
+const BLOCK: usize = 512;
+
+fn signal() -> Vec<f32> {
+    (0..BLOCK).map(|n| 1.2 * (n as f32 * 0.05).sin()).collect()
+}
+
+fn bench_riaa_4ch(c: &mut Criterion) {
+    let input = signal();
+    let mut group = c.benchmark_group("riaa_4ch");
+    group.bench_function("scalar", |b| {
+        let mut filters: Vec<RIAAEQAdvanced> = (0..4).map(|_| RIAAEQAdvanced::new(48000)).collect();
+        let mut channels = vec![input.clone(); 4];
+        b.iter(|| {
+            for (filter, channel) in filters.iter_mut().zip(channels.iter_mut()) {
+                for sample in channel.iter_mut() {
+                    *sample = filter.process(*sample);
+                }
+            }
+            black_box(&channels);
+        });
+    });
+    group.bench_function("simd", |b| {
+        let mut filter = BiquadX4::riaa(48000);
+        let mut channels = vec![input.clone(); 4];
+        let mut slices: Vec<&mut [f32]> = channels.iter_mut().map(|c| c.as_mut_slice()).collect();
+        b.iter(|| {
+            filter.process_channels(&mut slices);
+            black_box(&slices);
+        });
+    });
+    group.finish();
+}
+
+fn bench_shapers(c: &mut Criterion) {
+    let input = signal();
+    let eq = MagneticEQ::<f32>::new(0.7, 0.4);
+    let mut group = c.benchmark_group("shapers");
+    group.bench_function("soft_clip_scalar", |b| {
+        let mut block = input.clone();
+        b.iter(|| {
+            block.copy_from_slice(&input);
+            block.iter_mut().for_each(|x| *x = soft_clip(*x));
+            black_box(&block);
+        });
+    });
+    group.bench_function("soft_clip_simd", |b| {
+        let mut block = input.clone();
+        b.iter(|| {
+            block.copy_from_slice(&input);
+            soft_clip_block(&mut block);
+            black_box(&block);
+        });
+    });
+    group.bench_function("magnetic_scalar", |b| {
+        let mut block = input.clone();
+        b.iter(|| {
+            block.copy_from_slice(&input);
+            block.iter_mut().for_each(|x| *x = eq.process(*x));
+            black_box(&block);
+        });
+    });
+    group.bench_function("magnetic_simd", |b| {
+        let mut block = input.clone();
+        b.iter(|| {
+            block.copy_from_slice(&input);
+            eq.process_block(&mut block);
+            black_box(&block);
+        });
+    });
+    group.finish();
+}
+
+fn bench_meter(c: &mut Criterion) {
+    let input = signal();
+    let mut group = c.benchmark_group("meter");
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            let block = black_box(&input);
+            let peak = block.iter().fold(0.0f32, |p, x| p.max(x.abs()));
+            let sum_sq: f32 = block.iter().map(|x| x * x).sum();
+            black_box((peak, sum_sq))
+        });
+    });
+    group.bench_function("simd", |b| {
+        b.iter(|| black_box(peak_and_sum_squares(black_box(&input))));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_riaa_4ch, bench_shapers, bench_meter);
+criterion_main!(benches);
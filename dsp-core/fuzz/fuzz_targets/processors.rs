@@ -0,0 +1,405 @@
+//! 프로세서 퍼징: 임의의 파라미터 변경 / 버퍼 / reset 시퀀스
+//! 유한한 파라미터와 유한한 입력(|x| <= MAX_INPUT)에서 출력이 항상 유한해야 함
+//!
+//! 실행: `cargo +nightly fuzz run processors` (dsp-core 디렉터리에서)
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use dsp_core::{
+    process_block, soft_clip, BiquadWrapper, Declicker, DelayLine, DspParams, Fir, HumRemover,
+    Limiter, MagneticEQ, NoiseProfileMode, NoiseReducer, Oversampler, ParallelFilterAdvanced,
+    RIAAEQAdvanced, RumbleFilter, TapeChain,
+};
+use libfuzzer_sys::fuzz_target;
+
+const SAMPLE_RATES: [f32; 8] = [
+    8000.0, 22050.0, 44100.0, 48000.0, 88200.0, 96000.0, 176400.0, 192000.0,
+];
+/// 입력 샘플 한계 (풀스케일 +24dB)
+const MAX_INPUT: f32 = 16.0;
+/// 한 입력에서 처리할 최대 샘플 수 (실행 시간 제한)
+const MAX_SAMPLES: usize = 1 << 16;
+
+#[derive(Arbitrary, Debug, Clone, Copy)]
+enum Kind {
+    Lowpass,
+    Highpass,
+    Notch,
+    Peaking,
+    HighShelf,
+    Riaa,
+    RiaaF64,
+    Parallel,
+    Magnetic,
+    Limiter,
+    Declicker,
+    Denoiser,
+    Dehum,
+    Rumble,
+    Fir,
+    Oversampler,
+    Delay,
+    TapeChain,
+    Legacy,
+}
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Process(Vec<f32>),
+    /// param: 프로세서별 파라미터 번호 (나머지 연산으로 선택)
+    Set { param: u8, value: f32 },
+    Reset,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    kind: Kind,
+    sample_rate: u8,
+    ops: Vec<Op>,
+}
+
+trait Target {
+    fn process(&mut self, x: f32) -> f32;
+    fn set(&mut self, param: u8, value: f32);
+    fn reset(&mut self);
+}
+
+/// 정수 파라미터 (배율, 고조파 수, 지연)
+fn count(value: f32) -> usize {
+    value.abs() as usize
+}
+
+struct Biquad {
+    kind: Kind,
+    /// 정규화 주파수, q, 게인 dB
+    params: [f32; 3],
+    filter: BiquadWrapper,
+}
+
+impl Biquad {
+    fn new(kind: Kind) -> Self {
+        let mut biquad = Self {
+            kind,
+            params: [0.1, 0.707, 0.0],
+            filter: BiquadWrapper::new(1.0, 0.0, 0.0, 0.0, 0.0),
+        };
+        biquad.redesign();
+        biquad
+    }
+
+    fn redesign(&mut self) {
+        let [freq, q, gain_db] = self.params;
+        let design = match self.kind {
+            Kind::Lowpass => BiquadWrapper::lowpass(freq, q),
+            Kind::Highpass => BiquadWrapper::highpass(freq, q),
+            Kind::Notch => BiquadWrapper::notch(freq, q),
+            Kind::Peaking => BiquadWrapper::peaking(freq, q, gain_db),
+            _ => BiquadWrapper::high_shelf(freq, gain_db),
+        };
+        self.filter.set_coefficients(&design);
+    }
+}
+
+impl Target for Biquad {
+    fn process(&mut self, x: f32) -> f32 {
+        self.filter.process(x)
+    }
+
+    fn set(&mut self, param: u8, value: f32) {
+        self.params[param as usize % 3] = value;
+        self.redesign();
+    }
+
+    fn reset(&mut self) {
+        self.filter.reset();
+    }
+}
+
+impl Target for RIAAEQAdvanced<f32> {
+    fn process(&mut self, x: f32) -> f32 {
+        RIAAEQAdvanced::process(self, x)
+    }
+
+    fn set(&mut self, _param: u8, _value: f32) {}
+
+    fn reset(&mut self) {
+        RIAAEQAdvanced::reset(self);
+    }
+}
+
+impl Target for RIAAEQAdvanced<f64> {
+    fn process(&mut self, x: f32) -> f32 {
+        RIAAEQAdvanced::process(self, x as f64) as f32
+    }
+
+    fn set(&mut self, _param: u8, _value: f32) {}
+
+    fn reset(&mut self) {
+        RIAAEQAdvanced::reset(self);
+    }
+}
+
+impl Target for ParallelFilterAdvanced {
+    fn process(&mut self, x: f32) -> f32 {
+        ParallelFilterAdvanced::process(self, x)
+    }
+
+    fn set(&mut self, _param: u8, value: f32) {
+        self.set_intensity(value);
+    }
+
+    fn reset(&mut self) {
+        ParallelFilterAdvanced::reset(self);
+    }
+}
+
+impl Target for MagneticEQ {
+    fn process(&mut self, x: f32) -> f32 {
+        MagneticEQ::process(self, x)
+    }
+
+    fn set(&mut self, param: u8, value: f32) {
+        match param % 2 {
+            0 => self.set_saturation(value),
+            _ => self.set_hardness(value),
+        }
+    }
+
+    fn reset(&mut self) {}
+}
+
+/// 설정자가 없으므로 파라미터가 바뀌면 새 인스턴스
+struct LimiterTarget {
+    /// threshold, release (샘플)
+    params: [f32; 2],
+    limiter: Limiter,
+}
+
+impl Target for LimiterTarget {
+    fn process(&mut self, x: f32) -> f32 {
+        self.limiter.process(x)
+    }
+
+    fn set(&mut self, param: u8, value: f32) {
+        self.params[param as usize % 2] = value;
+        self.limiter = Limiter::new(self.params[0], self.params[1]);
+    }
+
+    fn reset(&mut self) {
+        self.limiter.reset();
+    }
+}
+
+struct DeclickerTarget(Declicker, f32);
+
+impl Target for DeclickerTarget {
+    fn process(&mut self, x: f32) -> f32 {
+        self.0.process(x)
+    }
+
+    fn set(&mut self, param: u8, value: f32) {
+        match param % 3 {
+            0 => self.0.set_sensitivity(value),
+            1 => self.0.set_max_click_ms(value, self.1),
+            _ => self.0.set_enabled(value > 0.0),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Target for NoiseReducer {
+    fn process(&mut self, x: f32) -> f32 {
+        NoiseReducer::process(self, x)
+    }
+
+    fn set(&mut self, param: u8, value: f32) {
+        match param % 6 {
+            0 => self.set_over_subtraction(value),
+            1 => self.set_floor_db(value),
+            2 => self.set_smoothing(value),
+            3 => {
+                let modes = [
+                    NoiseProfileMode::Auto,
+                    NoiseProfileMode::Learn,
+                    NoiseProfileMode::Hold,
+                ];
+                self.set_profile_mode(modes[count(value) % modes.len()]);
+            }
+            4 => self.clear_noise_profile(),
+            _ => self.set_enabled(value > 0.0),
+        }
+    }
+
+    fn reset(&mut self) {
+        NoiseReducer::reset(self);
+    }
+}
+
+impl Target for HumRemover {
+    fn process(&mut self, x: f32) -> f32 {
+        HumRemover::process(self, x)
+    }
+
+    fn set(&mut self, param: u8, value: f32) {
+        match param % 6 {
+            0 => self.set_harmonics(count(value)),
+            1 => self.set_q(value),
+            2 => self.set_detection_window(value),
+            3 => self.set_fundamental(Some(value)),
+            4 => self.set_fundamental(None),
+            _ => self.clear_detection(),
+        }
+    }
+
+    fn reset(&mut self) {
+        HumRemover::reset(self);
+    }
+}
+
+impl Target for RumbleFilter {
+    fn process(&mut self, x: f32) -> f32 {
+        RumbleFilter::process(self, x)
+    }
+
+    fn set(&mut self, _param: u8, value: f32) {
+        self.set_cutoff(value);
+    }
+
+    fn reset(&mut self) {
+        RumbleFilter::reset(self);
+    }
+}
+
+impl Target for Fir<63> {
+    fn process(&mut self, x: f32) -> f32 {
+        Fir::process(self, x)
+    }
+
+    fn set(&mut self, _param: u8, value: f32) {
+        self.set_lowpass(value);
+    }
+
+    fn reset(&mut self) {
+        Fir::reset(self);
+    }
+}
+
+impl Target for Oversampler {
+    fn process(&mut self, x: f32) -> f32 {
+        Oversampler::process(self, x, soft_clip)
+    }
+
+    fn set(&mut self, _param: u8, value: f32) {
+        self.set_factor(count(value));
+    }
+
+    fn reset(&mut self) {
+        Oversampler::reset(self);
+    }
+}
+
+impl Target for DelayLine<64> {
+    fn process(&mut self, x: f32) -> f32 {
+        DelayLine::process(self, x)
+    }
+
+    fn set(&mut self, _param: u8, value: f32) {
+        self.set_delay(count(value));
+    }
+
+    fn reset(&mut self) {
+        DelayLine::reset(self);
+    }
+}
+
+impl Target for TapeChain {
+    fn process(&mut self, x: f32) -> f32 {
+        TapeChain::process(self, x)
+    }
+
+    fn set(&mut self, param: u8, value: f32) {
+        match param % 6 {
+            0 => self.set_drive_db(value),
+            1 => self.set_warmth(value),
+            2 => self.set_tone(value),
+            3 => self.set_output_db(value),
+            4 => self.set_dry_wet(value),
+            _ => self.set_oversampling(count(value)),
+        }
+    }
+
+    fn reset(&mut self) {
+        TapeChain::reset(self);
+    }
+}
+
+struct Legacy(DspParams);
+
+impl Target for Legacy {
+    fn process(&mut self, x: f32) -> f32 {
+        let mut y = [0.0];
+        process_block(&[x], &mut y, 48000.0, &self.0);
+        y[0]
+    }
+
+    fn set(&mut self, _param: u8, value: f32) {
+        self.0.drive_db = value;
+    }
+
+    fn reset(&mut self) {}
+}
+
+fn target(kind: Kind, sample_rate: f32) -> Box<dyn Target> {
+    match kind {
+        Kind::Lowpass | Kind::Highpass | Kind::Notch | Kind::Peaking | Kind::HighShelf => {
+            Box::new(Biquad::new(kind))
+        }
+        Kind::Riaa => Box::new(RIAAEQAdvanced::<f32>::new(sample_rate as u32)),
+        Kind::RiaaF64 => Box::new(RIAAEQAdvanced::<f64>::new(sample_rate as u32)),
+        Kind::Parallel => Box::new(ParallelFilterAdvanced::new(0.5)),
+        Kind::Magnetic => Box::new(MagneticEQ::new(0.5, 0.5)),
+        Kind::Limiter => Box::new(LimiterTarget {
+            params: [0.95, 4800.0],
+            limiter: Limiter::new(0.95, 4800.0),
+        }),
+        Kind::Declicker => Box::new(DeclickerTarget(
+            Declicker::new(sample_rate, 0.5, 1.0),
+            sample_rate,
+        )),
+        Kind::Denoiser => Box::new(NoiseReducer::new(1.0, -20.0, 0.5)),
+        Kind::Dehum => Box::new(HumRemover::new(sample_rate, 4, 30.0)),
+        Kind::Rumble => Box::new(RumbleFilter::new(sample_rate, 20.0)),
+        Kind::Fir => Box::new(Fir::<63>::lowpass(0.25)),
+        Kind::Oversampler => Box::new(Oversampler::new(2)),
+        Kind::Delay => Box::new(DelayLine::<64>::new(16)),
+        Kind::TapeChain => Box::new(TapeChain::new(sample_rate)),
+        Kind::Legacy => Box::new(Legacy(DspParams::new(0.0))),
+    }
+}
+
+fuzz_target!(|input: Input| {
+    let sample_rate = SAMPLE_RATES[input.sample_rate as usize % SAMPLE_RATES.len()];
+    let mut target = target(input.kind, sample_rate);
+    let mut processed = 0;
+    for op in input.ops {
+        match op {
+            Op::Process(buffer) => {
+                for x in buffer.into_iter().filter(|x| x.is_finite()) {
+                    if processed == MAX_SAMPLES {
+                        return;
+                    }
+                    processed += 1;
+                    let y = target.process(x.clamp(-MAX_INPUT, MAX_INPUT));
+                    assert!(y.is_finite(), "{:?}: output {y} for input {x}", input.kind);
+                }
+            }
+            Op::Set { param, value } if value.is_finite() => target.set(param, value),
+            Op::Set { .. } => {}
+            Op::Reset => target.reset(),
+        }
+    }
+});
@@ -1,5 +1,7 @@
-/// Velocity 분석 및 신호 특성 추출
-/// 다차원 점수: RMS + Crest Factor + Spectral Tilt
+//! Velocity 분석 및 신호 특성 추출
+//! 다차원 점수: RMS + Crest Factor + Spectral Tilt
+
+use crate::math;
 
 /// 다차원 velocity 분석기
 pub struct VelocityAnalyzer {
@@ -11,13 +13,17 @@ impl VelocityAnalyzer {
         Self { sample_rate }
     }
 
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
     /// RMS 계산
     pub fn calculate_rms(input: &[f32]) -> f32 {
         if input.is_empty() {
             return 0.0;
         }
         let sum_sq: f32 = input.iter().map(|&s| s * s).sum();
-        (sum_sq / input.len() as f32).sqrt()
+        math::sqrt(sum_sq / input.len() as f32)
     }
 
     /// Crest Factor 계산 (Peak / RMS)
@@ -64,7 +70,7 @@ impl VelocityAnalyzer {
 
         // 정규화 (0-1)
         let rms_norm = (rms * 10.0).min(1.0); // 최대 0.1 -> 1.0
-        let crest_norm = ((crest - 1.0) / 10.0).max(0.0).min(1.0); // 1-11 range
+        let crest_norm = ((crest - 1.0) / 10.0).clamp(0.0, 1.0); // 1-11 range
         let tilt_norm = tilt;
 
         // 가중 평균
@@ -78,7 +84,7 @@ impl VelocityAnalyzer {
 /// Sigmoid 함수
 #[inline]
 fn sigmoid(x: f32) -> f32 {
-    1.0 / (1.0 + (-x).exp())
+    1.0 / (1.0 + math::exp(-x))
 }
 
 #[cfg(test)]
@@ -103,6 +109,6 @@ mod tests {
     fn test_velocity_range() {
         let signal = vec![0.1; 100];
         let velocity = VelocityAnalyzer::calculate_velocity(&signal);
-        assert!(velocity >= 0.0 && velocity <= 1.0);
+        assert!((0.0..=1.0).contains(&velocity));
     }
 }
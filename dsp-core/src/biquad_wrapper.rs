@@ -1,39 +1,72 @@
-/// Biquad 필터 래퍼
-/// 다목적 2차 IIR 필터
-
-pub struct BiquadWrapper {
-    b0: f32,
-    b1: f32,
-    b2: f32,
-    a1: f32,
-    a2: f32,
-    x1: f32,
-    x2: f32,
-    y1: f32,
-    y2: f32,
+//! Biquad 필터 래퍼
+//! 다목적 2차 IIR 필터 (계수 설계는 f64, 상태/계수는 샘플 타입 T)
+
+use crate::math;
+use crate::sample::Sample;
+
+/// 설계 입력 범위: Nyquist(0.5) 이상, q <= 0 에서는 계수가 발산하므로 클램프
+/// (최저 주파수는 샘플 타입별 `Sample::MIN_DESIGN_FREQ`)
+const MAX_FREQ: f32 = 0.49;
+const MIN_Q: f32 = 0.025;
+const MAX_Q: f32 = 100.0;
+const MAX_GAIN_DB: f32 = 48.0;
+
+/// 정규화 주파수 -> (sin w, cos w)
+fn angle<T: Sample>(freq: f32) -> (f64, f64) {
+    let w = 2.0 * core::f64::consts::PI * freq.clamp(T::MIN_DESIGN_FREQ, MAX_FREQ) as f64;
+    (math::double::sin(w), math::double::cos(w))
+}
+
+fn bandwidth(sin_w: f64, q: f32) -> f64 {
+    sin_w / (2.0 * q.clamp(MIN_Q, MAX_Q) as f64)
+}
+
+/// dB -> 진폭 제곱근 (RBJ 쿡북의 A)
+fn gain_amplitude(gain_db: f32) -> f64 {
+    math::double::powf(10.0, gain_db.clamp(-MAX_GAIN_DB, MAX_GAIN_DB) as f64 / 40.0)
 }
 
-impl BiquadWrapper {
-    pub fn new(b0: f32, b1: f32, b2: f32, a1: f32, a2: f32) -> Self {
+pub struct BiquadWrapper<T: Sample = f32> {
+    b0: T,
+    b1: T,
+    b2: T,
+    a1: T,
+    a2: T,
+    x1: T,
+    x2: T,
+    y1: T,
+    y2: T,
+}
+
+impl<T: Sample> BiquadWrapper<T> {
+    pub fn new(b0: T, b1: T, b2: T, a1: T, a2: T) -> Self {
         Self {
             b0,
             b1,
             b2,
             a1,
             a2,
-            x1: 0.0,
-            x2: 0.0,
-            y1: 0.0,
-            y2: 0.0,
+            x1: T::ZERO,
+            x2: T::ZERO,
+            y1: T::ZERO,
+            y2: T::ZERO,
         }
     }
 
+    fn from_f64(b0: f64, b1: f64, b2: f64, a1: f64, a2: f64) -> Self {
+        Self::new(
+            T::from_f64(b0),
+            T::from_f64(b1),
+            T::from_f64(b2),
+            T::from_f64(a1),
+            T::from_f64(a2),
+        )
+    }
+
     /// Lowpass 필터 계수 생성
     pub fn lowpass(cutoff: f32, q: f32) -> Self {
-        let w = 2.0 * core::f32::consts::PI * cutoff;
-        let sin_w = w.sin();
-        let cos_w = w.cos();
-        let alpha = sin_w / (2.0 * q);
+        let (sin_w, cos_w) = angle::<T>(cutoff);
+        let alpha = bandwidth(sin_w, q);
 
         let b0 = (1.0 - cos_w) / 2.0;
         let b1 = 1.0 - cos_w;
@@ -42,22 +75,109 @@ impl BiquadWrapper {
         let a1 = -2.0 * cos_w;
         let a2 = 1.0 - alpha;
 
-        Self::new(b0 / a0, b1 / a0, b2 / a0, a1 / a0, a2 / a0)
+        Self::from_f64(b0 / a0, b1 / a0, b2 / a0, a1 / a0, a2 / a0)
+    }
+
+    /// Highpass 필터 계수 생성
+    pub fn highpass(cutoff: f32, q: f32) -> Self {
+        let (sin_w, cos_w) = angle::<T>(cutoff);
+        let alpha = bandwidth(sin_w, q);
+
+        let b0 = (1.0 + cos_w) / 2.0;
+        let b1 = -(1.0 + cos_w);
+        let b2 = (1.0 + cos_w) / 2.0;
+        let a0 = 1.0 + alpha;
+        let a1 = -2.0 * cos_w;
+        let a2 = 1.0 - alpha;
+
+        Self::from_f64(b0 / a0, b1 / a0, b2 / a0, a1 / a0, a2 / a0)
     }
 
-    /// Direct Form II Transposed 구조
-    pub fn process(&mut self, x: f32) -> f32 {
+    /// Notch 필터 계수 생성 (center: 정규화 주파수, q: 중심 주파수 / 대역폭)
+    pub fn notch(center: f32, q: f32) -> Self {
+        let (sin_w, cos_w) = angle::<T>(center);
+        let alpha = bandwidth(sin_w, q);
+
+        let a0 = 1.0 + alpha;
+        let b1 = -2.0 * cos_w;
+        let a2 = 1.0 - alpha;
+
+        Self::from_f64(1.0 / a0, b1 / a0, 1.0 / a0, b1 / a0, a2 / a0)
+    }
+
+    /// Peaking EQ 계수 생성 (gain_db: 중심 주파수 부스트/컷)
+    pub fn peaking(center: f32, q: f32, gain_db: f32) -> Self {
+        let a = gain_amplitude(gain_db);
+        let (sin_w, cos_w) = angle::<T>(center);
+        let alpha = bandwidth(sin_w, q);
+
+        let b0 = 1.0 + alpha * a;
+        let b1 = -2.0 * cos_w;
+        let b2 = 1.0 - alpha * a;
+        let a0 = 1.0 + alpha / a;
+        let a2 = 1.0 - alpha / a;
+
+        Self::from_f64(b0 / a0, b1 / a0, b2 / a0, b1 / a0, a2 / a0)
+    }
+
+    /// High shelf 계수 생성 (기울기 S = 1)
+    pub fn high_shelf(cutoff: f32, gain_db: f32) -> Self {
+        let a = gain_amplitude(gain_db);
+        let (sin_w, cos_w) = angle::<T>(cutoff);
+        let alpha = sin_w / 2.0 * core::f64::consts::SQRT_2;
+        let sqrt_a_alpha = 2.0 * math::double::sqrt(a) * alpha;
+
+        let b0 = a * ((a + 1.0) + (a - 1.0) * cos_w + sqrt_a_alpha);
+        let b1 = -2.0 * a * ((a - 1.0) + (a + 1.0) * cos_w);
+        let b2 = a * ((a + 1.0) + (a - 1.0) * cos_w - sqrt_a_alpha);
+        let a0 = (a + 1.0) - (a - 1.0) * cos_w + sqrt_a_alpha;
+        let a1 = 2.0 * ((a - 1.0) - (a + 1.0) * cos_w);
+        let a2 = (a + 1.0) - (a - 1.0) * cos_w - sqrt_a_alpha;
+
+        Self::from_f64(b0 / a0, b1 / a0, b2 / a0, a1 / a0, a2 / a0)
+    }
+
+    /// 필터 상태는 유지하고 계수만 교체 (주파수 추적용)
+    pub fn set_coefficients(&mut self, other: &Self) {
+        self.b0 = other.b0;
+        self.b1 = other.b1;
+        self.b2 = other.b2;
+        self.a1 = other.a1;
+        self.a2 = other.a2;
+    }
+
+    /// 계수 [b0, b1, b2, a1, a2] (a0 = 1 정규화)
+    pub fn coefficients(&self) -> [T; 5] {
+        [self.b0, self.b1, self.b2, self.a1, self.a2]
+    }
+
+    /// w (rad/sample) 에서의 진폭 응답
+    pub fn magnitude(&self, w: f64) -> f64 {
+        let [b0, b1, b2, a1, a2] = self.coefficients().map(T::to_f64);
+        let (sin1, cos1) = (math::double::sin(w), math::double::cos(w));
+        let (sin2, cos2) = (math::double::sin(2.0 * w), math::double::cos(2.0 * w));
+        let num_re = b0 + b1 * cos1 + b2 * cos2;
+        let num_im = b1 * sin1 + b2 * sin2;
+        let den_re = 1.0 + a1 * cos1 + a2 * cos2;
+        let den_im = a1 * sin1 + a2 * sin2;
+        math::double::sqrt(
+            (num_re * num_re + num_im * num_im) / (den_re * den_re + den_im * den_im),
+        )
+    }
+
+    /// Direct Form II Transposed 구조 (무음 꼬리에서 상태가 비정규화 수로 가지 않도록 flush)
+    pub fn process(&mut self, x: T) -> T {
         let y = self.b0 * x + self.y1;
-        self.y1 = self.b1 * x - self.a1 * y + self.y2;
-        self.y2 = self.b2 * x - self.a2 * y;
+        self.y1 = (self.b1 * x - self.a1 * y + self.y2).flush_denormal();
+        self.y2 = (self.b2 * x - self.a2 * y).flush_denormal();
         y
     }
 
     pub fn reset(&mut self) {
-        self.x1 = 0.0;
-        self.x2 = 0.0;
-        self.y1 = 0.0;
-        self.y2 = 0.0;
+        self.x1 = T::ZERO;
+        self.x2 = T::ZERO;
+        self.y1 = T::ZERO;
+        self.y2 = T::ZERO;
     }
 }
 
@@ -73,7 +193,58 @@ mod tests {
 
     #[test]
     fn test_lowpass_creation() {
-        let biquad = BiquadWrapper::lowpass(0.1, 1.0);
+        let biquad = BiquadWrapper::<f32>::lowpass(0.1, 1.0);
         assert!(!biquad.b0.is_nan());
     }
+
+    #[test]
+    fn test_notch_removes_center() {
+        let mut notch = BiquadWrapper::notch(60.0 / 48000.0, 10.0);
+        let mut peak: f32 = 0.0;
+        for n in 0..48000 {
+            let x = (2.0 * core::f32::consts::PI * 60.0 * n as f32 / 48000.0).sin();
+            let y = notch.process(x);
+            if n > 24000 {
+                peak = peak.max(y.abs());
+            }
+        }
+        assert!(peak < 0.01);
+    }
+
+    #[test]
+    fn test_f64_keeps_low_cutoff_accurate() {
+        // 192kHz에서 20Hz Lowpass: f32 계수 양자화로 DC 게인이 틀어짐
+        let dc_gain = |c: [f64; 5]| (c[0] + c[1] + c[2]) / (1.0 + c[3] + c[4]);
+        let wide = BiquadWrapper::<f64>::lowpass(20.0 / 192000.0, 0.707);
+        let narrow = BiquadWrapper::<f32>::lowpass(20.0 / 192000.0, 0.707);
+        assert!((dc_gain(wide.coefficients()) - 1.0).abs() < 1e-6);
+        assert!((dc_gain(narrow.coefficients().map(f64::from)) - 1.0).abs() > 1e-3);
+    }
+
+    fn assert_clamped<T: Sample>() {
+        // Nyquist 이상 / 0 이하 주파수, q = 0 또는 과도한 q, 과도한 게인: 극점이 단위원 안에 남아야 함
+        let designs = [
+            BiquadWrapper::<T>::lowpass(0.7, 0.0),
+            BiquadWrapper::lowpass(0.0, 1000.0),
+            BiquadWrapper::highpass(-0.1, -1.0),
+            BiquadWrapper::highpass(0.0, 1000.0),
+            BiquadWrapper::notch(0.5, 0.0),
+            BiquadWrapper::notch(0.0, 1000.0),
+            BiquadWrapper::peaking(0.6, 0.0, 1000.0),
+            BiquadWrapper::high_shelf(2.0, -1000.0),
+            BiquadWrapper::high_shelf(0.0, 1000.0),
+        ];
+        for biquad in designs {
+            let [b0, b1, b2, a1, a2] = biquad.coefficients().map(T::to_f64);
+            assert!([b0, b1, b2, a1, a2].iter().all(|c| c.is_finite()));
+            assert!(a2.abs() < 1.0 && a1.abs() < 1.0 + a2);
+        }
+    }
+
+    #[test]
+    fn test_out_of_range_design_inputs_are_clamped() {
+        assert_clamped::<f64>();
+        // f32 는 최저 주파수를 높여 계수 양자화 후에도 극점이 단위원 안쪽
+        assert_clamped::<f32>();
+    }
 }
@@ -0,0 +1,342 @@
+//! 바이닐 클릭/크래클 제거
+//! LPC 잔차(residual)로 충격성 잡음 검출 -> AR 보간(LSAR)으로 복원
+
+use crate::processor_trait::{ProcessParams, Processor};
+
+/// LPC 차수
+const ORDER: usize = 24;
+/// 한 번에 확정되는 샘플 수
+const HOP: usize = 512;
+/// 앞뒤 문맥 (ORDER + MAX_CLICK_LEN 이상)
+const MARGIN: usize = 256;
+/// 분석 프레임 길이
+const FRAME: usize = HOP + 2 * MARGIN;
+/// 보간 가능한 최대 클릭 길이 (샘플)
+pub const MAX_CLICK_LEN: usize = 128;
+/// 이 간격 이하로 떨어진 검출은 하나의 클릭으로 병합
+const MERGE_GAP: usize = 8;
+/// 무음 판정 레벨
+const SILENCE: f32 = 1e-5;
+
+/// 클릭 제거기
+/// 프레임 단위로 분석하므로 고정 지연(latency_samples) 발생
+pub struct Declicker {
+    sensitivity: f32,
+    max_click_len: usize,
+    enabled: bool,
+    frame: [f32; FRAME],
+    residual: [f32; FRAME],
+    ready: [f32; HOP],
+    write: usize,
+    read: usize,
+    clicks_repaired: usize,
+}
+
+impl Declicker {
+    /// sensitivity: 0.0(둔감)-1.0(민감), max_click_ms: 보간할 최대 클릭 길이
+    pub fn new(sample_rate: f32, sensitivity: f32, max_click_ms: f32) -> Self {
+        let mut declicker = Self {
+            sensitivity: 0.0,
+            max_click_len: 1,
+            enabled: true,
+            frame: [0.0; FRAME],
+            residual: [0.0; FRAME],
+            ready: [0.0; HOP],
+            write: FRAME - HOP,
+            read: 0,
+            clicks_repaired: 0,
+        };
+        declicker.set_sensitivity(sensitivity);
+        declicker.set_max_click_ms(max_click_ms, sample_rate);
+        declicker
+    }
+
+    pub fn set_sensitivity(&mut self, sensitivity: f32) {
+        self.sensitivity = sensitivity.clamp(0.0, 1.0);
+    }
+
+    pub fn set_max_click_ms(&mut self, max_click_ms: f32, sample_rate: f32) {
+        let samples = (max_click_ms.max(0.0) * 0.001 * sample_rate) as usize;
+        self.max_click_len = samples.clamp(1, MAX_CLICK_LEN);
+    }
+
+    /// 비활성화해도 지연은 유지 (호스트 지연 보상이 바뀌지 않도록)
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// 지금까지 복원한 클릭 수
+    pub fn clicks_repaired(&self) -> usize {
+        self.clicks_repaired
+    }
+
+    pub fn process(&mut self, input: f32) -> f32 {
+        let output = self.ready[self.read];
+        self.read += 1;
+
+        self.frame[self.write] = input;
+        self.write += 1;
+
+        if self.write == FRAME {
+            if self.enabled {
+                self.repair_frame();
+            }
+            self.ready.copy_from_slice(&self.frame[MARGIN..MARGIN + HOP]);
+            self.frame.copy_within(HOP.., 0);
+            self.write = FRAME - HOP;
+            self.read = 0;
+        }
+
+        output
+    }
+
+    pub fn reset(&mut self) {
+        self.frame = [0.0; FRAME];
+        self.residual = [0.0; FRAME];
+        self.ready = [0.0; HOP];
+        self.write = FRAME - HOP;
+        self.read = 0;
+        self.clicks_repaired = 0;
+    }
+
+    /// 프레임 중앙 HOP 구간에서 시작하는 클릭 검출 및 복원
+    fn repair_frame(&mut self) {
+        let mut lpc = [0.0f32; ORDER + 1];
+        if !estimate_lpc(&self.frame, &mut lpc) {
+            return;
+        }
+
+        // 예측 잔차 e[n] = sum(b_k * x[n-k]), b = lpc (b_0 = 1)
+        let mut abs_sum = 0.0;
+        for n in ORDER..FRAME {
+            let e: f32 = (0..=ORDER).map(|k| lpc[k] * self.frame[n - k]).sum();
+            self.residual[n] = e;
+            abs_sum += e.abs();
+        }
+
+        // 평균 절대 잔차로 표준편차 추정 (가우시안: sigma ≈ 1.2533 * mean|e|)
+        let sigma = (1.2533 * abs_sum / (FRAME - ORDER) as f32).max(1e-6);
+        let threshold = sigma * (10.0 - 7.0 * self.sensitivity);
+
+        let mut n = MARGIN;
+        while n < MARGIN + HOP {
+            if self.residual[n].abs() <= threshold {
+                n += 1;
+                continue;
+            }
+
+            // 가까운 검출을 병합해 클릭 구간 [start, end) 결정
+            let start = n.saturating_sub(1);
+            let mut last = n;
+            let mut scan = n + 1;
+            while scan < FRAME - ORDER && scan <= last + MERGE_GAP {
+                if self.residual[scan].abs() > threshold {
+                    last = scan;
+                }
+                scan += 1;
+            }
+            let end = (last + 2).min(FRAME - ORDER);
+            let len = end - start;
+
+            // 너무 긴 구간은 클릭이 아닌 음악적 트랜지언트로 간주
+            // 앞 문맥이 무음이면 신호 시작(onset)이므로 제외
+            let context_silent = start < ORDER
+                || self.frame[start - ORDER..start].iter().all(|x| x.abs() < SILENCE);
+            if len <= self.max_click_len && !context_silent {
+                interpolate_gap(&mut self.frame, &lpc, start, len);
+                self.clicks_repaired += 1;
+            }
+            n = end;
+        }
+    }
+}
+
+impl Processor for Declicker {
+    fn process(&mut self, input: &[f32], output: &mut [f32], _params: &ProcessParams) {
+        for (x, y) in input.iter().zip(output.iter_mut()) {
+            *y = Declicker::process(self, *x);
+        }
+    }
+
+    fn process_sample(&mut self, input: f32, _params: &ProcessParams) -> f32 {
+        Declicker::process(self, input)
+    }
+
+    fn reset(&mut self) {
+        Declicker::reset(self);
+    }
+
+    fn name(&self) -> &str {
+        "Declicker"
+    }
+
+    fn latency_samples(&self) -> usize {
+        HOP + MARGIN
+    }
+}
+
+/// 자기상관 + Levinson-Durbin으로 예측 오차 필터 b (b_0 = 1) 추정
+fn estimate_lpc(frame: &[f32; FRAME], lpc: &mut [f32; ORDER + 1]) -> bool {
+    let mut r = [0.0f32; ORDER + 1];
+    for (lag, r_lag) in r.iter_mut().enumerate() {
+        *r_lag = (lag..FRAME).map(|n| frame[n] * frame[n - lag]).sum();
+    }
+    if r[0] <= 1e-9 {
+        return false;
+    }
+    // 백색 잡음 보정 (수치 안정성)
+    r[0] *= 1.0 + 1e-4;
+
+    *lpc = [0.0; ORDER + 1];
+    lpc[0] = 1.0;
+    let mut error = r[0];
+    let mut prev = [0.0f32; ORDER + 1];
+    for i in 1..=ORDER {
+        let acc: f32 = (0..i).map(|j| lpc[j] * r[i - j]).sum();
+        let k = -acc / error;
+        prev.copy_from_slice(lpc);
+        for j in 1..i {
+            lpc[j] = prev[j] + k * prev[i - j];
+        }
+        lpc[i] = k;
+        error *= 1.0 - k * k;
+        if error <= 0.0 {
+            return false;
+        }
+    }
+    true
+}
+
+/// LSAR 보간: 구간 [start, start+len)의 샘플을 AR 예측 오차 제곱합이 최소가 되도록 재구성
+/// 정규방정식이 대칭 Toeplitz(R_b)라 Levinson 재귀로 O(len) 메모리에 해결
+fn interpolate_gap(frame: &mut [f32; FRAME], b: &[f32; ORDER + 1], start: usize, len: usize) {
+    // b의 자기상관 R_b(k), k > ORDER 이면 0
+    let mut rb = [0.0f32; MAX_CLICK_LEN];
+    for (k, rb_k) in rb.iter_mut().enumerate().take(ORDER + 1) {
+        *rb_k = (k..=ORDER).map(|j| b[j] * b[j - k]).sum();
+    }
+    // 약한 리지로 조건수 개선
+    rb[0] *= 1.0 + 1e-5;
+
+    // 우변: 구간 주변 알려진 샘플의 기여
+    let mut rhs = [0.0f32; MAX_CLICK_LEN];
+    for (i, rhs_i) in rhs.iter_mut().enumerate().take(len) {
+        let pos = start + i;
+        let lo = pos.saturating_sub(ORDER);
+        let hi = (pos + ORDER).min(FRAME - 1);
+        let mut acc = 0.0;
+        for t in lo..=hi {
+            if t < start || t >= start + len {
+                acc += rb[pos.abs_diff(t)] * frame[t];
+            }
+        }
+        *rhs_i = -acc;
+    }
+
+    let mut solution = [0.0f32; MAX_CLICK_LEN];
+    if solve_toeplitz(&rb, &rhs, &mut solution, len) {
+        frame[start..start + len].copy_from_slice(&solution[..len]);
+    }
+}
+
+/// 대칭 Toeplitz 시스템 T x = y (T 첫 행 = r) Levinson 재귀 해법
+fn solve_toeplitz(
+    r: &[f32; MAX_CLICK_LEN],
+    y: &[f32; MAX_CLICK_LEN],
+    x: &mut [f32; MAX_CLICK_LEN],
+    n: usize,
+) -> bool {
+    if r[0] <= 0.0 {
+        return false;
+    }
+    let mut f = [0.0f32; MAX_CLICK_LEN];
+    let mut next = [0.0f32; MAX_CLICK_LEN];
+    f[0] = 1.0 / r[0];
+    x[0] = y[0] / r[0];
+
+    for m in 1..n {
+        let ef: f32 = (0..m).map(|i| r[m - i] * f[i]).sum();
+        let denom = 1.0 - ef * ef;
+        if denom.abs() < 1e-12 {
+            return false;
+        }
+        // f' = ([f, 0] - ef * [0, reverse(f)]) / denom
+        for i in 0..=m {
+            let forward = if i < m { f[i] } else { 0.0 };
+            let backward = if i > 0 { f[m - i] } else { 0.0 };
+            next[i] = (forward - ef * backward) / denom;
+        }
+        f[..=m].copy_from_slice(&next[..=m]);
+
+        // x' = [x, 0] + (y[m] - ex) * reverse(f')
+        let ex: f32 = (0..m).map(|i| r[m - i] * x[i]).sum();
+        x[m] = 0.0;
+        let scale = y[m] - ex;
+        for i in 0..=m {
+            x[i] += scale * f[m - i];
+        }
+    }
+    x[..n].iter().all(|v| v.is_finite())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_signal(len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|n| {
+                let t = n as f32 / 48000.0;
+                0.3 * (2.0 * core::f32::consts::PI * 440.0 * t).sin()
+                    + 0.1 * (2.0 * core::f32::consts::PI * 1250.0 * t).sin()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_solve_toeplitz() {
+        let mut r = [0.0f32; MAX_CLICK_LEN];
+        r[..3].copy_from_slice(&[4.0, 1.0, 0.5]);
+        let mut y = [0.0f32; MAX_CLICK_LEN];
+        y[..3].copy_from_slice(&[1.0, 2.0, 3.0]);
+        let mut x = [0.0f32; MAX_CLICK_LEN];
+        assert!(solve_toeplitz(&r, &y, &mut x, 3));
+        // T x 재계산
+        for i in 0..3usize {
+            let tx: f32 = (0..3).map(|j| r[i.abs_diff(j)] * x[j]).sum();
+            assert!((tx - y[i]).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_click_removed() {
+        let clean = test_signal(8192);
+        let mut clicked = clean.clone();
+        for pos in [2000, 4100, 6000] {
+            clicked[pos] += 0.9;
+            clicked[pos + 1] -= 0.6;
+        }
+
+        let mut declicker = Declicker::new(48000.0, 0.5, 2.0);
+        let latency = declicker.latency_samples();
+        let output: Vec<f32> = clicked.iter().map(|&x| declicker.process(x)).collect();
+
+        assert!(declicker.clicks_repaired() >= 3);
+        for pos in [2000, 4100, 6000] {
+            let err = (output[pos + latency] - clean[pos]).abs();
+            assert!(err < 0.01, "click at {pos} not repaired (err {err})");
+        }
+    }
+
+    #[test]
+    fn test_clean_signal_passes_through() {
+        let clean = test_signal(4096);
+        let mut declicker = Declicker::new(48000.0, 0.3, 2.0);
+        let latency = declicker.latency_samples();
+        let output: Vec<f32> = clean.iter().map(|&x| declicker.process(x)).collect();
+
+        for n in latency..clean.len() {
+            assert!((output[n] - clean[n - latency]).abs() < 1e-3);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! 고정 최대 길이 지연선 (힙 할당 없음)
+//! 지연이 있는 경로와 다른 신호(Dry, 사이드체인)를 정렬할 때 사용
+
+use crate::sample::Sample;
+
+/// 최대 MAX 샘플까지 지연
+pub struct DelayLine<const MAX: usize, T: Sample = f32> {
+    buffer: [T; MAX],
+    pos: usize,
+    delay: usize,
+}
+
+impl<const MAX: usize, T: Sample> DelayLine<MAX, T> {
+    pub fn new(delay: usize) -> Self {
+        let mut line = Self {
+            buffer: [T::ZERO; MAX],
+            pos: 0,
+            delay: 0,
+        };
+        line.set_delay(delay);
+        line
+    }
+
+    /// 지연 변경 (MAX - 1 로 제한)
+    pub fn set_delay(&mut self, delay: usize) {
+        self.delay = delay.min(MAX.saturating_sub(1));
+    }
+
+    pub fn delay(&self) -> usize {
+        self.delay
+    }
+
+    #[inline]
+    pub fn process(&mut self, input: T) -> T {
+        if self.delay == 0 {
+            return input;
+        }
+        self.buffer[self.pos] = input;
+        let read = (self.pos + MAX - self.delay) % MAX;
+        self.pos = (self.pos + 1) % MAX;
+        self.buffer[read]
+    }
+
+    pub fn reset(&mut self) {
+        self.buffer = [T::ZERO; MAX];
+        self.pos = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay() {
+        let mut line = DelayLine::<8>::new(3);
+        let output: Vec<f32> = (1..=6).map(|n| line.process(n as f32)).collect();
+        assert_eq!(output, [0.0, 0.0, 0.0, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_delay_is_clamped() {
+        let mut line = DelayLine::<4>::new(10);
+        assert_eq!(line.delay(), 3);
+        assert_eq!(DelayLine::<4>::new(0).process(0.5), 0.5);
+        line.process(1.0);
+        assert_eq!(line.process(0.0), 0.0);
+    }
+}
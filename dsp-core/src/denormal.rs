@@ -0,0 +1,86 @@
+//! 비정규화 수(denormal) 대책
+//! - 필터 상태: `Sample::flush_denormal` (이식 가능, 모든 타깃에서 동작)
+//! - 처리 스코프: `ScopedFtz` (x86_64 MXCSR FTZ + DAZ, aarch64 FPCR FZ)
+//!
+//! 플러그인은 process() 전체를 ScopedFtz로 감싸고, dsp-core 필터는 FTZ가 없는
+//! 환경(다른 아키텍처, 호스트가 MXCSR를 되돌리는 경우)에서도 상태를 직접 flush
+
+/// 스코프 동안 비정규화 수를 0으로 처리, drop 시 이전 상태 복원
+/// (현재 스레드의 제어 레지스터만 변경)
+///
+/// x86_64 / aarch64 외 타깃(wasm32, Cortex-M 등)에서는 아무것도 하지 않음:
+/// 그곳에서는 dsp-core 필터의 `Sample::flush_denormal` 만으로 출력이 정규 수로 유지됨
+/// (stability_tests 의 무음 꼬리 테스트가 FTZ 없이 검증)
+pub struct ScopedFtz {
+    #[cfg(target_arch = "x86_64")]
+    previous: u32,
+    #[cfg(target_arch = "aarch64")]
+    previous: u64,
+}
+
+impl ScopedFtz {
+    /// MXCSR FTZ(15) + DAZ(6)
+    #[cfg(target_arch = "x86_64")]
+    const FTZ_DAZ: u32 = 0x8040;
+    /// FPCR FZ(24)
+    #[cfg(target_arch = "aarch64")]
+    const FZ: u64 = 1 << 24;
+
+    #[allow(deprecated)]
+    pub fn enable() -> Self {
+        #[cfg(target_arch = "x86_64")]
+        {
+            use core::arch::x86_64::{_mm_getcsr, _mm_setcsr};
+            // SAFETY: SSE는 x86_64에서 항상 사용 가능, 이 스레드의 MXCSR만 변경
+            let previous = unsafe { _mm_getcsr() };
+            unsafe { _mm_setcsr(previous | Self::FTZ_DAZ) };
+            Self { previous }
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            let previous: u64;
+            // SAFETY: FPCR 읽기/쓰기는 EL0에서 허용, 이 스레드의 FP 모드만 변경
+            unsafe {
+                core::arch::asm!("mrs {}, fpcr", out(reg) previous, options(nomem, nostack));
+                core::arch::asm!("msr fpcr, {}", in(reg) previous | Self::FZ, options(nomem, nostack));
+            }
+            Self { previous }
+        }
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        Self {}
+    }
+}
+
+impl Drop for ScopedFtz {
+    #[allow(deprecated)]
+    fn drop(&mut self) {
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: enable()에서 읽은 값 복원
+        unsafe {
+            core::arch::x86_64::_mm_setcsr(self.previous)
+        };
+        #[cfg(target_arch = "aarch64")]
+        // SAFETY: enable()에서 읽은 값 복원
+        unsafe {
+            core::arch::asm!("msr fpcr, {}", in(reg) self.previous, options(nomem, nostack))
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::hint::black_box;
+
+    #[test]
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn test_scoped_ftz_flushes_and_restores() {
+        let tiny = || black_box(1e-30f32) * black_box(1e-10f32);
+        assert!(tiny().is_subnormal());
+        {
+            let _ftz = ScopedFtz::enable();
+            assert_eq!(tiny(), 0.0);
+        }
+        assert!(tiny().is_subnormal());
+    }
+}
@@ -0,0 +1,116 @@
+//! 고정 크기 radix-2 FFT (힙 할당 없음)
+//! STFT 기반 프로세서에서 사용
+
+use crate::math;
+
+/// N점 복소 FFT (N은 2의 거듭제곱)
+pub struct Fft<const N: usize> {
+    cos_table: [f32; N],
+    sin_table: [f32; N],
+}
+
+impl<const N: usize> Fft<N> {
+    pub fn new() -> Self {
+        assert!(N.is_power_of_two(), "FFT size must be a power of two");
+        let mut cos_table = [0.0; N];
+        let mut sin_table = [0.0; N];
+        for k in 0..N / 2 {
+            let w = -2.0 * core::f32::consts::PI * k as f32 / N as f32;
+            cos_table[k] = math::cos(w);
+            sin_table[k] = math::sin(w);
+        }
+        Self {
+            cos_table,
+            sin_table,
+        }
+    }
+
+    /// 정방향 변환 (in-place)
+    pub fn forward(&self, re: &mut [f32; N], im: &mut [f32; N]) {
+        self.transform(re, im, false);
+    }
+
+    /// 역변환 (in-place, 1/N 스케일 포함)
+    pub fn inverse(&self, re: &mut [f32; N], im: &mut [f32; N]) {
+        self.transform(re, im, true);
+        let scale = 1.0 / N as f32;
+        for (r, i) in re.iter_mut().zip(im.iter_mut()) {
+            *r *= scale;
+            *i *= scale;
+        }
+    }
+
+    fn transform(&self, re: &mut [f32; N], im: &mut [f32; N], inverse: bool) {
+        // 비트 반전 순서로 재배열
+        let bits = N.trailing_zeros();
+        for i in 0..N {
+            let j = i.reverse_bits() >> (usize::BITS - bits);
+            if j > i {
+                re.swap(i, j);
+                im.swap(i, j);
+            }
+        }
+
+        let sign = if inverse { -1.0 } else { 1.0 };
+        let mut size = 2;
+        while size <= N {
+            let half = size / 2;
+            let stride = N / size;
+            for start in (0..N).step_by(size) {
+                for k in 0..half {
+                    let wr = self.cos_table[k * stride];
+                    let wi = sign * self.sin_table[k * stride];
+                    let a = start + k;
+                    let b = a + half;
+                    let tr = re[b] * wr - im[b] * wi;
+                    let ti = re[b] * wi + im[b] * wr;
+                    re[b] = re[a] - tr;
+                    im[b] = im[a] - ti;
+                    re[a] += tr;
+                    im[a] += ti;
+                }
+            }
+            size *= 2;
+        }
+    }
+}
+
+impl<const N: usize> Default for Fft<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fft_impulse() {
+        let fft = Fft::<8>::new();
+        let mut re = [0.0; 8];
+        let mut im = [0.0; 8];
+        re[0] = 1.0;
+        fft.forward(&mut re, &mut im);
+        assert!(re.iter().all(|&v| (v - 1.0).abs() < 1e-6));
+        assert!(im.iter().all(|&v| v.abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_fft_round_trip() {
+        let fft = Fft::<64>::new();
+        let original: [f32; 64] = core::array::from_fn(|n| ((n * 7) % 13) as f32 - 6.0);
+        let mut re = original;
+        let mut im = [0.0; 64];
+        fft.forward(&mut re, &mut im);
+        // Parseval: 시간/주파수 에너지 일치
+        let time_energy: f32 = original.iter().map(|v| v * v).sum();
+        let freq_energy: f32 = re.iter().zip(&im).map(|(r, i)| r * r + i * i).sum::<f32>() / 64.0;
+        assert!((time_energy - freq_energy).abs() / time_energy < 1e-4);
+
+        fft.inverse(&mut re, &mut im);
+        for (a, b) in re.iter().zip(&original) {
+            assert!((a - b).abs() < 1e-4);
+        }
+    }
+}
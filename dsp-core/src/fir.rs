@@ -0,0 +1,229 @@
+//! 선형 위상 FIR 필터 (윈도우 sinc)
+//! 오버샘플링 보간/데시메이션과 고품질 모드의 선형 위상 EQ에 사용
+
+use crate::math;
+use crate::processor_trait::{ProcessParams, Processor};
+use crate::sample::Sample;
+
+/// set_magnitude 주파수 샘플링 점 수 (0 ~ π, 탭 수보다 충분히 조밀하게)
+const DESIGN_POINTS: usize = 512;
+
+/// TAPS 탭 FIR (TAPS는 홀수: 지연 (TAPS - 1) / 2 샘플)
+#[derive(Clone, Debug)]
+pub struct Fir<const TAPS: usize, T: Sample = f32> {
+    coeffs: [T; TAPS],
+    history: [T; TAPS],
+    pos: usize,
+}
+
+impl<const TAPS: usize, T: Sample> Fir<TAPS, T> {
+    /// Blackman 윈도우 sinc Lowpass (cutoff: 샘플레이트 대비 정규화, DC 게인 1)
+    pub fn lowpass(cutoff: f32) -> Self {
+        let mut fir = Self {
+            coeffs: [T::ZERO; TAPS],
+            history: [T::ZERO; TAPS],
+            pos: 0,
+        };
+        fir.set_lowpass(cutoff);
+        fir
+    }
+
+    /// 진폭 응답 magnitude(w)를 따르는 선형 위상 FIR (set_magnitude 참고)
+    pub fn from_magnitude(magnitude: impl Fn(f64) -> f64) -> Self {
+        let mut fir = Self {
+            coeffs: [T::ZERO; TAPS],
+            history: [T::ZERO; TAPS],
+            pos: 0,
+        };
+        fir.set_magnitude(magnitude);
+        fir
+    }
+
+    /// 상태는 유지하고 컷오프만 변경 (계수 설계는 f64)
+    pub fn set_lowpass(&mut self, cutoff: f32) {
+        let cutoff = cutoff.clamp(0.001, 0.499) as f64;
+        let center = (TAPS - 1) as f64 / 2.0;
+        let pi = core::f64::consts::PI;
+        let mut coeffs = [0.0f64; TAPS];
+        for (n, coeff) in coeffs.iter_mut().enumerate() {
+            let t = n as f64 - center;
+            *coeff = if t == 0.0 {
+                2.0 * cutoff
+            } else {
+                math::double::sin(2.0 * pi * cutoff * t) / (pi * t)
+            };
+        }
+        self.set_windowed(coeffs, 1.0);
+    }
+
+    /// 상태는 유지하고 진폭 응답 magnitude(w)를 따르도록 재설계 (w: rad/sample, 0 ~ π)
+    /// 주파수 샘플링: h(t) = (1/π) ∫ A(w) cos(wt) dw 를 사다리꼴 적분한 뒤 윈도우, DC 게인은 magnitude(0)
+    /// 탭 길이보다 긴 응답(낮은 주파수의 좁은 공진)은 윈도우 폭만큼 완만해짐
+    pub fn set_magnitude(&mut self, magnitude: impl Fn(f64) -> f64) {
+        let center = (TAPS - 1) as f64 / 2.0;
+        let pi = core::f64::consts::PI;
+        let mut coeffs = [0.0f64; TAPS];
+        for k in 0..=DESIGN_POINTS {
+            let w = pi * k as f64 / DESIGN_POINTS as f64;
+            let edge = if k == 0 || k == DESIGN_POINTS {
+                0.5
+            } else {
+                1.0
+            };
+            let weight = edge * magnitude(w) / DESIGN_POINTS as f64;
+            // cos(w(t + 1)) = 2cos(w)cos(wt) - cos(w(t - 1)) 점화식 (탭마다 cos 호출 없이)
+            let step = 2.0 * math::double::cos(w);
+            let mut previous = math::double::cos(-w * (center + 1.0));
+            let mut current = math::double::cos(-w * center);
+            for coeff in coeffs.iter_mut() {
+                *coeff += weight * current;
+                let next = step * current - previous;
+                previous = current;
+                current = next;
+            }
+        }
+        self.set_windowed(coeffs, magnitude(0.0));
+    }
+
+    /// Blackman 윈도우 적용 후 DC 게인을 dc_gain으로 정규화
+    fn set_windowed(&mut self, mut coeffs: [f64; TAPS], dc_gain: f64) {
+        let span = (TAPS - 1).max(1) as f64;
+        let pi = core::f64::consts::PI;
+        for (n, coeff) in coeffs.iter_mut().enumerate() {
+            let phase = 2.0 * pi * n as f64 / span;
+            let window =
+                0.42 - 0.5 * math::double::cos(phase) + 0.08 * math::double::cos(2.0 * phase);
+            *coeff *= window;
+        }
+        let sum: f64 = coeffs.iter().sum();
+        // Blackman 창 끝 탭(~1e-17)은 0: 무음 꼬리에서 탭 곱이 비정규화 수로 내려가지 않도록
+        for (coeff, designed) in self.coeffs.iter_mut().zip(coeffs) {
+            *coeff = T::from_f64(designed * dc_gain / sum).flush_denormal();
+        }
+    }
+
+    #[inline]
+    pub fn process(&mut self, input: T) -> T {
+        self.history[self.pos] = input;
+        // history[pos]가 가장 최근 샘플: coeffs[0] * x[n] + coeffs[1] * x[n-1] + ...
+        let (older, newer) = self.history.split_at(self.pos + 1);
+        let recent = newer.len();
+        let mut y = T::ZERO;
+        for (coeff, x) in self.coeffs.iter().zip(older.iter().rev()) {
+            y += *coeff * *x;
+        }
+        for (coeff, x) in self.coeffs[TAPS - recent..].iter().zip(newer.iter().rev()) {
+            y += *coeff * *x;
+        }
+        self.pos = (self.pos + 1) % TAPS;
+        y
+    }
+
+    pub fn reset(&mut self) {
+        self.history = [T::ZERO; TAPS];
+        self.pos = 0;
+    }
+}
+
+impl<const TAPS: usize, T: Sample> Processor for Fir<TAPS, T> {
+    fn process(&mut self, input: &[f32], output: &mut [f32], _params: &ProcessParams) {
+        for (x, y) in input.iter().zip(output.iter_mut()) {
+            *y = Fir::process(self, T::from_f32(*x)).to_f32();
+        }
+    }
+
+    fn process_sample(&mut self, input: f32, _params: &ProcessParams) -> f32 {
+        Fir::process(self, T::from_f32(input)).to_f32()
+    }
+
+    fn reset(&mut self) {
+        Fir::reset(self);
+    }
+
+    fn name(&self) -> &str {
+        "Linear Phase FIR"
+    }
+
+    fn latency_samples(&self) -> usize {
+        (TAPS - 1) / 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_impulse_is_symmetric_and_delayed() {
+        let mut fir = Fir::<31>::lowpass(0.2);
+        let response: Vec<f32> = (0..31)
+            .map(|n| fir.process(if n == 0 { 1.0 } else { 0.0 }))
+            .collect();
+        let peak =
+            response.iter().enumerate().fold(
+                (0, 0.0f32),
+                |best, (n, &v)| if v > best.1 { (n, v) } else { best },
+            );
+        assert_eq!(peak.0, fir.latency_samples());
+        for n in 0..15 {
+            assert!((response[n] - response[30 - n]).abs() < 1e-6);
+        }
+        assert!((response.iter().sum::<f32>() - 1.0).abs() < 1e-4);
+    }
+
+    /// 계수로 직접 계산한 진폭 응답 (dB)
+    fn response_db<const TAPS: usize>(fir: &Fir<TAPS>, w: f64) -> f64 {
+        let (mut re, mut im) = (0.0, 0.0);
+        for (n, &coeff) in fir.coeffs.iter().enumerate() {
+            re += coeff as f64 * (w * n as f64).cos();
+            im -= coeff as f64 * (w * n as f64).sin();
+        }
+        10.0 * (re * re + im * im).log10()
+    }
+
+    #[test]
+    fn test_magnitude_matches_biquad_lowpass() {
+        use crate::biquad_wrapper::BiquadWrapper;
+        let pi = core::f64::consts::PI;
+        for (sample_rate, cutoff, q) in [
+            (48000.0f32, 12000.0f32, 0.707f32),
+            (44100.0, 16000.0, 0.707),
+            (48000.0, 8000.0, 2.0),
+            (96000.0, 20000.0, 4.0),
+        ] {
+            let biquad = BiquadWrapper::<f64>::lowpass(cutoff / sample_rate, q);
+            let fir = Fir::<127>::from_magnitude(|w| biquad.magnitude(w));
+            for ratio in [0.01, 0.25, 0.5, 0.8, 0.9, 1.0, 1.1] {
+                let w = 2.0 * pi * (cutoff * ratio / sample_rate) as f64;
+                let expected = 20.0 * biquad.magnitude(w).log10();
+                let error = response_db(&fir, w) - expected;
+                assert!(
+                    error.abs() < 0.75,
+                    "{cutoff} Hz Q {q} x{ratio}: {error:+.2} dB"
+                );
+            }
+        }
+
+        // 컷오프에서 Q 공진: Q 0.707 은 -3 dB, Q 2 는 +6 dB
+        let at_cutoff = |q| {
+            let biquad = BiquadWrapper::<f64>::lowpass(0.2, q);
+            let fir = Fir::<127>::from_magnitude(|w| biquad.magnitude(w));
+            response_db(&fir, 2.0 * pi * 0.2)
+        };
+        assert!(at_cutoff(2.0) - at_cutoff(0.707) > 8.0);
+    }
+
+    #[test]
+    fn test_lowpass_rejects_high_frequency() {
+        let mut fir = Fir::<63>::lowpass(0.1);
+        let mut peak = 0.0f32;
+        for n in 0..2000 {
+            let x = (2.0 * core::f32::consts::PI * 0.35 * n as f32).sin();
+            let y = fir.process(x);
+            if n > 100 {
+                peak = peak.max(y.abs());
+            }
+        }
+        assert!(peak < 0.01, "{peak}");
+    }
+}
@@ -0,0 +1,363 @@
+//! 고정소수점 처리 경로 (FPU 없는 마이크로컨트롤러용)
+//! Q15 / Q31 샘플, 포화 연산, 64비트 누산기
+//!
+//! f32 기준 구현과의 최대 절대 오차 (테스트로 확인):
+//! - Biquad / RIAA (Q31): 1e-4 이하
+//!   (RIAA 계수는 저역 게인이 최대 약 +35 dB: 입력을 -35 dBFS 이하로 두어야 포화 없음)
+//! - 소프트 클립 테이블 (Q15): 2e-4 이하
+//! - 리미터 (Q31): 1e-4 이하
+//!
+//! 계수 계산(sin/cos/exp)만 초기화 시 부동소수점 사용, 샘플 처리는 정수 연산만 사용
+
+use crate::biquad_wrapper::BiquadWrapper;
+use crate::math;
+use crate::riaa_eq_advanced::RIAACoefficients;
+
+/// Q1.15 샘플 (-1.0 <= x < 1.0)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Q15(pub i16);
+
+/// Q1.31 샘플 (-1.0 <= x < 1.0)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Q31(pub i32);
+
+impl Q15 {
+    pub const ONE: f32 = 32768.0;
+    pub const MAX: Self = Self(i16::MAX);
+
+    /// 범위를 벗어난 값은 포화
+    pub fn from_f32(x: f32) -> Self {
+        Self(math::round(x * Self::ONE).clamp(i16::MIN as f32, i16::MAX as f32) as i16)
+    }
+
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / Self::ONE
+    }
+
+    #[inline]
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    #[inline]
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    /// 반올림 곱셈 (-1 * -1만 포화)
+    #[inline]
+    pub fn saturating_mul(self, other: Self) -> Self {
+        let product = (self.0 as i32 * other.0 as i32 + (1 << 14)) >> 15;
+        Self(saturate_i16(product))
+    }
+}
+
+impl Q31 {
+    pub const ONE: f32 = 2_147_483_648.0;
+    pub const MAX: Self = Self(i32::MAX);
+
+    /// 범위를 벗어난 값은 포화 (f32 -> i32 변환은 포화 변환)
+    pub fn from_f32(x: f32) -> Self {
+        Self((x * Self::ONE) as i32)
+    }
+
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / Self::ONE
+    }
+
+    #[inline]
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    #[inline]
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    /// 반올림 곱셈 (-1 * -1만 포화)
+    #[inline]
+    pub fn saturating_mul(self, other: Self) -> Self {
+        let product = (self.0 as i64 * other.0 as i64 + (1 << 30)) >> 31;
+        Self(saturate_i32(product))
+    }
+}
+
+impl From<Q15> for Q31 {
+    fn from(x: Q15) -> Self {
+        Self((x.0 as i32) << 16)
+    }
+}
+
+impl From<Q31> for Q15 {
+    /// 반올림 후 포화
+    fn from(x: Q31) -> Self {
+        Self(saturate_i16((x.0 as i64 + (1 << 15)) >> 16))
+    }
+}
+
+#[inline]
+fn saturate_i16<T: Into<i64>>(x: T) -> i16 {
+    x.into().clamp(i16::MIN as i64, i16::MAX as i64) as i16
+}
+
+#[inline]
+fn saturate_i32(x: i64) -> i32 {
+    x.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+/// 계수 형식 Q3.29 (|계수| < 4: 필터 a1 ~ -2 포함)
+const COEFF_FRAC_BITS: u32 = 29;
+
+fn coeff_from_f32(c: f32) -> i32 {
+    (c * (1u32 << COEFF_FRAC_BITS) as f32) as i32
+}
+
+/// Q31 Biquad (Direct Form I, 64비트 누산기)
+/// 고정소수점에서는 내부 상태가 샘플 범위를 넘지 않는 DF1 사용
+#[derive(Clone, Debug)]
+pub struct FixedBiquad {
+    /// [b0, b1, b2, a1, a2] (Q3.29)
+    coeffs: [i32; 5],
+    x1: i32,
+    x2: i32,
+    y1: i32,
+    y2: i32,
+}
+
+impl FixedBiquad {
+    /// f32 계수 [b0, b1, b2, a1, a2] (a0 = 1 정규화)
+    pub fn from_coefficients(coeffs: [f32; 5]) -> Self {
+        Self {
+            coeffs: coeffs.map(coeff_from_f32),
+            x1: 0,
+            x2: 0,
+            y1: 0,
+            y2: 0,
+        }
+    }
+
+    /// f32 Biquad와 같은 계수 (lowpass / highpass / notch / shelf 생성자 재사용)
+    pub fn from_biquad(biquad: &BiquadWrapper) -> Self {
+        Self::from_coefficients(biquad.coefficients())
+    }
+
+    /// 샘플레이트별 RIAA 계수 (RIAAEQAdvanced와 같은 곡선)
+    pub fn riaa(sample_rate: u32) -> Self {
+        let c = RIAACoefficients::calculate(sample_rate);
+        Self::from_coefficients(c.to_array().map(|c| c as f32))
+    }
+
+    #[inline]
+    pub fn process(&mut self, x: Q31) -> Q31 {
+        let [b0, b1, b2, a1, a2] = self.coeffs.map(i64::from);
+        let acc = (b0 * x.0 as i64)
+            .saturating_add(b1 * self.x1 as i64)
+            .saturating_add(b2 * self.x2 as i64)
+            .saturating_sub(a1 * self.y1 as i64)
+            .saturating_sub(a2 * self.y2 as i64);
+        let y = saturate_i32((acc + (1 << (COEFF_FRAC_BITS - 1))) >> COEFF_FRAC_BITS);
+        self.x2 = self.x1;
+        self.x1 = x.0;
+        self.y2 = self.y1;
+        self.y1 = y;
+        Q31(y)
+    }
+
+    /// Q15 입출력 (내부는 Q31)
+    #[inline]
+    pub fn process_q15(&mut self, x: Q15) -> Q15 {
+        self.process(x.into()).into()
+    }
+
+    pub fn reset(&mut self) {
+        self.x1 = 0;
+        self.x2 = 0;
+        self.y1 = 0;
+        self.y2 = 0;
+    }
+}
+
+/// 소프트 클립 테이블 구간 수 (입력 -1.0..1.0)
+const SOFT_CLIP_SEGMENTS: usize = 256;
+
+/// crate::soft_clip (x - x^3 / 3)의 Q15 테이블, 컴파일 시 정수 연산으로 생성
+const SOFT_CLIP_TABLE: [i16; SOFT_CLIP_SEGMENTS + 1] = soft_clip_table();
+
+const fn soft_clip_table() -> [i16; SOFT_CLIP_SEGMENTS + 1] {
+    let mut table = [0i16; SOFT_CLIP_SEGMENTS + 1];
+    let mut i = 0;
+    while i <= SOFT_CLIP_SEGMENTS {
+        // x (Q15) = -1.0 + 2.0 * i / SEGMENTS
+        let x = (i as i64 - (SOFT_CLIP_SEGMENTS / 2) as i64) * (65536 / SOFT_CLIP_SEGMENTS as i64);
+        let cube = (x * x * x) >> 30;
+        table[i] = (x - cube / 3) as i16;
+        i += 1;
+    }
+    table
+}
+
+/// 테이블 + 선형 보간 소프트 클립 (Q15)
+/// 입력은 이미 포화되어 있으므로 |x| >= 1 구간(상수 2/3)과 일치
+#[inline]
+pub fn soft_clip_q15(x: Q15) -> Q15 {
+    let position = (x.0 as i32 + 32768) as usize;
+    let shift = 16 - SOFT_CLIP_SEGMENTS.trailing_zeros();
+    let index = position >> shift;
+    let frac = (position & ((1 << shift) - 1)) as i32;
+    let a = SOFT_CLIP_TABLE[index] as i32;
+    let b = SOFT_CLIP_TABLE[index + 1] as i32;
+    Q15((a + (((b - a) * frac) >> shift)) as i16)
+}
+
+/// Q15 드라이브 + 소프트 클립 (드라이브 배율 = gain / 2^shift, 포화 후 테이블 조회)
+#[inline]
+pub fn drive_soft_clip_q15(x: Q15, gain: i32, shift: u32) -> Q15 {
+    let driven = saturate_i16((x.0 as i64 * gain as i64) >> shift);
+    soft_clip_q15(Q15(driven))
+}
+
+/// Q31 피크 리미터 (f32 Limiter와 같은 순간 어택 / 지수 릴리즈)
+#[derive(Clone, Debug)]
+pub struct FixedLimiter {
+    threshold: i32,
+    /// 릴리즈 계수 (Q31)
+    release_coeff: i32,
+    envelope: i32,
+    /// 마지막 샘플의 게인 (Q31, 1.0 = i32::MAX)
+    gain: i32,
+}
+
+impl FixedLimiter {
+    /// Limiter::new와 같은 인자 (threshold 0.0-1.0, release_time: 샘플 단위 시정수)
+    pub fn new(threshold: f32, release_time: f32) -> Self {
+        let release_time = release_time.max(0.001);
+        Self {
+            threshold: Q31::from_f32(threshold.clamp(0.0, 1.0)).0,
+            release_coeff: Q31::from_f32(math::exp(-1.0 / release_time)).0,
+            envelope: 0,
+            gain: i32::MAX,
+        }
+    }
+
+    #[inline]
+    pub fn process(&mut self, input: Q31) -> Q31 {
+        let abs_input = input.0.saturating_abs();
+        if abs_input > self.threshold {
+            self.envelope = abs_input;
+        } else {
+            self.envelope = Q31(self.envelope).saturating_mul(Q31(self.release_coeff)).0;
+        }
+
+        // 엔벨로프가 threshold 아래면 게인 1 (증폭하지 않음)
+        self.gain = if self.envelope > self.threshold {
+            saturate_i32(((self.threshold as i64) << 31) / self.envelope as i64)
+        } else {
+            i32::MAX
+        };
+        if self.gain == i32::MAX {
+            input
+        } else {
+            input.saturating_mul(Q31(self.gain))
+        }
+    }
+
+    /// Q15 입출력 (내부는 Q31)
+    #[inline]
+    pub fn process_q15(&mut self, x: Q15) -> Q15 {
+        self.process(x.into()).into()
+    }
+
+    /// 마지막 샘플의 게인 (Q31)
+    pub fn gain(&self) -> Q31 {
+        Q31(self.gain)
+    }
+
+    pub fn reset(&mut self) {
+        self.envelope = 0;
+        self.gain = i32::MAX;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{soft_clip, Limiter, RIAAEQAdvanced};
+
+    fn sine(n: usize, freq: f32, amplitude: f32) -> f32 {
+        amplitude * (2.0 * core::f32::consts::PI * freq * n as f32).sin()
+    }
+
+    #[test]
+    fn test_q_format_saturates() {
+        assert_eq!(Q15::from_f32(2.0), Q15::MAX);
+        assert_eq!(Q15::from_f32(-2.0), Q15(i16::MIN));
+        assert_eq!(Q31::from_f32(1.0), Q31::MAX);
+        assert_eq!(Q15(i16::MIN).saturating_mul(Q15(i16::MIN)), Q15::MAX);
+        assert_eq!(Q31(i32::MAX).saturating_add(Q31(1)), Q31::MAX);
+        assert_eq!(Q15::from(Q31::from(Q15(-1234))), Q15(-1234));
+        assert!((Q15::from_f32(0.25).to_f32() - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_biquad_matches_f32() {
+        let mut reference = BiquadWrapper::lowpass(0.05, 0.707);
+        let mut fixed = FixedBiquad::from_biquad(&reference);
+        let mut max_error = 0.0f32;
+        for n in 0..4800 {
+            let x = sine(n, 0.01, 0.5) + sine(n, 0.2, 0.3);
+            let expected = reference.process(x);
+            let y = fixed.process(Q31::from_f32(x)).to_f32();
+            max_error = max_error.max((y - expected).abs());
+        }
+        assert!(max_error < 1e-4, "{max_error}");
+    }
+
+    #[test]
+    fn test_riaa_matches_f32() {
+        for sample_rate in [44100, 48000] {
+            let mut reference = RIAAEQAdvanced::new(sample_rate);
+            let mut fixed = FixedBiquad::riaa(sample_rate);
+            let mut max_error = 0.0f32;
+            for n in 0..48000 {
+                // 포노 입력 레벨 (-40 dBFS): 게인 후에도 포화 없음
+                let x = sine(n, 100.0 / sample_rate as f32, 0.01);
+                let expected = reference.process(x);
+                let y = fixed.process(Q31::from_f32(x)).to_f32();
+                max_error = max_error.max((y - expected).abs());
+            }
+            assert!(max_error < 1e-4, "{sample_rate}: {max_error}");
+        }
+    }
+
+    #[test]
+    fn test_soft_clip_table_matches_f32() {
+        let mut max_error = 0.0f32;
+        for raw in (i16::MIN..=i16::MAX).step_by(7) {
+            let x = Q15(raw);
+            let expected = soft_clip(x.to_f32());
+            max_error = max_error.max((soft_clip_q15(x).to_f32() - expected).abs());
+        }
+        assert!(max_error < 2e-4, "{max_error}");
+        // 드라이브 x4: 포화 구간은 2/3
+        let driven = drive_soft_clip_q15(Q15::from_f32(0.5), 4, 0);
+        assert!((driven.to_f32() - 2.0 / 3.0).abs() < 2e-4);
+    }
+
+    #[test]
+    fn test_limiter_matches_f32() {
+        let mut reference = Limiter::new(0.5, 200.0);
+        let mut fixed = FixedLimiter::new(0.5, 200.0);
+        let mut max_error = 0.0f32;
+        for n in 0..4800 {
+            // 구간별 레벨 변화: 리미팅 -> 릴리즈 -> 리미팅
+            let amplitude = if (n / 1200) % 2 == 0 { 0.9 } else { 0.2 };
+            let x = sine(n, 0.013, amplitude);
+            let expected = reference.process(x);
+            let y = fixed.process(Q31::from_f32(x)).to_f32();
+            max_error = max_error.max((y - expected).abs());
+            assert!(y.abs() <= 0.5 + 1e-6);
+        }
+        assert!(max_error < 1e-4, "{max_error}");
+    }
+}
@@ -0,0 +1,374 @@
+//! 험(50/60Hz 전원 잡음) / 럼블 제거
+//! Goertzel로 험 기본 주파수 검출 -> 고조파 추적 노치 캐스케이드, 가파른 서브소닉 하이패스
+
+use crate::biquad_wrapper::BiquadWrapper;
+use crate::math;
+
+/// 노치를 걸 수 있는 최대 고조파 수
+pub const MAX_HARMONICS: usize = 8;
+/// 검출에 사용하는 고조파 수
+const DETECT_HARMONICS: usize = 3;
+/// 기본 주파수 후보 오프셋 (Hz, 전원 주파수 편차)
+const OFFSETS: [f32; 5] = [-1.0, -0.5, 0.0, 0.5, 1.0];
+/// 기준(배경) 레벨 측정 오프셋 (Hz, 고조파 차수 배)
+const REFERENCE_OFFSET: f32 = 3.0;
+/// 험 판정: 후보 파워 / 배경 파워
+const DETECT_RATIO: f32 = 20.0;
+/// 험 판정: 윈도우 전체 파워 대비 최소 비율 (-50dB)
+const MIN_FRACTION: f32 = 1e-5;
+const MAINS: [f32; 2] = [50.0, 60.0];
+const CANDIDATES: usize = OFFSETS.len() * DETECT_HARMONICS;
+const REFERENCES: usize = 2 * DETECT_HARMONICS;
+const DETECTORS: usize = CANDIDATES + REFERENCES;
+
+/// 단일 주파수 파워 측정 (Goertzel)
+#[derive(Clone, Copy, Default)]
+struct Goertzel {
+    coeff: f32,
+    s1: f32,
+    s2: f32,
+}
+
+impl Goertzel {
+    fn new(freq: f32, sample_rate: f32) -> Self {
+        Self {
+            coeff: 2.0 * math::cos(2.0 * core::f32::consts::PI * freq / sample_rate),
+            s1: 0.0,
+            s2: 0.0,
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, x: f32) {
+        let s = x + self.coeff * self.s1 - self.s2;
+        self.s2 = self.s1;
+        self.s1 = s;
+    }
+
+    /// 누적 파워를 반환하고 상태 초기화
+    fn take_power(&mut self) -> f32 {
+        let power = self.s1 * self.s1 + self.s2 * self.s2 - self.coeff * self.s1 * self.s2;
+        self.s1 = 0.0;
+        self.s2 = 0.0;
+        power.max(0.0)
+    }
+}
+
+/// 험 제거기
+/// 검출 윈도우(기본 1초)마다 50/60Hz 계열을 검출하고 노치 주파수를 갱신 (지연 없음)
+/// 좁은 저역 노치(Q 최대 100)는 극점이 단위원에 가까우므로 노치 상태는 f64
+pub struct HumRemover {
+    sample_rate: f32,
+    /// [50Hz 계열, 60Hz 계열] 각각 후보(오프셋 x 고조파) + 배경 기준
+    detectors: [[Goertzel; DETECTORS]; 2],
+    window: usize,
+    counter: usize,
+    energy: f32,
+    fixed: Option<f32>,
+    fundamental: Option<f32>,
+    notches: [BiquadWrapper<f64>; MAX_HARMONICS],
+    harmonics: usize,
+    q: f32,
+}
+
+impl HumRemover {
+    /// harmonics: 노치 개수 (1-8), q: 노치 Q (클수록 좁음)
+    pub fn new(sample_rate: f32, harmonics: usize, q: f32) -> Self {
+        let mut remover = Self {
+            sample_rate,
+            detectors: [[Goertzel::default(); DETECTORS]; 2],
+            window: (sample_rate as usize).max(1),
+            counter: 0,
+            energy: 0.0,
+            fixed: None,
+            fundamental: None,
+            notches: core::array::from_fn(|_| BiquadWrapper::new(1.0, 0.0, 0.0, 0.0, 0.0)),
+            harmonics: 1,
+            q: 30.0,
+        };
+        for (detectors, mains) in remover.detectors.iter_mut().zip(MAINS) {
+            for (h, harmonic) in (1..=DETECT_HARMONICS).enumerate() {
+                let order = harmonic as f32;
+                for (i, offset) in OFFSETS.iter().enumerate() {
+                    detectors[i * DETECT_HARMONICS + h] =
+                        Goertzel::new((mains + offset) * order, sample_rate);
+                }
+                for (j, sign) in [-1.0, 1.0].into_iter().enumerate() {
+                    let freq = (mains + sign * REFERENCE_OFFSET) * order;
+                    detectors[CANDIDATES + j * DETECT_HARMONICS + h] =
+                        Goertzel::new(freq, sample_rate);
+                }
+            }
+        }
+        remover.set_harmonics(harmonics);
+        remover.set_q(q);
+        remover
+    }
+
+    /// 값이 바뀔 때만 재설계 (블록마다 호출해도 됨)
+    pub fn set_harmonics(&mut self, harmonics: usize) {
+        let harmonics = harmonics.clamp(1, MAX_HARMONICS);
+        if harmonics == self.harmonics {
+            return;
+        }
+        self.harmonics = harmonics;
+        self.retune();
+    }
+
+    pub fn set_q(&mut self, q: f32) {
+        self.q = q.clamp(1.0, 100.0);
+        self.retune();
+    }
+
+    /// 검출 윈도우 길이 (0.5초 이상, 진행 중인 윈도우는 버림)
+    pub fn set_detection_window(&mut self, seconds: f32) {
+        let window = ((seconds.max(0.5) * self.sample_rate) as usize).max(1);
+        if window != self.window {
+            self.window = window;
+            for detector in self.detectors.iter_mut().flatten() {
+                detector.take_power();
+            }
+            self.counter = 0;
+            self.energy = 0.0;
+        }
+    }
+
+    /// 기본 주파수 고정 (None이면 자동 검출)
+    pub fn set_fundamental(&mut self, fundamental: Option<f32>) {
+        self.fixed = fundamental.map(|f| f.clamp(20.0, 200.0));
+        if self.fixed.is_some() {
+            self.fundamental = self.fixed;
+        }
+        self.retune();
+    }
+
+    /// 현재 노치가 걸린 기본 주파수 (검출 전에는 None -> 통과)
+    pub fn fundamental(&self) -> Option<f32> {
+        self.fundamental
+    }
+
+    pub fn process(&mut self, input: f32) -> f32 {
+        if self.fixed.is_none() {
+            for detector in self.detectors.iter_mut().flatten() {
+                detector.push(input);
+            }
+            self.energy += input * input;
+            self.counter += 1;
+            if self.counter == self.window {
+                self.counter = 0;
+                self.detect();
+            }
+        }
+
+        if self.fundamental.is_none() {
+            return input;
+        }
+        self.notches[..self.harmonics]
+            .iter_mut()
+            .fold(input as f64, |y, notch| notch.process(y)) as f32
+    }
+
+    /// 필터/검출 상태 초기화 (검출된 주파수는 유지)
+    pub fn reset(&mut self) {
+        for detector in self.detectors.iter_mut().flatten() {
+            detector.take_power();
+        }
+        self.counter = 0;
+        self.energy = 0.0;
+        for notch in &mut self.notches {
+            notch.reset();
+        }
+    }
+
+    /// 검출 결과 삭제
+    pub fn clear_detection(&mut self) {
+        self.fundamental = self.fixed;
+        self.reset();
+    }
+
+    fn detect(&mut self) {
+        // 진폭 A 정현파: Goertzel 파워 (A N / 2)^2, 윈도우 에너지 N A^2 / 2
+        let min_score = MIN_FRACTION * self.energy * self.window as f32 / 2.0;
+        self.energy = 0.0;
+        let mut best: Option<(f32, f32)> = None;
+        for (detectors, mains) in self.detectors.iter_mut().zip(MAINS) {
+            let mut powers = [0.0f32; DETECTORS];
+            for (power, detector) in powers.iter_mut().zip(detectors.iter_mut()) {
+                *power = detector.take_power();
+            }
+
+            // 오프셋별 고조파 파워 합
+            let mut scores = [0.0f32; OFFSETS.len()];
+            for (score, chunk) in scores
+                .iter_mut()
+                .zip(powers[..CANDIDATES].chunks(DETECT_HARMONICS))
+            {
+                *score = chunk.iter().sum();
+            }
+            let reference = powers[CANDIDATES..].iter().sum::<f32>() / 2.0;
+
+            let (peak, &score) = scores
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .unwrap_or((0, &0.0));
+            let ratio = score / reference.max(1e-12);
+            if score <= min_score || ratio < DETECT_RATIO {
+                continue;
+            }
+
+            // 포물선 보간으로 오프셋 미세 조정
+            let mut offset = OFFSETS[peak];
+            if peak > 0 && peak + 1 < OFFSETS.len() {
+                let (l, c, r) = (scores[peak - 1], score, scores[peak + 1]);
+                let denom = l - 2.0 * c + r;
+                if denom.abs() > 1e-12 {
+                    let step = OFFSETS[1] - OFFSETS[0];
+                    offset += (0.5 * (l - r) / denom).clamp(-0.5, 0.5) * step;
+                }
+            }
+
+            if best.is_none_or(|(_, best_ratio)| ratio > best_ratio) {
+                best = Some((mains + offset, ratio));
+            }
+        }
+
+        if let Some((freq, _)) = best {
+            self.fundamental = Some(match self.fundamental {
+                // 급격한 노치 이동 방지
+                Some(previous) if (previous - freq).abs() < 2.0 => 0.5 * (previous + freq),
+                _ => freq,
+            });
+            self.retune();
+        }
+    }
+
+    /// 노치 계수만 갱신 (필터 상태 유지)
+    fn retune(&mut self) {
+        let Some(fundamental) = self.fundamental else {
+            return;
+        };
+        for (h, notch) in self.notches.iter_mut().enumerate() {
+            let center = fundamental * (h + 1) as f32 / self.sample_rate;
+            // 나이퀴스트 근처 고조파는 통과
+            let coefficients = if center < 0.45 {
+                BiquadWrapper::notch(center, self.q)
+            } else {
+                BiquadWrapper::new(1.0, 0.0, 0.0, 0.0, 0.0)
+            };
+            notch.set_coefficients(&coefficients);
+        }
+    }
+}
+
+/// 8차 Butterworth 섹션 Q (48dB/oct)
+const BUTTERWORTH_Q: [f32; 4] = [0.5098, 0.6013, 0.9000, 2.5629];
+
+/// 서브소닉 럼블 필터 (8차 Butterworth 하이패스)
+/// 차단 주파수가 샘플레이트에 비해 매우 낮으므로 내부 상태는 f64
+pub struct RumbleFilter {
+    sample_rate: f32,
+    sections: [BiquadWrapper<f64>; 4],
+    /// 현재 계수의 정규화 차단 주파수
+    cutoff: f32,
+}
+
+impl RumbleFilter {
+    /// cutoff_hz: 차단 주파수 (10-60Hz)
+    pub fn new(sample_rate: f32, cutoff_hz: f32) -> Self {
+        let mut filter = Self {
+            sample_rate,
+            sections: core::array::from_fn(|_| BiquadWrapper::new(1.0, 0.0, 0.0, 0.0, 0.0)),
+            cutoff: f32::NAN,
+        };
+        filter.set_cutoff(cutoff_hz);
+        filter
+    }
+
+    /// 상태 유지하며 차단 주파수 변경 (값이 바뀔 때만 재설계)
+    pub fn set_cutoff(&mut self, cutoff_hz: f32) {
+        let cutoff = cutoff_hz.clamp(10.0, 60.0) / self.sample_rate;
+        if cutoff == self.cutoff {
+            return;
+        }
+        self.cutoff = cutoff;
+        for (section, q) in self.sections.iter_mut().zip(BUTTERWORTH_Q) {
+            section.set_coefficients(&BiquadWrapper::highpass(cutoff, q));
+        }
+    }
+
+    pub fn process(&mut self, input: f32) -> f32 {
+        self.sections
+            .iter_mut()
+            .fold(input as f64, |y, section| section.process(y)) as f32
+    }
+
+    pub fn reset(&mut self) {
+        for section in &mut self.sections {
+            section.reset();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48000.0;
+
+    fn sine(freq: f32, amp: f32, n: usize) -> f32 {
+        amp * (2.0 * core::f32::consts::PI * freq * n as f32 / SR).sin()
+    }
+
+    fn rms(signal: &[f32]) -> f32 {
+        (signal.iter().map(|s| s * s).sum::<f32>() / signal.len() as f32).sqrt()
+    }
+
+    #[test]
+    fn test_detects_and_removes_hum() {
+        let tone: Vec<f32> = (0..SR as usize * 4).map(|n| sine(1000.0, 0.3, n)).collect();
+        let hum: Vec<f32> = (0..tone.len())
+            .map(|n| sine(60.3, 0.1, n) + sine(120.6, 0.05, n) + sine(180.9, 0.03, n))
+            .collect();
+        let mut remover = HumRemover::new(SR, 4, 30.0);
+        let output: Vec<f32> = tone
+            .iter()
+            .zip(&hum)
+            .map(|(t, h)| remover.process(t + h))
+            .collect();
+
+        let fundamental = remover.fundamental().expect("hum not detected");
+        assert!((fundamental - 60.3).abs() < 0.2, "detected {fundamental}");
+
+        let tail = tone.len() - SR as usize..tone.len();
+        let residual: Vec<f32> = output[tail.clone()]
+            .iter()
+            .zip(&tone[tail])
+            .map(|(y, t)| y - t)
+            .collect();
+        assert!(rms(&residual) < 0.01);
+    }
+
+    #[test]
+    fn test_no_hum_passes_through() {
+        let mut remover = HumRemover::new(SR, 4, 30.0);
+        for n in 0..SR as usize * 2 {
+            let x = sine(440.0, 0.5, n);
+            assert_eq!(remover.process(x), x);
+        }
+        assert!(remover.fundamental().is_none());
+    }
+
+    #[test]
+    fn test_rumble_filter() {
+        let mut filter = RumbleFilter::new(SR, 25.0);
+        let output: Vec<f32> = (0..SR as usize)
+            .map(|n| filter.process(sine(8.0, 0.5, n) + sine(1000.0, 0.1, n)))
+            .collect();
+        let tail = &output[SR as usize / 2..];
+        let expected: Vec<f32> = (SR as usize / 2..SR as usize)
+            .map(|n| sine(1000.0, 0.1, n))
+            .collect();
+        assert!((rms(tail) - rms(&expected)).abs() < 0.005);
+    }
+}
@@ -1,5 +1,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(not(any(feature = "std", feature = "no_std")))]
+compile_error!("dsp-core: enable either the `std` feature (default) or `no_std` (libm)");
+
+mod math;
+
 pub mod analyzer_advanced;
 pub mod magnetic_eq;
 pub mod processor_trait;
@@ -7,6 +12,31 @@ pub mod riaa_eq_advanced;
 pub mod parallel_filter_advanced;
 pub mod biquad_wrapper;
 pub mod limiter;
+pub mod declicker;
+pub mod fft;
+pub mod noise_reducer;
+pub mod hum_remover;
+pub mod tape_chain;
+pub mod preset_chain;
+pub mod smoothing;
+pub mod delay_line;
+pub mod denormal;
+pub mod meters;
+pub mod response;
+pub mod fir;
+pub mod oversampler;
+pub mod quality;
+pub mod sample;
+pub mod simd;
+pub mod preset;
+#[cfg(feature = "std")]
+pub mod midi_map;
+#[cfg(feature = "std")]
+pub mod param_edits;
+#[cfg(feature = "fixed-point")]
+pub mod fixed_point;
+#[cfg(feature = "preset-files")]
+pub mod preset_file;
 
 // Re-exports
 pub use analyzer_advanced::VelocityAnalyzer;
@@ -16,6 +46,30 @@ pub use riaa_eq_advanced::RIAAEQAdvanced;
 pub use parallel_filter_advanced::ParallelFilterAdvanced;
 pub use biquad_wrapper::BiquadWrapper;
 pub use limiter::Limiter;
+pub use declicker::Declicker;
+pub use noise_reducer::{NoiseProfileMode, NoiseReducer};
+pub use hum_remover::{HumRemover, RumbleFilter};
+pub use tape_chain::{TapeChain, TapeRamps};
+pub use preset_chain::{ChainRamps, OutputStage, PresetChain, Restoration};
+pub use smoothing::{ParamRamp, RampStyle, SmoothedParam};
+pub use delay_line::DelayLine;
+pub use denormal::ScopedFtz;
+pub use meters::{AtomicF32, LevelMeter, MeterSnapshot, Meters, ProcessingPath};
+pub use response::chain_response_db;
+pub use fir::Fir;
+pub use oversampler::Oversampler;
+pub use quality::Quality;
+pub use sample::Sample;
+pub use simd::{peak_and_sum_squares, soft_clip_block, BiquadX4, F32x4};
+pub use preset::{ChainPreset, FieldRange, BUILTIN_PRESET_NAMES};
+#[cfg(feature = "std")]
+pub use midi_map::{CcMapping, MidiMap};
+#[cfg(feature = "std")]
+pub use param_edits::ParamEdits;
+#[cfg(feature = "fixed-point")]
+pub use fixed_point::{drive_soft_clip_q15, soft_clip_q15, FixedBiquad, FixedLimiter, Q15, Q31};
+#[cfg(feature = "preset-files")]
+pub use preset_file::{PresetBank, PresetError, PresetFile, PresetFormat, PRESET_SCHEMA_VERSION};
 
 /// Legacy API support for plugins
 
@@ -37,11 +91,28 @@ pub fn process_block(
     _sample_rate: f32,
     params: &DspParams
 ) {
-    let drive = db_to_linear_simple(params.drive_db);
-    for (in_sample, out_sample) in input.iter().zip(output.iter_mut()) {
-        let x = *in_sample * drive;
-        let y = soft_clip(x);
-        *out_sample = y;
+    process_block_ramped(input, output, _sample_rate, ParamRamp::Constant(params.drive_db));
+}
+
+/// 레거시 드라이브 범위 (dB, 무한대 게인 * 0 = NaN 방지)
+const MAX_DRIVE_DB: f32 = 96.0;
+
+/// 샘플별 드라이브 램프를 받는 블록 처리 (자동화 지퍼 노이즈 없음)
+pub fn process_block_ramped(
+    input: &[f32],
+    output: &mut [f32],
+    _sample_rate: f32,
+    drive_db: ParamRamp,
+) {
+    let mut last_db = f32::NAN;
+    let mut drive = 1.0;
+    for (n, (in_sample, out_sample)) in input.iter().zip(output.iter_mut()).enumerate() {
+        let db = drive_db.at(n);
+        if db != last_db {
+            drive = db_to_linear_simple(db.clamp(-MAX_DRIVE_DB, MAX_DRIVE_DB));
+            last_db = db;
+        }
+        *out_sample = soft_clip(*in_sample * drive);
     }
 }
 
@@ -49,25 +120,29 @@ pub fn process_block(
 #[inline]
 pub fn soft_clip(x: f32) -> f32 {
     if x.abs() < 1.0 {
-        x - (x.powi(3)) / 3.0
+        x - (x * x * x) / 3.0
     } else {
         x.signum() * (2.0 / 3.0)
     }
 }
 
-/// dB to Linear 변환
+/// 프리셋 체인 드라이브 단계 soft clip: ±1 안쪽은 선형, 바깥은 완만한 무릎 (출력 상한 없음)
 #[inline]
-pub fn db_to_linear_simple(db: f32) -> f32 {
-    #[cfg(feature = "std")]
-    {
-        10.0_f32.powf(db / 20.0)
+pub fn knee_clip(x: f32) -> f32 {
+    const THRESHOLD: f32 = 1.0;
+    if x > THRESHOLD {
+        THRESHOLD + (x - THRESHOLD) / (1.0 + (x - THRESHOLD).abs())
+    } else if x < -THRESHOLD {
+        -THRESHOLD + (x + THRESHOLD) / (1.0 + (x + THRESHOLD).abs())
+    } else {
+        x
     }
+}
 
-    #[cfg(not(feature = "std"))]
-    {
-        use libm::pow10f;
-        pow10f(db / 20.0)
-    }
+/// dB to Linear 변환
+#[inline]
+pub fn db_to_linear_simple(db: f32) -> f32 {
+    crate::math::exp10(db / 20.0)
 }
 
 #[cfg(test)]
@@ -99,5 +174,18 @@ mod tests {
         process_block(&input, &mut output, 48000.0, &params);
         assert!(output[0] != 0.0);
     }
+
+    #[test]
+    fn test_process_block_ramped_matches_constant() {
+        let input = vec![0.5; 4];
+        let mut constant = vec![0.0; 4];
+        process_block(&input, &mut constant, 48000.0, &DspParams::new(3.0));
+
+        let ramp = [0.0, 1.0, 2.0, 3.0];
+        let mut ramped = vec![0.0; 4];
+        process_block_ramped(&input, &mut ramped, 48000.0, ParamRamp::PerSample(&ramp));
+        assert_eq!(ramped[3], constant[3]);
+        assert!(ramped[0] < ramped[3]);
+    }
 }
 
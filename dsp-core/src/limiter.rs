@@ -1,39 +1,87 @@
-/// Limiter - 피크 클리핑 방지
+//! Limiter - 피크 클리핑 방지
 
-pub struct Limiter {
-    threshold: f32,
-    release_time: f32,
-    envelope: f32,
+use crate::math;
+use crate::processor_trait::{ProcessParams, Processor};
+use crate::sample::Sample;
+
+pub struct Limiter<T: Sample = f32> {
+    threshold: T,
+    release_time: T,
+    envelope: T,
+    gain: T,
 }
 
-impl Limiter {
+impl<T: Sample> Limiter<T> {
     pub fn new(threshold: f32, release_time: f32) -> Self {
         Self {
-            threshold: threshold.clamp(0.0, 1.0),
-            release_time: release_time.max(0.001),
-            envelope: 0.0,
+            threshold: T::from_f32(threshold.clamp(0.0, 1.0)),
+            release_time: T::from_f32(release_time.max(0.001)),
+            envelope: T::ZERO,
+            gain: T::ONE,
         }
     }
 
-    pub fn process(&mut self, input: f32) -> f32 {
+    /// 엔벨로프/게인 상태를 유지하며 threshold 변경
+    pub fn set_threshold(&mut self, threshold: f32) {
+        self.threshold = T::from_f32(threshold.clamp(0.0, 1.0));
+    }
+
+    /// 엔벨로프/게인 상태를 유지하며 릴리즈 시정수 변경
+    pub fn set_release_time(&mut self, release_time: f32) {
+        self.release_time = T::from_f32(release_time.max(0.001));
+    }
+
+    pub fn process(&mut self, input: T) -> T {
         let abs_input = input.abs();
 
         if abs_input > self.threshold {
             self.envelope = abs_input;
         } else {
-            let release_coeff = (-1.0 / self.release_time).exp();
-            self.envelope = self.envelope * release_coeff;
+            let release_coeff = (-T::ONE / self.release_time).exp();
+            // 무음에서 지수 감쇠가 비정규화 수로 내려가지 않도록
+            self.envelope = (self.envelope * release_coeff).flush_denormal();
         }
 
-        if self.envelope > 0.0 {
-            input * (self.threshold / self.envelope)
+        // 엔벨로프가 threshold 아래로 내려가면 게인 1 (증폭하지 않음):
+        // threshold / envelope 를 그대로 쓰면 피크 후 엔벨로프가 감쇠하는 동안 게인이 1을 넘어
+        // 작은 신호를 증폭함 (엔벨로프 -> 0 이면 게인 발산)
+        self.gain = if self.envelope > self.threshold {
+            self.threshold / self.envelope
         } else {
-            input
-        }
+            T::ONE
+        };
+        input * self.gain
+    }
+
+    /// 마지막 샘플의 게인 리덕션 (dB, 0 이상)
+    pub fn gain_reduction_db(&self) -> f32 {
+        -20.0 * math::log10(self.gain.to_f32().max(1e-6))
     }
 
     pub fn reset(&mut self) {
-        self.envelope = 0.0;
+        self.envelope = T::ZERO;
+        self.gain = T::ONE;
+    }
+}
+
+/// 룩어헤드 없음 (지연 0)
+impl<T: Sample> Processor for Limiter<T> {
+    fn process(&mut self, input: &[f32], output: &mut [f32], _params: &ProcessParams) {
+        for (x, y) in input.iter().zip(output.iter_mut()) {
+            *y = Limiter::process(self, T::from_f32(*x)).to_f32();
+        }
+    }
+
+    fn process_sample(&mut self, input: f32, _params: &ProcessParams) -> f32 {
+        Limiter::process(self, T::from_f32(input)).to_f32()
+    }
+
+    fn reset(&mut self) {
+        Limiter::reset(self);
+    }
+
+    fn name(&self) -> &str {
+        "Limiter"
     }
 }
 
@@ -43,14 +91,40 @@ mod tests {
 
     #[test]
     fn test_limiter_creation() {
-        let limiter = Limiter::new(0.95, 0.1);
+        let limiter = Limiter::<f32>::new(0.95, 0.1);
         assert_eq!(limiter.threshold, 0.95);
     }
 
     #[test]
     fn test_limiter_clipping() {
         let mut limiter = Limiter::new(0.9, 0.05);
-        let output = limiter.process(2.0);
+        let output: f32 = limiter.process(2.0);
         assert!(output.abs() <= 1.0);
+        assert!(limiter.gain_reduction_db() > 6.0);
+    }
+
+    #[test]
+    fn test_limiter_never_boosts_after_release() {
+        let mut limiter = Limiter::<f32>::new(0.9, 10.0);
+        limiter.process(2.0);
+        for _ in 0..100 {
+            let output = limiter.process(0.5);
+            assert!(output <= 0.5);
+        }
+        assert!(limiter.gain_reduction_db() >= 0.0);
+    }
+
+    #[test]
+    fn test_unity_gain_below_threshold() {
+        // 피크 전후 모두 threshold 아래 입력은 그대로 통과
+        let mut limiter = Limiter::<f32>::new(0.9, 0.05);
+        assert_eq!(limiter.process(0.3), 0.3);
+        limiter.process(2.0);
+        for _ in 0..10 {
+            limiter.process(0.0);
+        }
+        assert_eq!(limiter.process(0.3), 0.3);
+        assert_eq!(limiter.process(-0.89), -0.89);
+        assert_eq!(limiter.gain_reduction_db(), 0.0);
     }
 }
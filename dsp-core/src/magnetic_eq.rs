@@ -1,36 +1,40 @@
 /// Magnetic Tape Emulation
 /// 자기 테이프의 비선형 특성 시뮬레이션
+use crate::sample::Sample;
+use crate::simd::F32x4;
 
 /// Magnetic Tape 에뮬레이터
-pub struct MagneticEQ {
-    saturation: f32,
-    hardness: f32,
+pub struct MagneticEQ<T: Sample = f32> {
+    saturation: T,
+    hardness: T,
 }
 
-impl MagneticEQ {
+impl<T: Sample> MagneticEQ<T> {
     pub fn new(saturation: f32, hardness: f32) -> Self {
         Self {
-            saturation: saturation.clamp(0.0, 1.0),
-            hardness: hardness.clamp(0.0, 1.0),
+            saturation: T::from_f32(saturation.clamp(0.0, 1.0)),
+            hardness: T::from_f32(hardness.clamp(0.0, 1.0)),
         }
     }
 
     /// 자기 테이프 포화 곡선
     /// 매끄러운 클리핑으로 따뜻한 톤 생성
-    pub fn process(&self, sample: f32) -> f32 {
-        let drive = 1.0 + self.saturation * 5.0; // 1.0-6.0
+    pub fn process(&self, sample: T) -> T {
+        let c = T::from_f32;
+        let drive = T::ONE + self.saturation * c(5.0); // 1.0-6.0
         let driven = sample * drive;
 
         // Soft clipping with magnetic saturation curve
         let tape_saturation = self.tape_saturation(driven, self.hardness);
 
         // Makeup gain
-        tape_saturation / (drive * 0.8)
+        tape_saturation / (drive * c(0.8))
     }
 
     /// 테이프 포화 곡선 (비선형 곡선)
-    fn tape_saturation(&self, x: f32, hardness: f32) -> f32 {
-        if x.abs() < 0.5 {
+    fn tape_saturation(&self, x: T, hardness: T) -> T {
+        let c = T::from_f32;
+        if x.abs() < c(0.5) {
             // 선형 영역
             x
         } else {
@@ -39,22 +43,49 @@ impl MagneticEQ {
             let abs_x = x.abs();
 
             // 부드러운 포화 곡선
-            let knee = 0.5 + hardness * 0.2; // 0.5-0.7
-            let excess = (abs_x - knee).max(0.0);
-            let saturation_factor = 1.0 - hardness * 0.3;
+            let knee = c(0.5) + hardness * c(0.2); // 0.5-0.7
+            let excess = (abs_x - knee).max(T::ZERO);
+            let saturation_factor = T::ONE - hardness * c(0.3);
 
             let saturated = knee + excess * saturation_factor;
-            sign * saturated.min(1.0 + hardness * 0.1)
+            sign * saturated.min(T::ONE + hardness * c(0.1))
         }
     }
 
     /// 매개변수 업데이트
     pub fn set_saturation(&mut self, saturation: f32) {
-        self.saturation = saturation.clamp(0.0, 1.0);
+        self.saturation = T::from_f32(saturation.clamp(0.0, 1.0));
     }
 
     pub fn set_hardness(&mut self, hardness: f32) {
-        self.hardness = hardness.clamp(0.0, 1.0);
+        self.hardness = T::from_f32(hardness.clamp(0.0, 1.0));
+    }
+}
+
+impl MagneticEQ<f32> {
+    /// process()의 블록 버전 (4샘플씩 SIMD, 분기 대신 레인 선택)
+    pub fn process_block(&self, samples: &mut [f32]) {
+        let drive = 1.0 + self.saturation * 5.0;
+        let knee = 0.5 + self.hardness * 0.2;
+        let saturation_factor = 1.0 - self.hardness * 0.3;
+        let ceiling = 1.0 + self.hardness * 0.1;
+
+        let zero = F32x4::splat(0.0);
+        let half = F32x4::splat(0.5);
+        let mut chunks = samples.chunks_exact_mut(F32x4::LANES);
+        for chunk in &mut chunks {
+            let driven = F32x4::from_slice(chunk) * F32x4::splat(drive);
+            let abs_x = driven.abs();
+            let excess = (abs_x - F32x4::splat(knee)).max(zero);
+            let saturated = (F32x4::splat(knee) + excess * F32x4::splat(saturation_factor))
+                .min(F32x4::splat(ceiling));
+            let signed = driven.select_lt(zero, -saturated, saturated);
+            let shaped = abs_x.select_lt(half, driven, signed);
+            (shaped / F32x4::splat(drive * 0.8)).write_to_slice(chunk);
+        }
+        for sample in chunks.into_remainder() {
+            *sample = self.process(*sample);
+        }
     }
 }
 
@@ -64,7 +95,7 @@ mod tests {
 
     #[test]
     fn test_magnetic_eq_creation() {
-        let eq = MagneticEQ::new(0.5, 0.3);
+        let eq = MagneticEQ::<f32>::new(0.5, 0.3);
         assert_eq!(eq.saturation, 0.5);
         assert_eq!(eq.hardness, 0.3);
     }
@@ -73,7 +104,7 @@ mod tests {
     fn test_magnetic_eq_process() {
         let eq = MagneticEQ::new(0.5, 0.5);
         let input = 0.1;
-        let output = eq.process(input);
+        let output: f32 = eq.process(input);
         assert!(!output.is_nan());
         assert!(!output.is_infinite());
     }
@@ -82,7 +113,7 @@ mod tests {
     fn test_saturation_clipping() {
         let eq = MagneticEQ::new(0.9, 0.8);
         let large_input = 2.0;
-        let output = eq.process(large_input);
+        let output: f32 = eq.process(large_input);
         assert!(output.abs() <= 1.5); // 클리핑 확인
     }
 }
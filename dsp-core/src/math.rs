@@ -0,0 +1,108 @@
+//! 부동소수점 수학 함수 파사드
+//! std 빌드는 f32/f64 내장 메서드, no_std 빌드는 libm 사용 (임베디드 타깃)
+
+#[cfg(feature = "std")]
+mod imp {
+    #[inline]
+    pub fn sqrt(x: f32) -> f32 {
+        x.sqrt()
+    }
+
+    #[inline]
+    pub fn exp(x: f32) -> f32 {
+        x.exp()
+    }
+
+    #[inline]
+    pub fn sin(x: f32) -> f32 {
+        x.sin()
+    }
+
+    #[inline]
+    pub fn cos(x: f32) -> f32 {
+        x.cos()
+    }
+
+    #[inline]
+    pub fn powf(x: f32, y: f32) -> f32 {
+        x.powf(y)
+    }
+
+    #[inline]
+    pub fn log10(x: f32) -> f32 {
+        x.log10()
+    }
+
+    #[inline]
+    pub fn round(x: f32) -> f32 {
+        x.round()
+    }
+
+    /// f64 버전 (Sample for f64)
+    pub mod double {
+        #[inline]
+        pub fn sqrt(x: f64) -> f64 {
+            x.sqrt()
+        }
+
+        #[inline]
+        pub fn exp(x: f64) -> f64 {
+            x.exp()
+        }
+
+        #[inline]
+        pub fn sin(x: f64) -> f64 {
+            x.sin()
+        }
+
+        #[inline]
+        pub fn cos(x: f64) -> f64 {
+            x.cos()
+        }
+
+        #[inline]
+        pub fn powf(x: f64, y: f64) -> f64 {
+            x.powf(y)
+        }
+
+        #[inline]
+        pub fn log10(x: f64) -> f64 {
+            x.log10()
+        }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod imp {
+    pub use libm::{cosf as cos, expf as exp, log10f as log10, powf, roundf as round};
+    pub use libm::{sinf as sin, sqrtf as sqrt};
+
+    /// f64 버전 (Sample for f64)
+    pub mod double {
+        pub use libm::{cos, exp, log10, pow as powf, sin, sqrt};
+    }
+}
+
+pub(crate) use imp::*;
+
+/// 10^(x) (dB 변환용)
+#[inline]
+pub(crate) fn exp10(x: f32) -> f32 {
+    powf(10.0, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_reference_values() {
+        assert!((sqrt(2.0) - core::f32::consts::SQRT_2).abs() < 1e-6);
+        assert!((exp(1.0) - core::f32::consts::E).abs() < 1e-6);
+        assert!((sin(core::f32::consts::FRAC_PI_2) - 1.0).abs() < 1e-6);
+        assert!((cos(0.0) - 1.0).abs() < 1e-6);
+        assert!((log10(1000.0) - 3.0).abs() < 1e-6);
+        assert!((exp10(-20.0 / 20.0) - 0.1).abs() < 1e-6);
+        assert_eq!(round(2.5), 3.0);
+    }
+}
@@ -0,0 +1,213 @@
+//! 미터 값 공유 (오디오 스레드 -> 에디터 / 호스트 / 테스트 하네스)
+//! 원자 변수만 사용: 락과 힙 할당 없음
+
+use crate::{math, simd};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// f32 원자 변수 (비트 패턴 저장)
+#[derive(Debug, Default)]
+pub struct AtomicF32(AtomicU32);
+
+impl AtomicF32 {
+    pub const fn new(value: f32) -> Self {
+        Self(AtomicU32::new(value.to_bits()))
+    }
+
+    #[inline]
+    pub fn load(&self) -> f32 {
+        f32::from_bits(self.0.load(Ordering::Relaxed))
+    }
+
+    #[inline]
+    pub fn store(&self, value: f32) {
+        self.0.store(value.to_bits(), Ordering::Relaxed);
+    }
+}
+
+/// 현재 적응형 처리 경로
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProcessingPath {
+    /// RIAA 크로스페이드 경로 (velocity >= magnetic_threshold)
+    #[default]
+    Riaa,
+    /// Magnetic 포화 경로 (약한 신호)
+    Magnetic,
+}
+
+impl ProcessingPath {
+    fn to_bits(self) -> u32 {
+        match self {
+            ProcessingPath::Riaa => 0,
+            ProcessingPath::Magnetic => 1,
+        }
+    }
+
+    fn from_bits(bits: u32) -> Self {
+        match bits {
+            1 => ProcessingPath::Magnetic,
+            _ => ProcessingPath::Riaa,
+        }
+    }
+}
+
+/// 한 시점의 미터 값
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MeterSnapshot {
+    pub input_peak: f32,
+    pub input_rms: f32,
+    pub output_peak: f32,
+    pub output_rms: f32,
+    /// VelocityAnalyzer 점수 (0.0-1.0)
+    pub velocity: f32,
+    pub path: ProcessingPath,
+    /// 리미터 게인 리덕션 (dB, 0 이상)
+    pub gain_reduction_db: f32,
+    /// 실제 적용된 RIAA 믹스 (0.0-1.0)
+    pub riaa_blend: f32,
+    /// 실제 적용된 Parallel HF 믹스 (0.0-1.0)
+    pub parallel_blend: f32,
+}
+
+/// 플러그인과 에디터가 Arc로 공유하는 미터
+/// 필드별로 원자적이므로 스냅샷은 서로 다른 버퍼의 값이 섞일 수 있음 (미터 용도로 충분)
+#[derive(Debug, Default)]
+pub struct Meters {
+    input_peak: AtomicF32,
+    input_rms: AtomicF32,
+    output_peak: AtomicF32,
+    output_rms: AtomicF32,
+    velocity: AtomicF32,
+    path: AtomicU32,
+    gain_reduction_db: AtomicF32,
+    riaa_blend: AtomicF32,
+    parallel_blend: AtomicF32,
+}
+
+impl Meters {
+    /// 오디오 스레드에서 호출 (대기 없음)
+    pub fn publish(&self, snapshot: &MeterSnapshot) {
+        self.input_peak.store(snapshot.input_peak);
+        self.input_rms.store(snapshot.input_rms);
+        self.output_peak.store(snapshot.output_peak);
+        self.output_rms.store(snapshot.output_rms);
+        self.velocity.store(snapshot.velocity);
+        self.path.store(snapshot.path.to_bits(), Ordering::Relaxed);
+        self.gain_reduction_db.store(snapshot.gain_reduction_db);
+        self.riaa_blend.store(snapshot.riaa_blend);
+        self.parallel_blend.store(snapshot.parallel_blend);
+    }
+
+    pub fn snapshot(&self) -> MeterSnapshot {
+        MeterSnapshot {
+            input_peak: self.input_peak.load(),
+            input_rms: self.input_rms.load(),
+            output_peak: self.output_peak.load(),
+            output_rms: self.output_rms.load(),
+            velocity: self.velocity.load(),
+            path: ProcessingPath::from_bits(self.path.load(Ordering::Relaxed)),
+            gain_reduction_db: self.gain_reduction_db.load(),
+            riaa_blend: self.riaa_blend.load(),
+            parallel_blend: self.parallel_blend.load(),
+        }
+    }
+}
+
+/// 피크 하강 시간 (-20dB까지, ms)
+const PEAK_RELEASE_MS: f32 = 300.0;
+/// RMS 평균 시정수 (ms)
+const RMS_WINDOW_MS: f32 = 300.0;
+
+/// 블록 단위 피크/RMS 측정 (피크 홀드 하강 + RMS 지수 평균)
+/// 에디터가 버퍼보다 느리게 읽어도 피크를 놓치지 않음
+#[derive(Clone, Debug)]
+pub struct LevelMeter {
+    sample_rate: f32,
+    peak: f32,
+    mean_square: f32,
+}
+
+impl LevelMeter {
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            sample_rate,
+            peak: 0.0,
+            mean_square: 0.0,
+        }
+    }
+
+    /// 여러 채널의 한 블록 측정 (피크는 채널 최대, RMS는 채널 평균)
+    pub fn measure<'a>(&mut self, channels: impl IntoIterator<Item = &'a [f32]>) {
+        let mut block_peak = 0.0f32;
+        let mut sum_sq = 0.0f32;
+        let mut count = 0usize;
+        let mut frames = 0usize;
+        for channel in channels {
+            frames = frames.max(channel.len());
+            count += channel.len();
+            let (peak, channel_sum_sq) = simd::peak_and_sum_squares(channel);
+            block_peak = block_peak.max(peak);
+            sum_sq += channel_sum_sq;
+        }
+        if count == 0 {
+            return;
+        }
+
+        let duration_ms = frames as f32 * 1000.0 / self.sample_rate;
+        let peak_decay = math::powf(0.1, duration_ms / PEAK_RELEASE_MS);
+        self.peak = block_peak.max(self.peak * peak_decay);
+
+        let weight = 1.0 - math::exp(-duration_ms / RMS_WINDOW_MS);
+        self.mean_square += (sum_sq / count as f32 - self.mean_square) * weight;
+    }
+
+    pub fn peak(&self) -> f32 {
+        self.peak
+    }
+
+    pub fn rms(&self) -> f32 {
+        math::sqrt(self.mean_square.max(0.0))
+    }
+
+    pub fn reset(&mut self) {
+        self.peak = 0.0;
+        self.mean_square = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meters_round_trip() {
+        let meters = Meters::default();
+        let snapshot = MeterSnapshot {
+            input_peak: 0.8,
+            velocity: 0.4,
+            path: ProcessingPath::Magnetic,
+            gain_reduction_db: 3.5,
+            ..MeterSnapshot::default()
+        };
+        meters.publish(&snapshot);
+        assert_eq!(meters.snapshot(), snapshot);
+    }
+
+    #[test]
+    fn test_level_meter_peak_hold_and_rms() {
+        let mut meter = LevelMeter::new(48000.0);
+        let loud = [0.5f32; 480];
+        meter.measure([&loud[..], &loud[..]]);
+        assert_eq!(meter.peak(), 0.5);
+
+        // 무음 블록 후 피크는 천천히 하강
+        let silence = [0.0f32; 480];
+        meter.measure([&silence[..]]);
+        assert!(meter.peak() > 0.4 && meter.peak() < 0.5);
+
+        let mut meter = LevelMeter::new(48000.0);
+        for _ in 0..200 {
+            meter.measure([&loud[..]]);
+        }
+        assert!((meter.rms() - 0.5).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,180 @@
+//! MIDI CC -> 파라미터 매핑 (CC Learn)
+//! 파라미터는 플러그인 파라미터 ID 문자열로 지정, 채널 구분 없음 (옴니)
+
+/// CC 번호 하나와 파라미터 ID 하나의 연결
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "preset-files", derive(serde::Serialize, serde::Deserialize))]
+pub struct CcMapping {
+    pub cc: u8,
+    pub param_id: String,
+}
+
+/// CC 매핑 테이블 + 진행 중인 Learn 대상
+/// 세션에는 매핑만 저장 (Learn 상태는 저장하지 않음)
+#[derive(Clone, Debug, Default, Eq)]
+#[cfg_attr(feature = "preset-files", derive(serde::Serialize, serde::Deserialize))]
+pub struct MidiMap {
+    mappings: Vec<CcMapping>,
+    #[cfg_attr(feature = "preset-files", serde(skip))]
+    learning: Option<String>,
+    /// 마지막 take_changed() 이후 매핑이 그대로인지 (새로 만들거나 불러온 매핑은 false)
+    #[cfg_attr(feature = "preset-files", serde(skip))]
+    resolved: bool,
+    /// 오디오 스레드의 Learn이 교체한 매핑: 그 자리에서 해제하지 않고 GUI 스레드 호출에서 해제
+    #[cfg_attr(feature = "preset-files", serde(skip))]
+    retired: Vec<CcMapping>,
+}
+
+impl PartialEq for MidiMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.mappings == other.mappings && self.learning == other.learning
+    }
+}
+
+impl MidiMap {
+    /// 기본 매핑으로 시작 (예: CC 1 모듈레이션 휠 -> 드라이브)
+    pub fn with_mappings(mappings: &[(u8, &str)]) -> Self {
+        let mut map = Self::default();
+        for &(cc, param_id) in mappings {
+            map.assign(cc, param_id.to_string());
+        }
+        map
+    }
+
+    /// 매핑이 바뀌었으면 true를 한 번 반환 (플러그인이 CC -> 파라미터 인덱스 표를 다시 계산)
+    pub fn take_changed(&mut self) -> bool {
+        !std::mem::replace(&mut self.resolved, true)
+    }
+
+    /// 다음에 들어오는 CC를 param_id에 연결 (GUI 스레드에서 호출)
+    pub fn start_learn(&mut self, param_id: &str) {
+        // 오디오 스레드에서 매핑을 교체할 때 할당/해제하지 않도록 미리 확보
+        // (교체되는 매핑은 같은 CC 하나 + 같은 파라미터 하나)
+        self.retired.clear();
+        self.retired.reserve(2);
+        self.mappings.reserve(1);
+        self.learning = Some(param_id.to_string());
+    }
+
+    pub fn cancel_learn(&mut self) {
+        self.retired.clear();
+        self.learning = None;
+    }
+
+    /// Learn 대기 중인 파라미터 ID
+    pub fn learning(&self) -> Option<&str> {
+        self.learning.as_deref()
+    }
+
+    /// param_id에 연결된 CC 번호
+    pub fn cc_for(&self, param_id: &str) -> Option<u8> {
+        self.mappings
+            .iter()
+            .find(|mapping| mapping.param_id == param_id)
+            .map(|mapping| mapping.cc)
+    }
+
+    /// param_id의 매핑 해제 (GUI 스레드에서 호출)
+    pub fn clear(&mut self, param_id: &str) {
+        self.retired.clear();
+        self.mappings.retain(|mapping| mapping.param_id != param_id);
+        self.resolved = false;
+    }
+
+    pub fn mappings(&self) -> &[CcMapping] {
+        &self.mappings
+    }
+
+    /// CC 수신: Learn 중이면 먼저 연결한 뒤, 값을 적용할 파라미터 ID 반환
+    pub fn handle_cc(&mut self, cc: u8) -> Option<&str> {
+        if let Some(param_id) = self.learning.take() {
+            self.assign(cc, param_id);
+        }
+        self.mappings
+            .iter()
+            .find(|mapping| mapping.cc == cc)
+            .map(|mapping| mapping.param_id.as_str())
+    }
+
+    /// CC와 파라미터는 각각 하나의 매핑만 가짐 (기존 연결 교체, 교체된 매핑은 retired로)
+    fn assign(&mut self, cc: u8, param_id: String) {
+        while let Some(index) = self
+            .mappings
+            .iter()
+            .position(|mapping| mapping.cc == cc || mapping.param_id == param_id)
+        {
+            self.retired.push(self.mappings.remove(index));
+        }
+        self.mappings.push(CcMapping { cc, param_id });
+        self.resolved = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_learn_assigns_next_cc() {
+        let mut map = MidiMap::default();
+        assert_eq!(map.handle_cc(20), None);
+
+        map.start_learn("drive");
+        assert_eq!(map.learning(), Some("drive"));
+        assert_eq!(map.handle_cc(20), Some("drive"));
+        assert_eq!(map.learning(), None);
+        assert_eq!(map.cc_for("drive"), Some(20));
+        assert_eq!(map.handle_cc(21), None);
+
+        map.clear("drive");
+        assert_eq!(map.handle_cc(20), None);
+    }
+
+    #[test]
+    fn test_relearn_replaces_mappings() {
+        let mut map = MidiMap::with_mappings(&[(1, "drive"), (7, "output")]);
+        // 같은 파라미터를 다른 CC로: 이전 CC 해제
+        map.start_learn("drive");
+        map.handle_cc(74);
+        assert_eq!(map.cc_for("drive"), Some(74));
+        assert_eq!(map.handle_cc(1), None);
+        // 같은 CC를 다른 파라미터로: 이전 파라미터 해제
+        map.start_learn("tone");
+        map.handle_cc(7);
+        assert_eq!(map.cc_for("output"), None);
+        assert_eq!(map.mappings().len(), 2);
+    }
+
+    #[test]
+    fn test_learn_does_not_free_on_audio_thread() {
+        let mut map = MidiMap::with_mappings(&[(1, "drive"), (7, "output")]);
+        map.start_learn("drive");
+        let capacity = (map.mappings.capacity(), map.retired.capacity());
+        // CC 7 -> drive: (1, drive) 와 (7, output) 모두 교체, 해제/할당 없이 retired 로 이동
+        map.handle_cc(7);
+        assert_eq!(map.cc_for("drive"), Some(7));
+        assert_eq!(map.mappings().len(), 1);
+        assert_eq!(map.retired.len(), 2);
+        assert_eq!((map.mappings.capacity(), map.retired.capacity()), capacity);
+        // 다음 GUI 스레드 호출에서 해제
+        map.cancel_learn();
+        assert!(map.retired.is_empty());
+    }
+
+    #[test]
+    fn test_take_changed_after_edits() {
+        let mut map = MidiMap::with_mappings(&[(1, "drive")]);
+        assert!(map.take_changed());
+        assert!(!map.take_changed());
+        // 같은 CC 재수신은 매핑 변경이 아님
+        map.handle_cc(1);
+        assert!(!map.take_changed());
+
+        map.start_learn("tone");
+        map.handle_cc(74);
+        assert!(map.take_changed());
+        map.clear("tone");
+        assert!(map.take_changed());
+        assert_eq!(map, MidiMap::with_mappings(&[(1, "drive")]));
+    }
+}
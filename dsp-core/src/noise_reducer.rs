@@ -0,0 +1,341 @@
+//! 광대역 히스 / 표면 잡음 제거
+//! STFT 스펙트럼 차감 (sqrt-Hann 50% overlap-add)
+
+use crate::fft::Fft;
+use crate::math;
+use crate::processor_trait::{ProcessParams, Processor};
+
+/// FFT 크기 (48kHz에서 약 21ms)
+const SIZE: usize = 1024;
+const HOP: usize = SIZE / 2;
+const BINS: usize = SIZE / 2 + 1;
+
+/// 최소 추적 시 잡음 추정 상승률 (프레임당, 약 +3dB/s @ 48kHz)
+const MIN_TRACK_RISE: f32 = 1.008;
+/// 최소값은 평균 잡음 파워를 과소 추정하므로 보정
+const MIN_TRACK_BIAS: f32 = 1.5;
+/// 최소 추적용 파워 스무딩 계수
+const POWER_SMOOTHING: f32 = 0.8;
+
+/// 잡음 프로파일 추정 방식
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NoiseProfileMode {
+    /// 최소 통계 추적으로 자동 추정
+    Auto,
+    /// 입력을 잡음으로 학습 (처리하지 않고 통과)
+    Learn,
+    /// 학습된 프로파일 고정 사용
+    Hold,
+}
+
+/// 스펙트럼 차감 잡음 제거기
+pub struct NoiseReducer {
+    fft: Fft<SIZE>,
+    window: [f32; SIZE],
+    input: [f32; SIZE],
+    overlap: [f32; SIZE],
+    ready: [f32; HOP],
+    pos: usize,
+    re: [f32; SIZE],
+    im: [f32; SIZE],
+    noise: [f32; BINS],
+    learned_sum: [f32; BINS],
+    learned_frames: u32,
+    smoothed_power: [f32; BINS],
+    gains: [f32; BINS],
+    mode: NoiseProfileMode,
+    over_subtraction: f32,
+    floor: f32,
+    smoothing: f32,
+    enabled: bool,
+}
+
+impl NoiseReducer {
+    /// over_subtraction: 1.0-4.0, floor_db: 스펙트럼 바닥 (-60..0 dB), smoothing: 0.0-0.95
+    pub fn new(over_subtraction: f32, floor_db: f32, smoothing: f32) -> Self {
+        let mut reducer = Self {
+            fft: Fft::new(),
+            // sqrt-Hann (periodic): 분석*합성 창의 50% 중첩 합 = 1
+            window: core::array::from_fn(|n| {
+                math::sin(core::f32::consts::PI * n as f32 / SIZE as f32)
+            }),
+            input: [0.0; SIZE],
+            overlap: [0.0; SIZE],
+            ready: [0.0; HOP],
+            pos: 0,
+            re: [0.0; SIZE],
+            im: [0.0; SIZE],
+            noise: [0.0; BINS],
+            learned_sum: [0.0; BINS],
+            learned_frames: 0,
+            smoothed_power: [0.0; BINS],
+            gains: [1.0; BINS],
+            mode: NoiseProfileMode::Auto,
+            over_subtraction: 1.0,
+            floor: 0.1,
+            smoothing: 0.5,
+            enabled: true,
+        };
+        reducer.set_over_subtraction(over_subtraction);
+        reducer.set_floor_db(floor_db);
+        reducer.set_smoothing(smoothing);
+        reducer
+    }
+
+    pub fn set_over_subtraction(&mut self, over_subtraction: f32) {
+        self.over_subtraction = over_subtraction.clamp(0.0, 4.0);
+    }
+
+    pub fn set_floor_db(&mut self, floor_db: f32) {
+        self.floor = crate::db_to_linear(floor_db.clamp(-60.0, 0.0));
+    }
+
+    /// 게인의 시간축 스무딩 (뮤지컬 노이즈 억제)
+    pub fn set_smoothing(&mut self, smoothing: f32) {
+        self.smoothing = smoothing.clamp(0.0, 0.95);
+    }
+
+    /// Learn -> Hold 전환 시 학습 평균을 프로파일로 확정
+    pub fn set_profile_mode(&mut self, mode: NoiseProfileMode) {
+        if mode == NoiseProfileMode::Learn && self.mode != NoiseProfileMode::Learn {
+            self.learned_sum = [0.0; BINS];
+            self.learned_frames = 0;
+        }
+        self.mode = mode;
+    }
+
+    pub fn profile_mode(&self) -> NoiseProfileMode {
+        self.mode
+    }
+
+    /// 비활성화해도 지연은 유지
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// 학습된 프레임 수 (Hold 모드에서 0이면 처리하지 않음)
+    pub fn learned_frames(&self) -> u32 {
+        self.learned_frames
+    }
+
+    pub fn process(&mut self, input: f32) -> f32 {
+        let output = self.ready[self.pos];
+        self.input[SIZE - HOP + self.pos] = input;
+        self.pos += 1;
+
+        if self.pos == HOP {
+            self.process_frame();
+            self.ready.copy_from_slice(&self.overlap[..HOP]);
+            self.overlap.copy_within(HOP.., 0);
+            self.overlap[SIZE - HOP..].fill(0.0);
+            self.input.copy_within(HOP.., 0);
+            self.pos = 0;
+        }
+
+        output
+    }
+
+    /// 스트림 상태 초기화 (학습된 잡음 프로파일은 유지)
+    pub fn reset(&mut self) {
+        self.input = [0.0; SIZE];
+        self.overlap = [0.0; SIZE];
+        self.ready = [0.0; HOP];
+        self.pos = 0;
+        self.smoothed_power = [0.0; BINS];
+        self.gains = [1.0; BINS];
+        if self.mode == NoiseProfileMode::Auto {
+            self.noise = [0.0; BINS];
+        }
+    }
+
+    /// 잡음 프로파일 삭제
+    pub fn clear_noise_profile(&mut self) {
+        self.noise = [0.0; BINS];
+        self.learned_sum = [0.0; BINS];
+        self.learned_frames = 0;
+    }
+
+    fn process_frame(&mut self) {
+        for n in 0..SIZE {
+            self.re[n] = self.input[n] * self.window[n];
+            self.im[n] = 0.0;
+        }
+        self.fft.forward(&mut self.re, &mut self.im);
+
+        let mut power = [0.0f32; BINS];
+        for (k, p) in power.iter_mut().enumerate() {
+            *p = self.re[k] * self.re[k] + self.im[k] * self.im[k];
+        }
+        self.update_noise(&power);
+
+        let active = self.enabled
+            && match self.mode {
+                NoiseProfileMode::Auto => true,
+                NoiseProfileMode::Learn => false,
+                NoiseProfileMode::Hold => self.learned_frames > 0,
+            };
+
+        if active {
+            // 파워 스펙트럼 차감: G = sqrt(max(1 - a * N / |X|^2, floor^2))
+            let floor_sq = self.floor * self.floor;
+            for ((smoothed, &noise), &power) in self.gains.iter_mut().zip(&self.noise).zip(&power) {
+                let ratio = self.over_subtraction * noise / power.max(1e-12);
+                let gain = math::sqrt((1.0 - ratio).max(floor_sq));
+                *smoothed = self.smoothing * *smoothed + (1.0 - self.smoothing) * gain;
+            }
+
+            // 주파수축 3-tap 스무딩 후 양/음 주파수 bin에 적용
+            for k in 0..BINS {
+                let prev = self.gains[k.saturating_sub(1)];
+                let next = self.gains[(k + 1).min(BINS - 1)];
+                let gain = 0.25 * prev + 0.5 * self.gains[k] + 0.25 * next;
+                self.re[k] *= gain;
+                self.im[k] *= gain;
+                if k > 0 && k < SIZE / 2 {
+                    self.re[SIZE - k] *= gain;
+                    self.im[SIZE - k] *= gain;
+                }
+            }
+        }
+
+        self.fft.inverse(&mut self.re, &mut self.im);
+        for n in 0..SIZE {
+            self.overlap[n] += self.re[n] * self.window[n];
+        }
+    }
+
+    fn update_noise(&mut self, power: &[f32; BINS]) {
+        match self.mode {
+            NoiseProfileMode::Auto => {
+                let bins = self
+                    .noise
+                    .iter_mut()
+                    .zip(&mut self.smoothed_power)
+                    .zip(power);
+                for ((noise, smoothed), &power) in bins {
+                    *smoothed = POWER_SMOOTHING * *smoothed + (1.0 - POWER_SMOOTHING) * power;
+                    let tracked = *smoothed * MIN_TRACK_BIAS;
+                    *noise = if *noise == 0.0 {
+                        tracked
+                    } else {
+                        tracked.min(*noise * MIN_TRACK_RISE)
+                    };
+                }
+            }
+            NoiseProfileMode::Learn => {
+                self.learned_frames += 1;
+                let count = self.learned_frames as f32;
+                let bins = self.noise.iter_mut().zip(&mut self.learned_sum).zip(power);
+                for ((noise, sum), &power) in bins {
+                    *sum += power;
+                    *noise = *sum / count;
+                }
+            }
+            NoiseProfileMode::Hold => {}
+        }
+    }
+}
+
+impl Processor for NoiseReducer {
+    fn process(&mut self, input: &[f32], output: &mut [f32], _params: &ProcessParams) {
+        for (x, y) in input.iter().zip(output.iter_mut()) {
+            *y = NoiseReducer::process(self, *x);
+        }
+    }
+
+    fn process_sample(&mut self, input: f32, _params: &ProcessParams) -> f32 {
+        NoiseReducer::process(self, input)
+    }
+
+    fn reset(&mut self) {
+        NoiseReducer::reset(self);
+    }
+
+    fn name(&self) -> &str {
+        "Noise Reducer"
+    }
+
+    fn latency_samples(&self) -> usize {
+        SIZE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 결정적 의사 난수 백색 잡음 (-amp..amp)
+    fn noise(len: usize, amp: f32, seed: u32) -> Vec<f32> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+                amp * ((state >> 8) as f32 / (1u32 << 24) as f32 * 2.0 - 1.0)
+            })
+            .collect()
+    }
+
+    fn rms(signal: &[f32]) -> f32 {
+        (signal.iter().map(|s| s * s).sum::<f32>() / signal.len() as f32).sqrt()
+    }
+
+    #[test]
+    fn test_disabled_is_transparent() {
+        let input = noise(8192, 0.5, 1);
+        let mut reducer = NoiseReducer::new(2.0, -20.0, 0.5);
+        reducer.set_enabled(false);
+        let latency = reducer.latency_samples();
+        let output: Vec<f32> = input.iter().map(|&x| reducer.process(x)).collect();
+        for n in latency + SIZE..input.len() {
+            assert!((output[n] - input[n - latency]).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_learned_profile_reduces_hiss() {
+        let hiss = noise(48000, 0.05, 7);
+        let mut reducer = NoiseReducer::new(2.0, -30.0, 0.5);
+        reducer.set_profile_mode(NoiseProfileMode::Learn);
+        for &x in &hiss[..24000] {
+            reducer.process(x);
+        }
+        reducer.set_profile_mode(NoiseProfileMode::Hold);
+        assert!(reducer.learned_frames() > 0);
+
+        let output: Vec<f32> = hiss[24000..].iter().map(|&x| reducer.process(x)).collect();
+        let tail = &output[SIZE * 2..];
+        assert!(rms(tail) < rms(&hiss[24000..]) * 0.3);
+    }
+
+    #[test]
+    fn test_auto_tracking_keeps_music() {
+        // 250ms 간격으로 on/off 되는 톤 (쉬는 구간에서 잡음 최소값 추적)
+        let gate = 12000;
+        let tone: Vec<f32> = (0..96000)
+            .map(|n| {
+                let on = (n / gate) % 2 == 0;
+                let t = n as f32 / 48000.0;
+                if on {
+                    0.5 * (2.0 * core::f32::consts::PI * 1000.0 * t).sin()
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        let hiss = noise(tone.len(), 0.05, 3);
+        let mut reducer = NoiseReducer::new(1.5, -30.0, 0.5);
+        let latency = reducer.latency_samples();
+        let output: Vec<f32> = tone
+            .iter()
+            .zip(&hiss)
+            .map(|(t, h)| reducer.process(t + h))
+            .collect();
+
+        // 마지막 on 구간: 톤 유지, 마지막 off 구간: 히스 감소
+        let on = 4 * gate + 2000..5 * gate - 2000;
+        let off = 5 * gate + 2000..6 * gate - 2000;
+        let shifted = |range: core::ops::Range<usize>| range.start + latency..range.end + latency;
+        assert!((rms(&output[shifted(on.clone())]) - rms(&tone[on])).abs() < 0.05);
+        assert!(rms(&output[shifted(off.clone())]) < rms(&hiss[off]) * 0.75);
+    }
+}
@@ -0,0 +1,169 @@
+//! 정수배 오버샘플링 (2x 단계 캐스케이드, 선형 위상 FIR)
+//! 비선형 단계(포화/클리핑)의 에일리어싱 감소
+
+use crate::fir::Fir;
+use crate::sample::Sample;
+
+/// 단계별 FIR 탭 수 (지연 16 샘플 @ 단계 샘플레이트)
+const STAGE_TAPS: usize = 33;
+/// 단계 샘플레이트 대비 컷오프 (원래 Nyquist 0.25보다 약간 낮게)
+const STAGE_CUTOFF: f32 = 0.22;
+/// 최대 단계 수 (8x)
+pub const MAX_OVERSAMPLING_STAGES: usize = 3;
+const MAX_FACTOR: usize = 1 << MAX_OVERSAMPLING_STAGES;
+
+#[derive(Clone, Debug)]
+struct Stage<T: Sample> {
+    up: Fir<STAGE_TAPS, T>,
+    down: Fir<STAGE_TAPS, T>,
+}
+
+/// 1x / 2x / 4x / 8x 오버샘플러
+/// 각 단계의 왕복 지연이 정수 샘플이 되도록 같은 탭 수 사용
+#[derive(Clone, Debug)]
+pub struct Oversampler<T: Sample = f32> {
+    stages: [Stage<T>; MAX_OVERSAMPLING_STAGES],
+    active: usize,
+}
+
+impl<T: Sample> Oversampler<T> {
+    /// factor: 1, 2, 4, 8 (그 외 값은 가까운 아래 2의 거듭제곱, 최대 8)
+    pub fn new(factor: usize) -> Self {
+        let stage = Stage {
+            up: Fir::lowpass(STAGE_CUTOFF),
+            down: Fir::lowpass(STAGE_CUTOFF),
+        };
+        let mut oversampler = Self {
+            stages: core::array::from_fn(|_| stage.clone()),
+            active: 0,
+        };
+        oversampler.set_factor(factor);
+        oversampler
+    }
+
+    /// 배율 변경 (필터 상태 초기화)
+    pub fn set_factor(&mut self, factor: usize) {
+        let factor = factor.clamp(1, MAX_FACTOR);
+        self.active = (usize::BITS - 1 - factor.leading_zeros()) as usize;
+        self.reset();
+    }
+
+    pub fn factor(&self) -> usize {
+        1 << self.active
+    }
+
+    /// 원래 샘플레이트 기준 왕복 지연 (단계 k: 2 * 16 / 2^k)
+    pub fn latency_samples(&self) -> usize {
+        let delay = (STAGE_TAPS - 1) / 2;
+        (1..=self.active).map(|k| (2 * delay) >> k).sum()
+    }
+
+    /// 한 샘플을 업샘플 -> f 적용 -> 다운샘플
+    #[inline]
+    pub fn process(&mut self, input: T, mut f: impl FnMut(T) -> T) -> T {
+        if self.active == 0 {
+            return f(input);
+        }
+
+        let two = T::from_f32(2.0);
+        let mut buffer = [T::ZERO; MAX_FACTOR];
+        let mut scratch = [T::ZERO; MAX_FACTOR];
+        buffer[0] = input;
+        let mut len = 1;
+        for stage in &mut self.stages[..self.active] {
+            // 0 삽입 후 보간 (게인 2로 보상)
+            for (i, &x) in buffer[..len].iter().enumerate() {
+                scratch[2 * i] = stage.up.process(two * x);
+                scratch[2 * i + 1] = stage.up.process(T::ZERO);
+            }
+            len *= 2;
+            buffer[..len].copy_from_slice(&scratch[..len]);
+        }
+
+        for sample in &mut buffer[..len] {
+            *sample = f(*sample);
+        }
+
+        for stage in self.stages[..self.active].iter_mut().rev() {
+            len /= 2;
+            // 짝수 위치 출력을 유지 (0 삽입 위치와 정렬 -> 정수 지연)
+            for i in 0..len {
+                scratch[i] = stage.down.process(buffer[2 * i]);
+                stage.down.process(buffer[2 * i + 1]);
+            }
+            buffer[..len].copy_from_slice(&scratch[..len]);
+        }
+        buffer[0]
+    }
+
+    pub fn reset(&mut self) {
+        for stage in &mut self.stages {
+            stage.up.reset();
+            stage.down.reset();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_and_unity_gain() {
+        for factor in [1, 2, 4, 8] {
+            let mut oversampler = Oversampler::<f32>::new(factor);
+            assert_eq!(oversampler.factor(), factor);
+            let latency = oversampler.latency_samples();
+            // 저주파 정현파는 지연만큼 밀린 채로 통과
+            let x = |n: usize| (2.0 * core::f32::consts::PI * 0.01 * n as f32).sin();
+            let mut max_error = 0.0f32;
+            for n in 0..1000 {
+                let y = oversampler.process(x(n), |s| s);
+                if n > 200 {
+                    max_error = max_error.max((y - x(n - latency)).abs());
+                }
+            }
+            assert!(max_error < 0.01, "factor {factor}: {max_error}");
+        }
+        assert_eq!(Oversampler::<f32>::new(8).latency_samples(), 28);
+    }
+
+    #[test]
+    fn test_f64_matches_f32() {
+        let mut single = Oversampler::<f32>::new(4);
+        let mut double = Oversampler::<f64>::new(4);
+        for n in 0..500 {
+            let x = 0.8 * (0.05 * n as f32).sin();
+            let a = single.process(x, |s| s.clamp(-0.5, 0.5));
+            let b = double.process(x as f64, |s| s.clamp(-0.5, 0.5));
+            assert!((a as f64 - b).abs() < 1e-5, "{n}");
+        }
+    }
+
+    #[test]
+    fn test_oversampling_reduces_aliasing() {
+        // 고주파 정현파 하드 클리핑: 에일리어싱 성분이 기본파와 상관없는 에너지로 남음
+        let alias_energy = |factor: usize| {
+            let mut oversampler = Oversampler::<f32>::new(factor);
+            let freq = 0.23;
+            let latency = oversampler.latency_samples();
+            let (mut fundamental_re, mut fundamental_im, mut total) = (0.0, 0.0, 0.0);
+            let count = 4000;
+            for n in 0..count + 200 + latency {
+                let x = 0.9 * (2.0 * core::f32::consts::PI * freq * n as f32).sin();
+                let y = oversampler.process(x, |s| (3.0 * s).clamp(-1.0, 1.0));
+                if n >= 200 + latency {
+                    let phase = 2.0 * core::f32::consts::PI * freq * (n - latency) as f32;
+                    fundamental_re += y * phase.cos();
+                    fundamental_im += y * phase.sin();
+                    total += y * y;
+                }
+            }
+            let fundamental = 2.0
+                * (fundamental_re * fundamental_re + fundamental_im * fundamental_im)
+                / count as f32;
+            total - fundamental
+        };
+        assert!(alias_energy(4) < 0.5 * alias_energy(1));
+    }
+}
@@ -0,0 +1,125 @@
+//! 오디오 스레드 -> 에디터 파라미터 변경 전달 (프리셋 적용, MIDI CC)
+//! 오디오 스레드는 정규화 값을 기록만 하고, 에디터가 호스트 파라미터 변경 경로로 보고
+//! 보고 전까지 오디오 스레드는 pending() 값을 파라미터 값 대신 사용
+//! 그 사이 호스트 값이 바뀌면(자동화, 세션 복원) 기록을 버리고 호스트 값을 따름
+
+use crate::meters::AtomicF32;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[derive(Debug, Default)]
+struct EditSlot {
+    normalized: AtomicF32,
+    /// 기록 시점의 호스트 정규화 값
+    host: AtomicF32,
+    pending: AtomicBool,
+}
+
+/// 파라미터 인덱스별 미보고 변경 (락과 오디오 스레드 할당 없음)
+/// 같은 파라미터의 변경이 여러 번 쌓이면 마지막 값만 보고
+#[derive(Debug)]
+pub struct ParamEdits {
+    slots: Box<[EditSlot]>,
+}
+
+impl ParamEdits {
+    /// count: 플러그인 파라미터 수 (인덱스는 param_map() 순서)
+    pub fn new(count: usize) -> Self {
+        Self {
+            slots: (0..count).map(|_| EditSlot::default()).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// 변경 기록 (오디오 스레드), 범위 밖 인덱스는 무시
+    /// host: 지금의 호스트 정규화 값 (이후 호스트 값이 바뀌었는지 판단)
+    pub fn push(&self, index: usize, normalized: f32, host: f32) {
+        if let Some(slot) = self.slots.get(index) {
+            slot.normalized.store(normalized);
+            slot.host.store(host);
+            slot.pending.store(true, Ordering::Release);
+        }
+    }
+
+    /// 아직 보고되지 않은 값
+    /// host: 지금의 호스트 정규화 값, 기록 이후 바뀌었으면 기록을 버리고 None
+    pub fn pending(&self, index: usize, host: f32) -> Option<f32> {
+        let slot = self.slots.get(index)?;
+        if !slot.pending.load(Ordering::Acquire) {
+            return None;
+        }
+        if slot.host.load() != host {
+            slot.pending.store(false, Ordering::Release);
+            return None;
+        }
+        Some(slot.normalized.load())
+    }
+
+    /// 미보고 변경을 꺼내 report(index, normalized) 호출 (에디터)
+    /// 보고 중 새로 기록된 값은 다음 drain에서 다시 보고
+    pub fn drain(&self, mut report: impl FnMut(usize, f32)) {
+        for (index, slot) in self.slots.iter().enumerate() {
+            if slot.pending.swap(false, Ordering::AcqRel) {
+                report(index, slot.normalized.load());
+            }
+        }
+    }
+
+    /// 미보고 변경을 꺼내지 않고 (index, normalized, host)로 나열 (세션 저장)
+    /// 복원은 같은 값으로 push
+    pub fn unreported(&self) -> impl Iterator<Item = (usize, f32, f32)> + '_ {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| slot.pending.load(Ordering::Acquire))
+            .map(|(index, slot)| (index, slot.normalized.load(), slot.host.load()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drain_reports_last_value_once() {
+        let edits = ParamEdits::new(3);
+        edits.push(1, 0.25, 0.5);
+        edits.push(1, 0.75, 0.5);
+        edits.push(7, 1.0, 0.5);
+        assert_eq!(edits.pending(1, 0.5), Some(0.75));
+        assert_eq!(edits.pending(0, 0.5), None);
+
+        let mut reported = Vec::new();
+        edits.drain(|index, value| reported.push((index, value)));
+        assert_eq!(reported, [(1, 0.75)]);
+        assert_eq!(edits.pending(1, 0.5), None);
+
+        edits.drain(|_, _| panic!("nothing pending"));
+    }
+
+    #[test]
+    fn test_host_change_drops_unreported_edit() {
+        let edits = ParamEdits::new(2);
+        edits.push(0, 0.9, 0.2);
+        edits.push(1, 0.4, 0.6);
+        assert_eq!(
+            edits.unreported().collect::<Vec<_>>(),
+            [(0, 0.9, 0.2), (1, 0.4, 0.6)]
+        );
+
+        // 에디터 없이 호스트 자동화가 값을 바꾸면 자동화가 이김
+        assert_eq!(edits.pending(0, 0.3), None);
+        assert_eq!(edits.pending(0, 0.2), None);
+        assert_eq!(edits.pending(1, 0.6), Some(0.4));
+
+        let mut reported = Vec::new();
+        edits.drain(|index, value| reported.push((index, value)));
+        assert_eq!(reported, [(1, 0.4)]);
+    }
+}
@@ -0,0 +1,258 @@
+//! 체인 프리셋 - CLI와 플러그인이 공유하는 데이터 기반 프리셋
+//! 모든 체인 파라미터를 하나의 구조체로 정의
+
+/// 내장 프리셋 이름
+pub const BUILTIN_PRESET_NAMES: &[&str] = &["vinyl", "warm", "clean"];
+
+/// 프리셋 필드의 유효 범위 (sanitized()와 플러그인 파라미터 범위가 공유)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FieldRange {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl FieldRange {
+    pub const fn new(min: f32, max: f32) -> Self {
+        Self { min, max }
+    }
+
+    pub fn clamp(self, value: f32) -> f32 {
+        value.clamp(self.min, self.max)
+    }
+
+    pub fn contains(self, value: f32) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+}
+
+/// 체인 전체 파라미터
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ChainPreset {
+    /// 입력 드라이브 (dB)
+    pub drive_db: f32,
+    /// 테이프 포화 하드니스 (0.0-1.0)
+    pub hardness: f32,
+    /// RIAA 크로스페이드 강도 (0.0-1.0)
+    pub riaa_intensity: f32,
+    /// Parallel HF Recovery 믹스 (0.0-1.0)
+    pub parallel_mix: f32,
+    /// Dry/Wet 믹스 (0.0-1.0)
+    pub dry_wet: f32,
+    /// 이 velocity 미만이면 Magnetic 경로
+    pub magnetic_threshold: f32,
+    /// 이 velocity 미만이면 Parallel HF Recovery 활성화
+    pub parallel_threshold: f32,
+    /// 리미터 천장 (linear, 0.0-1.0)
+    pub limiter_ceiling: f32,
+    /// 리미터 릴리즈 (ms)
+    pub limiter_release_ms: f32,
+    /// 출력 Lowpass 컷오프 (Hz)
+    pub lowpass_hz: f32,
+    /// 출력 Lowpass Q
+    pub lowpass_q: f32,
+}
+
+impl ChainPreset {
+    pub const DRIVE_DB: FieldRange = FieldRange::new(0.0, 24.0);
+    pub const HARDNESS: FieldRange = FieldRange::new(0.0, 1.0);
+    pub const RIAA_INTENSITY: FieldRange = FieldRange::new(0.0, 1.0);
+    pub const PARALLEL_MIX: FieldRange = FieldRange::new(0.0, 1.0);
+    pub const DRY_WET: FieldRange = FieldRange::new(0.0, 1.0);
+    pub const MAGNETIC_THRESHOLD: FieldRange = FieldRange::new(0.0, 1.0);
+    pub const PARALLEL_THRESHOLD: FieldRange = FieldRange::new(0.0, 1.0);
+    pub const LIMITER_CEILING: FieldRange = FieldRange::new(0.1, 1.0);
+    pub const LIMITER_RELEASE_MS: FieldRange = FieldRange::new(1.0, 5000.0);
+    pub const LOWPASS_HZ: FieldRange = FieldRange::new(20.0, 20000.0);
+    pub const LOWPASS_Q: FieldRange = FieldRange::new(0.1, 10.0);
+
+    /// Vinyl: 강한 드라이브, 높은 RIAA 비율, 표면 잡음을 줄이는 Lowpass
+    pub const fn vinyl() -> Self {
+        Self {
+            drive_db: 8.0,
+            hardness: 0.3,
+            riaa_intensity: 0.8,
+            parallel_mix: 0.6,
+            dry_wet: 1.0,
+            magnetic_threshold: 0.60,
+            parallel_threshold: 0.50,
+            limiter_ceiling: 0.95,
+            limiter_release_ms: 80.0,
+            lowpass_hz: 16000.0,
+            lowpass_q: 0.707,
+        }
+    }
+
+    /// Warm: 중간 드라이브, 테이프 포화 중심
+    pub const fn warm() -> Self {
+        Self {
+            drive_db: 5.0,
+            hardness: 0.5,
+            riaa_intensity: 0.5,
+            parallel_mix: 0.5,
+            dry_wet: 1.0,
+            magnetic_threshold: 0.60,
+            parallel_threshold: 0.50,
+            limiter_ceiling: 0.95,
+            limiter_release_ms: 120.0,
+            lowpass_hz: 12000.0,
+            lowpass_q: 0.707,
+        }
+    }
+
+    /// Clean: 낮은 드라이브, 대역 제한 없음
+    pub const fn clean() -> Self {
+        Self {
+            drive_db: 2.0,
+            hardness: 0.2,
+            riaa_intensity: 0.3,
+            parallel_mix: 0.3,
+            dry_wet: 1.0,
+            magnetic_threshold: 0.60,
+            parallel_threshold: 0.50,
+            limiter_ceiling: 0.98,
+            limiter_release_ms: 50.0,
+            lowpass_hz: 20000.0,
+            lowpass_q: 0.707,
+        }
+    }
+
+    /// 이름으로 내장 프리셋 조회 (대소문자 무시)
+    pub fn builtin(name: &str) -> Option<Self> {
+        if name.eq_ignore_ascii_case("vinyl") {
+            Some(Self::vinyl())
+        } else if name.eq_ignore_ascii_case("warm") {
+            Some(Self::warm())
+        } else if name.eq_ignore_ascii_case("clean") {
+            Some(Self::clean())
+        } else {
+            None
+        }
+    }
+
+    /// 모든 값을 유효 범위로 제한 (외부 파일 로드 후 사용)
+    pub fn sanitized(self) -> Self {
+        let field =
+            |value: f32, fallback: f32, range: FieldRange| range.clamp(finite_or(value, fallback));
+        Self {
+            drive_db: field(self.drive_db, 4.0, Self::DRIVE_DB),
+            hardness: field(self.hardness, 0.5, Self::HARDNESS),
+            riaa_intensity: field(self.riaa_intensity, 0.5, Self::RIAA_INTENSITY),
+            parallel_mix: field(self.parallel_mix, 0.5, Self::PARALLEL_MIX),
+            dry_wet: field(self.dry_wet, 1.0, Self::DRY_WET),
+            magnetic_threshold: field(self.magnetic_threshold, 0.6, Self::MAGNETIC_THRESHOLD),
+            parallel_threshold: field(self.parallel_threshold, 0.5, Self::PARALLEL_THRESHOLD),
+            limiter_ceiling: field(self.limiter_ceiling, 0.95, Self::LIMITER_CEILING),
+            limiter_release_ms: field(self.limiter_release_ms, 100.0, Self::LIMITER_RELEASE_MS),
+            lowpass_hz: field(self.lowpass_hz, 20000.0, Self::LOWPASS_HZ),
+            lowpass_q: field(self.lowpass_q, 0.707, Self::LOWPASS_Q),
+        }
+    }
+
+    /// 필드별 유효 범위 (fields()와 같은 순서)
+    pub const fn ranges() -> [FieldRange; 11] {
+        [
+            Self::DRIVE_DB,
+            Self::HARDNESS,
+            Self::RIAA_INTENSITY,
+            Self::PARALLEL_MIX,
+            Self::DRY_WET,
+            Self::MAGNETIC_THRESHOLD,
+            Self::PARALLEL_THRESHOLD,
+            Self::LIMITER_CEILING,
+            Self::LIMITER_RELEASE_MS,
+            Self::LOWPASS_HZ,
+            Self::LOWPASS_Q,
+        ]
+    }
+
+    /// (필드 이름, 값) 목록 - 파일 입출력과 표시용
+    pub fn fields(&self) -> [(&'static str, f32); 11] {
+        [
+            ("drive_db", self.drive_db),
+            ("hardness", self.hardness),
+            ("riaa_intensity", self.riaa_intensity),
+            ("parallel_mix", self.parallel_mix),
+            ("dry_wet", self.dry_wet),
+            ("magnetic_threshold", self.magnetic_threshold),
+            ("parallel_threshold", self.parallel_threshold),
+            ("limiter_ceiling", self.limiter_ceiling),
+            ("limiter_release_ms", self.limiter_release_ms),
+            ("lowpass_hz", self.lowpass_hz),
+            ("lowpass_q", self.lowpass_q),
+        ]
+    }
+
+    /// 드라이브(dB)를 MagneticEQ saturation(0.0-1.0)으로 매핑
+    pub fn saturation(&self) -> f32 {
+        ((self.drive_db - 1.0) / 9.0).clamp(0.0, 1.0)
+    }
+
+    /// 릴리즈 시간을 샘플 단위로 변환 (Limiter::new 입력)
+    pub fn limiter_release_samples(&self, sample_rate: f32) -> f32 {
+        self.limiter_release_ms * 0.001 * sample_rate
+    }
+
+    /// 정규화된 Lowpass 컷오프 (cutoff / sample_rate, Nyquist 아래로 제한)
+    pub fn lowpass_normalized(&self, sample_rate: f32) -> f32 {
+        (self.lowpass_hz / sample_rate).clamp(0.0001, 0.45)
+    }
+}
+
+impl Default for ChainPreset {
+    fn default() -> Self {
+        Self {
+            drive_db: 4.0,
+            ..Self::warm()
+        }
+    }
+}
+
+#[inline]
+fn finite_or(value: f32, fallback: f32) -> f32 {
+    if value.is_finite() {
+        value
+    } else {
+        fallback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_lookup() {
+        for name in BUILTIN_PRESET_NAMES {
+            assert!(ChainPreset::builtin(name).is_some());
+        }
+        assert_eq!(ChainPreset::builtin("Vinyl"), Some(ChainPreset::vinyl()));
+        assert_eq!(ChainPreset::builtin("unknown"), None);
+    }
+
+    #[test]
+    fn test_sanitized_clamps() {
+        let preset = ChainPreset {
+            hardness: 3.0,
+            limiter_ceiling: f32::NAN,
+            ..ChainPreset::default()
+        }
+        .sanitized();
+        assert_eq!(preset.hardness, 1.0);
+        assert_eq!(preset.limiter_ceiling, 0.95);
+        assert!(ChainPreset::vinyl().lowpass_normalized(8000.0) < 0.5);
+    }
+
+    #[test]
+    fn test_builtins_are_within_ranges() {
+        for name in BUILTIN_PRESET_NAMES {
+            let preset = ChainPreset::builtin(name).unwrap();
+            assert_eq!(preset.sanitized(), preset, "{name}");
+            for ((field, value), range) in preset.fields().into_iter().zip(ChainPreset::ranges()) {
+                assert!(range.contains(value), "{name}.{field} = {value}");
+            }
+        }
+        assert_eq!(ChainPreset::default().sanitized(), ChainPreset::default());
+    }
+}
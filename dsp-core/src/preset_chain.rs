@@ -0,0 +1,589 @@
+//! 프리셋 체인 - ChainPreset 하나로 구성되는 전체 신호 경로 (CLI / AI 플러그인 / 골든 테스트 공용)
+//! 복원(럼블 -> 험 -> 클릭 -> 히스) -> Velocity 판정 -> 드라이브 + soft clip / Magnetic 포화
+//! -> RIAA 크로스페이드 -> Parallel HF -> 출력 단계(Lowpass -> 리미터) -> Dry/Wet
+
+use crate::analyzer_advanced::VelocityAnalyzer;
+use crate::biquad_wrapper::BiquadWrapper;
+use crate::declicker::Declicker;
+use crate::delay_line::DelayLine;
+use crate::fir::Fir;
+use crate::hum_remover::{HumRemover, RumbleFilter};
+use crate::knee_clip;
+use crate::limiter::Limiter;
+use crate::magnetic_eq::MagneticEQ;
+use crate::noise_reducer::{NoiseProfileMode, NoiseReducer};
+use crate::oversampler::Oversampler;
+use crate::parallel_filter_advanced::ParallelFilterAdvanced;
+use crate::preset::ChainPreset;
+use crate::processor_trait::{db_to_linear, mix_dry_wet, ProcessParams, Processor};
+use crate::quality::Quality;
+use crate::riaa_eq_advanced::RIAAEQAdvanced;
+use crate::smoothing::{ParamRamp, RampStyle, SmoothedParam};
+
+/// 버퍼 단위 Velocity 판정을 샘플 단위 크로스페이드로 변환하는 램프 시간
+const VELOCITY_FADE_MS: f32 = 10.0;
+/// Constant 램프의 내부 스무딩 시간
+const RAMP_MS: f32 = 20.0;
+/// Dry 경로 정렬 지연 최대값 (Wet 경로 지연 이상)
+const DRY_DELAY_MAX: usize = 4096;
+/// 고품질 모드의 선형 위상 출력 Lowpass 탭 수
+pub const LINEAR_PHASE_TAPS: usize = 127;
+
+/// PresetChain::process_block 파라미터 (샘플별 램프 또는 고정 목표값)
+#[derive(Clone, Copy, Debug)]
+pub struct ChainRamps<'a> {
+    pub drive_db: ParamRamp<'a>,
+    pub riaa_intensity: ParamRamp<'a>,
+    pub parallel_mix: ParamRamp<'a>,
+    pub dry_wet: ParamRamp<'a>,
+}
+
+impl ChainRamps<'static> {
+    /// 프리셋 값 그대로 (CLI / 오프라인 렌더링)
+    pub fn from_preset(preset: &ChainPreset) -> Self {
+        Self {
+            drive_db: ParamRamp::Constant(preset.drive_db),
+            riaa_intensity: ParamRamp::Constant(preset.riaa_intensity),
+            parallel_mix: ParamRamp::Constant(preset.parallel_mix),
+            dry_wet: ParamRamp::Constant(preset.dry_wet),
+        }
+    }
+}
+
+/// 복원 단계 설정 (클릭 / 히스 제거는 꺼도 지연 유지)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Restoration {
+    pub rumble: bool,
+    pub rumble_cutoff_hz: f32,
+    pub dehum: bool,
+    pub hum_harmonics: usize,
+    pub declick: bool,
+    pub declick_sensitivity: f32,
+    pub declick_max_ms: f32,
+    pub denoise: bool,
+    pub denoise_amount: f32,
+    pub denoise_floor_db: f32,
+    pub denoise_profile: NoiseProfileMode,
+}
+
+impl Default for Restoration {
+    fn default() -> Self {
+        Self {
+            rumble: false,
+            rumble_cutoff_hz: 20.0,
+            dehum: false,
+            hum_harmonics: 4,
+            declick: false,
+            declick_sensitivity: 0.5,
+            declick_max_ms: 1.0,
+            denoise: false,
+            denoise_amount: 2.0,
+            denoise_floor_db: -20.0,
+            denoise_profile: NoiseProfileMode::Auto,
+        }
+    }
+}
+
+/// 프리셋 출력 단계: Lowpass -> 리미터 (프리셋 체인 / 테이프 체인 공용)
+pub struct OutputStage {
+    lowpass: BiquadWrapper,
+    /// 선형 위상 모드에서 lowpass 대신 사용 (같은 진폭 응답)
+    linear_lowpass: Fir<LINEAR_PHASE_TAPS>,
+    /// 필터를 설계한 (정규화 컷오프, Q)
+    lowpass_settings: (f32, f32),
+    linear_phase: bool,
+    limiter: Limiter,
+}
+
+/// Biquad Lowpass의 진폭 응답 (Q 공진 포함, 선형 위상 FIR 설계용)
+fn lowpass_magnitude(cutoff: f32, q: f32) -> impl Fn(f64) -> f64 {
+    let biquad = BiquadWrapper::<f64>::lowpass(cutoff, q);
+    move |w| biquad.magnitude(w)
+}
+
+impl OutputStage {
+    pub fn new(preset: &ChainPreset, sample_rate: f32) -> Self {
+        let (cutoff, q) = (preset.lowpass_normalized(sample_rate), preset.lowpass_q);
+        Self {
+            lowpass: BiquadWrapper::lowpass(cutoff, q),
+            linear_lowpass: Fir::from_magnitude(lowpass_magnitude(cutoff, q)),
+            lowpass_settings: (cutoff, q),
+            linear_phase: false,
+            limiter: Limiter::new(
+                preset.limiter_ceiling,
+                preset.limiter_release_samples(sample_rate),
+            ),
+        }
+    }
+
+    /// 프리셋의 필터/리미터 설정 적용 (필터/리미터 상태 유지, Lowpass는 설정이 바뀔 때만 재설계)
+    pub fn configure(&mut self, preset: &ChainPreset, sample_rate: f32) {
+        let (cutoff, q) = (preset.lowpass_normalized(sample_rate), preset.lowpass_q);
+        if (cutoff, q) != self.lowpass_settings {
+            self.lowpass
+                .set_coefficients(&BiquadWrapper::lowpass(cutoff, q));
+            self.linear_lowpass
+                .set_magnitude(lowpass_magnitude(cutoff, q));
+            self.lowpass_settings = (cutoff, q);
+        }
+        self.limiter.set_threshold(preset.limiter_ceiling);
+        self.limiter
+            .set_release_time(preset.limiter_release_samples(sample_rate));
+    }
+
+    /// 선형 위상 Lowpass 사용 여부 (지연이 바뀜)
+    pub fn set_linear_phase(&mut self, linear_phase: bool) {
+        if linear_phase != self.linear_phase {
+            self.linear_phase = linear_phase;
+            self.lowpass.reset();
+            self.linear_lowpass.reset();
+        }
+    }
+
+    #[inline]
+    pub fn process(&mut self, input: f32) -> f32 {
+        let y = if self.linear_phase {
+            self.linear_lowpass.process(input)
+        } else {
+            self.lowpass.process(input)
+        };
+        self.limiter.process(y)
+    }
+
+    pub fn gain_reduction_db(&self) -> f32 {
+        self.limiter.gain_reduction_db()
+    }
+
+    pub fn latency_samples(&self) -> usize {
+        let lowpass = if self.linear_phase {
+            self.linear_lowpass.latency_samples()
+        } else {
+            0
+        };
+        lowpass + self.limiter.latency_samples()
+    }
+
+    pub fn reset(&mut self) {
+        self.lowpass.reset();
+        self.linear_lowpass.reset();
+        self.limiter.reset();
+    }
+}
+
+/// 채널 하나의 프리셋 체인 상태
+pub struct PresetChain {
+    sample_rate: f32,
+    preset: ChainPreset,
+    restoration: Restoration,
+    rumble: RumbleFilter,
+    hum: HumRemover,
+    declicker: Declicker,
+    denoiser: NoiseReducer,
+    /// 저역 극점이 단위원에 가까우므로 f64로 처리
+    riaa: RIAAEQAdvanced<f64>,
+    parallel: ParallelFilterAdvanced,
+    magnetic: MagneticEQ,
+    /// 드라이브/포화 단계 오버샘플링
+    oversampler: Oversampler,
+    output: OutputStage,
+    /// Dry 신호를 Wet 경로 지연만큼 늦춰 Dry/Wet 믹스 위상 정렬
+    dry_delay: DelayLine<DRY_DELAY_MAX>,
+    /// 1.0 = Magnetic 경로, 0.0 = RIAA 경로
+    magnetic_blend: SmoothedParam,
+    /// Parallel HF Recovery 적용 여부 (0.0-1.0)
+    parallel_gate: SmoothedParam,
+    drive_db: SmoothedParam,
+    riaa_intensity: SmoothedParam,
+    parallel_mix: SmoothedParam,
+    dry_wet: SmoothedParam,
+}
+
+impl PresetChain {
+    pub fn new(sample_rate: f32, preset: &ChainPreset) -> Self {
+        let param = |style, value| {
+            let mut param = SmoothedParam::new(style, value);
+            param.set_sample_rate(sample_rate);
+            param
+        };
+        let restoration = Restoration::default();
+        let mut chain = Self {
+            sample_rate,
+            preset: *preset,
+            restoration,
+            rumble: RumbleFilter::new(sample_rate, restoration.rumble_cutoff_hz),
+            hum: HumRemover::new(sample_rate, restoration.hum_harmonics, 30.0),
+            declicker: Declicker::new(
+                sample_rate,
+                restoration.declick_sensitivity,
+                restoration.declick_max_ms,
+            ),
+            denoiser: NoiseReducer::new(
+                restoration.denoise_amount,
+                restoration.denoise_floor_db,
+                0.5,
+            ),
+            riaa: RIAAEQAdvanced::new(sample_rate as u32),
+            parallel: ParallelFilterAdvanced::new(0.0),
+            magnetic: MagneticEQ::new(preset.saturation(), preset.hardness),
+            oversampler: Oversampler::new(1),
+            output: OutputStage::new(preset, sample_rate),
+            dry_delay: DelayLine::new(0),
+            magnetic_blend: param(RampStyle::Linear(VELOCITY_FADE_MS), 0.0),
+            parallel_gate: param(RampStyle::Linear(VELOCITY_FADE_MS), 0.0),
+            drive_db: param(RampStyle::Linear(RAMP_MS), preset.drive_db),
+            riaa_intensity: param(RampStyle::Linear(RAMP_MS), preset.riaa_intensity),
+            parallel_mix: param(RampStyle::Linear(RAMP_MS), preset.parallel_mix),
+            dry_wet: param(RampStyle::Linear(RAMP_MS), preset.dry_wet),
+        };
+        chain.set_restoration(&restoration);
+        chain
+    }
+
+    pub fn preset(&self) -> &ChainPreset {
+        &self.preset
+    }
+
+    /// 프리셋 변경: 필터/리미터/포화/임계값은 즉시, 램프 파라미터는 목표값으로 스무딩
+    pub fn set_preset(&mut self, preset: &ChainPreset) {
+        self.preset = *preset;
+        self.output.configure(preset, self.sample_rate);
+        self.magnetic.set_hardness(preset.hardness);
+        self.drive_db.set_target(preset.drive_db);
+        self.riaa_intensity.set_target(preset.riaa_intensity);
+        self.parallel_mix.set_target(preset.parallel_mix);
+        self.dry_wet.set_target(preset.dry_wet);
+        self.update_delays();
+    }
+
+    pub fn set_restoration(&mut self, restoration: &Restoration) {
+        self.restoration = *restoration;
+        self.rumble.set_cutoff(restoration.rumble_cutoff_hz);
+        self.hum.set_harmonics(restoration.hum_harmonics);
+        self.declicker.set_enabled(restoration.declick);
+        self.declicker
+            .set_sensitivity(restoration.declick_sensitivity);
+        self.declicker
+            .set_max_click_ms(restoration.declick_max_ms, self.sample_rate);
+        self.denoiser.set_enabled(restoration.denoise);
+        self.denoiser
+            .set_over_subtraction(restoration.denoise_amount);
+        self.denoiser.set_floor_db(restoration.denoise_floor_db);
+        self.denoiser.set_profile_mode(restoration.denoise_profile);
+    }
+
+    /// 품질 단계 적용 (오버샘플링 / 분석 윈도우 / 선형 위상 EQ), 지연이 바뀔 수 있음
+    pub fn set_quality(&mut self, quality: Quality) {
+        self.oversampler.set_factor(quality.oversampling());
+        self.hum
+            .set_detection_window(quality.analysis_window_seconds());
+        self.output.set_linear_phase(quality.linear_phase_eq());
+        self.update_delays();
+    }
+
+    /// 복원 단계(클릭/잡음 제거) 지연 합계
+    /// Dry 신호는 복원 후에 분기하므로 외부 분석 신호(사이드체인)는 이만큼 정렬
+    pub fn restoration_latency(&self) -> usize {
+        self.declicker.latency_samples() + self.denoiser.latency_samples()
+    }
+
+    /// Dry 분기 이후 Wet 경로 지연 합계
+    fn wet_latency(&self) -> usize {
+        self.oversampler.latency_samples() + self.output.latency_samples()
+    }
+
+    fn update_delays(&mut self) {
+        self.dry_delay.set_delay(self.wet_latency());
+    }
+
+    /// Velocity에 따른 경로 선택 (다음 샘플부터 크로스페이드)
+    pub fn set_velocity(&mut self, velocity: f32) {
+        let magnetic = velocity < self.preset.magnetic_threshold;
+        let parallel = velocity < self.preset.parallel_threshold;
+        self.magnetic_blend
+            .set_target(if magnetic { 1.0 } else { 0.0 });
+        self.parallel_gate
+            .set_target(if parallel { 1.0 } else { 0.0 });
+    }
+
+    /// 복원 단계만 제자리 처리 (이후 분석과 Dry 신호 모두 복원된 입력 사용)
+    pub fn restore(&mut self, samples: &mut [f32]) {
+        if self.restoration.rumble {
+            for sample in samples.iter_mut() {
+                *sample = self.rumble.process(*sample);
+            }
+        }
+        if self.restoration.dehum {
+            for sample in samples.iter_mut() {
+                *sample = self.hum.process(*sample);
+            }
+        }
+        for sample in samples.iter_mut() {
+            *sample = self.denoiser.process(self.declicker.process(*sample));
+        }
+    }
+
+    /// 버퍼 하나 처리: 복원 -> Velocity 판정 -> 체인
+    /// key_velocity: 외부 velocity (사이드체인 / MIDI / 고정값), None이면 복원된 입력 분석
+    /// 판정에 사용한 velocity 반환
+    pub fn process_block(
+        &mut self,
+        samples: &mut [f32],
+        ramps: &ChainRamps,
+        key_velocity: Option<f32>,
+    ) -> f32 {
+        self.restore(samples);
+        let velocity =
+            key_velocity.unwrap_or_else(|| VelocityAnalyzer::calculate_velocity(samples));
+        self.set_velocity(velocity);
+        for (n, sample) in samples.iter_mut().enumerate() {
+            ramps.drive_db.apply(&mut self.drive_db, n);
+            ramps.riaa_intensity.apply(&mut self.riaa_intensity, n);
+            ramps.parallel_mix.apply(&mut self.parallel_mix, n);
+            ramps.dry_wet.apply(&mut self.dry_wet, n);
+            *sample = self.process_sample(*sample);
+        }
+        velocity
+    }
+
+    /// 복원 이후 체인 한 샘플 (램프 파라미터 한 스텝 진행)
+    pub fn process_sample(&mut self, input: f32) -> f32 {
+        let drive_db = self.drive_db.next();
+        let dry = self.dry_delay.process(input);
+        let driven = input * db_to_linear(drive_db);
+
+        // 비선형 단계(soft clip + Magnetic 포화)만 오버샘플링
+        self.magnetic.set_saturation(
+            ChainPreset {
+                drive_db,
+                ..self.preset
+            }
+            .saturation(),
+        );
+        let blend = self.magnetic_blend.next();
+        let magnetic = &self.magnetic;
+        let mut y = self.oversampler.process(driven, |x| {
+            let clipped = knee_clip(x);
+            mix_dry_wet(clipped, magnetic.process(clipped), blend)
+        });
+
+        // RIAA 크로스페이드는 RIAA 경로 비율만큼 적용
+        let riaa = self.riaa.process(y as f64) as f32;
+        y = mix_dry_wet(y, riaa, self.riaa_intensity.next() * (1.0 - blend));
+
+        let parallel_intensity = self.parallel_mix.next() * self.parallel_gate.next();
+        self.parallel.set_intensity(parallel_intensity);
+        y = self.parallel.process(y);
+        y = self.output.process(y);
+        mix_dry_wet(dry, y, self.dry_wet.next())
+    }
+
+    /// 현재 Magnetic 경로 비율 (미터)
+    pub fn magnetic_blend(&self) -> f32 {
+        self.magnetic_blend.value()
+    }
+
+    /// 현재 RIAA 크로스페이드 비율 (미터)
+    pub fn riaa_blend(&self) -> f32 {
+        self.riaa_intensity.value() * (1.0 - self.magnetic_blend.value())
+    }
+
+    /// 현재 Parallel HF 적용 비율 (미터)
+    pub fn parallel_blend(&self) -> f32 {
+        self.parallel_mix.value() * self.parallel_gate.value()
+    }
+
+    pub fn gain_reduction_db(&self) -> f32 {
+        self.output.gain_reduction_db()
+    }
+
+    /// 필터 상태 초기화 (new()와 같은 초기 상태, 검출된 험 주파수는 유지)
+    pub fn reset(&mut self) {
+        self.rumble.reset();
+        self.hum.reset();
+        self.declicker.reset();
+        self.denoiser.reset();
+        self.riaa.reset();
+        self.parallel.reset();
+        self.oversampler.reset();
+        self.output.reset();
+        self.dry_delay.reset();
+        self.magnetic_blend.set_immediate(0.0);
+        self.parallel_gate.set_immediate(0.0);
+        for param in [
+            &mut self.drive_db,
+            &mut self.riaa_intensity,
+            &mut self.parallel_mix,
+            &mut self.dry_wet,
+        ] {
+            param.set_immediate(param.target());
+        }
+    }
+}
+
+/// 블록 단위 Velocity 판정이 필요하므로 Processor::process 한 번이 버퍼 하나
+impl Processor for PresetChain {
+    fn process(&mut self, input: &[f32], output: &mut [f32], _params: &ProcessParams) {
+        output.copy_from_slice(input);
+        let ramps = ChainRamps::from_preset(&self.preset);
+        self.process_block(output, &ramps, None);
+    }
+
+    fn process_sample(&mut self, input: f32, _params: &ProcessParams) -> f32 {
+        PresetChain::process_sample(self, input)
+    }
+
+    fn reset(&mut self) {
+        PresetChain::reset(self);
+    }
+
+    fn name(&self) -> &str {
+        "Preset Chain"
+    }
+
+    fn latency_samples(&self) -> usize {
+        self.restoration_latency() + self.wet_latency()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine(freq: f32, len: usize, amplitude: f32) -> Vec<f32> {
+        (0..len)
+            .map(|n| amplitude * (2.0 * core::f32::consts::PI * freq * n as f32 / 48000.0).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_dry_path_is_aligned_with_reported_latency() {
+        for quality in [Quality::Eco, Quality::Normal, Quality::Ultra] {
+            let preset = ChainPreset {
+                dry_wet: 0.0,
+                ..ChainPreset::vinyl()
+            };
+            let mut chain = PresetChain::new(48000.0, &preset);
+            chain.set_quality(quality);
+            let latency = chain.latency_samples();
+            let input = sine(440.0, 4096, 0.25);
+            let mut output = input.clone();
+            for block in output.chunks_mut(256) {
+                chain.process_block(block, &ChainRamps::from_preset(&preset), None);
+            }
+            for n in latency + 100..input.len() {
+                assert!(
+                    (output[n] - input[n - latency]).abs() < 1e-4,
+                    "{quality:?} @ {n}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_preset_fields_change_the_output() {
+        let input = sine(1000.0, 4800, 0.5);
+        let render = |preset: ChainPreset, velocity: f32| {
+            let mut chain = PresetChain::new(48000.0, &preset);
+            let mut output = input.clone();
+            chain.process_block(
+                &mut output,
+                &ChainRamps::from_preset(&preset),
+                Some(velocity),
+            );
+            output
+        };
+        let base = ChainPreset::warm();
+        let reference = render(base, 0.9);
+        for preset in [
+            ChainPreset {
+                drive_db: 9.0,
+                ..base
+            },
+            ChainPreset {
+                riaa_intensity: 0.0,
+                ..base
+            },
+            ChainPreset {
+                limiter_ceiling: 0.3,
+                ..base
+            },
+            ChainPreset {
+                lowpass_hz: 2000.0,
+                ..base
+            },
+        ] {
+            let output = render(preset, 0.9);
+            assert!(
+                output
+                    .iter()
+                    .zip(&reference)
+                    .any(|(a, b)| (a - b).abs() > 1e-3),
+                "{preset:?}"
+            );
+        }
+        // 낮은 velocity: Magnetic 경로 + Parallel HF (hardness / parallel_mix 적용)
+        let quiet = render(base, 0.1);
+        let harder = render(
+            ChainPreset {
+                hardness: 1.0,
+                ..base
+            },
+            0.1,
+        );
+        let parallel = render(
+            ChainPreset {
+                parallel_mix: 0.0,
+                ..base
+            },
+            0.1,
+        );
+        assert!(quiet.iter().zip(&harder).any(|(a, b)| (a - b).abs() > 1e-3));
+        assert!(quiet
+            .iter()
+            .zip(&parallel)
+            .any(|(a, b)| (a - b).abs() > 1e-3));
+    }
+
+    #[test]
+    fn test_limiter_ceiling_holds() {
+        let preset = ChainPreset {
+            drive_db: 10.0,
+            limiter_ceiling: 0.5,
+            ..ChainPreset::vinyl()
+        };
+        let mut chain = PresetChain::new(48000.0, &preset);
+        let mut samples = sine(200.0, 9600, 1.0);
+        chain.process_block(&mut samples, &ChainRamps::from_preset(&preset), None);
+        assert!(samples.iter().all(|y| y.abs() <= 0.5 + 1e-6));
+    }
+
+    #[test]
+    fn test_drive_automation_keeps_limiter_state() {
+        // 블록마다 drive만 바뀌는 set_preset (호스트 오토메이션): 리미터 엔벨로프가 초기화되면
+        // 블록 첫 샘플의 게인 리덕션이 0으로 떨어짐
+        let preset = ChainPreset {
+            drive_db: 10.0,
+            limiter_ceiling: 0.5,
+            ..ChainPreset::vinyl()
+        };
+        let mut automated = PresetChain::new(48000.0, &preset);
+        let mut fixed = PresetChain::new(48000.0, &preset);
+        let input = sine(200.0, 9600, 1.0);
+        for (n, block) in input.chunks(64).enumerate() {
+            automated.set_preset(&ChainPreset {
+                drive_db: 10.0 + 0.001 * n as f32,
+                ..preset
+            });
+            for (i, &x) in block.iter().enumerate() {
+                automated.process_sample(x);
+                fixed.process_sample(x);
+                let (moving, steady) = (automated.gain_reduction_db(), fixed.gain_reduction_db());
+                assert!(
+                    (moving - steady).abs() < 0.5,
+                    "block {n} sample {i}: {moving} dB vs {steady} dB"
+                );
+            }
+        }
+        assert!(fixed.gain_reduction_db() > 1.0);
+    }
+}
@@ -0,0 +1,439 @@
+//! 프리셋 파일 입출력 (TOML / JSON)
+//! 스키마 버전 관리, 마이그레이션, 프리셋 뱅크
+
+use crate::preset::{ChainPreset, BUILTIN_PRESET_NAMES};
+use serde_json::{Map, Value};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// 현재 프리셋 스키마 버전
+/// 파라미터 이름/의미가 바뀌면 올리고 MIGRATIONS에 단계를 추가
+/// (새 필드 추가만으로는 불필요 - 누락 필드는 기본값 사용)
+pub const PRESET_SCHEMA_VERSION: u32 = 1;
+
+/// 버전 n -> n+1 마이그레이션 (인덱스 = 원본 버전)
+const MIGRATIONS: &[fn(&mut Map<String, Value>)] = &[migrate_v0_to_v1];
+
+/// v0: version 필드 없음, 드라이브가 `drive` 키 (구 CLI 형식)
+fn migrate_v0_to_v1(map: &mut Map<String, Value>) {
+    if let Some(drive) = map.remove("drive") {
+        map.entry("drive_db").or_insert(drive);
+    }
+}
+
+/// 프리셋 파일 형식
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PresetFormat {
+    Toml,
+    Json,
+}
+
+impl PresetFormat {
+    /// 확장자(.toml / .json)로 형식 판단
+    pub fn from_path(path: &Path) -> Result<Self, PresetError> {
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match ext.as_str() {
+            "toml" => Ok(PresetFormat::Toml),
+            "json" => Ok(PresetFormat::Json),
+            _ => Err(PresetError::UnsupportedFormat(ext)),
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            PresetFormat::Toml => "toml",
+            PresetFormat::Json => "json",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PresetError {
+    Io(std::io::Error),
+    Parse(String),
+    UnsupportedFormat(String),
+    UnsupportedVersion(u32),
+    /// 같은 이름(슬러그)의 사용자 프리셋이 이미 있음
+    AlreadyExists(PathBuf),
+    /// 내장 / 사용자 프리셋에 없는 이름 (known: 사용 가능한 이름)
+    UnknownPreset { name: String, known: Vec<String> },
+}
+
+impl fmt::Display for PresetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PresetError::Io(err) => write!(f, "preset I/O error: {err}"),
+            PresetError::Parse(msg) => write!(f, "invalid preset: {msg}"),
+            PresetError::UnsupportedFormat(ext) => {
+                write!(f, "unsupported preset format '{ext}' (expected .toml or .json)")
+            }
+            PresetError::UnsupportedVersion(version) => write!(
+                f,
+                "preset schema version {version} is newer than supported version {PRESET_SCHEMA_VERSION}"
+            ),
+            PresetError::AlreadyExists(path) => {
+                write!(f, "preset {} already exists", path.display())
+            }
+            PresetError::UnknownPreset { name, known } => {
+                write!(f, "unknown preset '{name}' (known: {})", known.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for PresetError {}
+
+impl From<std::io::Error> for PresetError {
+    fn from(err: std::io::Error) -> Self {
+        PresetError::Io(err)
+    }
+}
+
+/// 로드 결과 보고 (validate 용)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LoadReport {
+    /// 마이그레이션 전 원본 버전 (현재 버전이면 None)
+    pub migrated_from: Option<u32>,
+    /// 범위를 벗어나 제한된 필드
+    pub clamped: Vec<&'static str>,
+    /// 알 수 없는 키 (오타 가능성)
+    pub unknown_keys: Vec<String>,
+}
+
+/// 이름이 붙은 프리셋 파일
+/// 누락된 필드는 ChainPreset::default() 값 사용
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PresetFile {
+    pub name: String,
+    #[serde(flatten)]
+    pub chain: ChainPreset,
+}
+
+impl PresetFile {
+    pub fn new(name: impl Into<String>, chain: ChainPreset) -> Self {
+        Self {
+            name: name.into(),
+            chain,
+        }
+    }
+
+    pub fn from_toml_str(text: &str) -> Result<Self, PresetError> {
+        Self::parse(text, PresetFormat::Toml).map(|(file, _)| file)
+    }
+
+    pub fn from_json_str(text: &str) -> Result<Self, PresetError> {
+        Self::parse(text, PresetFormat::Json).map(|(file, _)| file)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, PresetError> {
+        Self::load_with_report(path).map(|(file, _)| file)
+    }
+
+    pub fn load_with_report(path: &Path) -> Result<(Self, LoadReport), PresetError> {
+        let format = PresetFormat::from_path(path)?;
+        let text = std::fs::read_to_string(path)?;
+        Self::parse(&text, format)
+    }
+
+    /// 파싱 -> 마이그레이션 -> 범위 제한
+    pub fn parse(text: &str, format: PresetFormat) -> Result<(Self, LoadReport), PresetError> {
+        let value: Value = match format {
+            PresetFormat::Toml => toml::from_str(text).map_err(|e| PresetError::Parse(e.to_string()))?,
+            PresetFormat::Json => {
+                serde_json::from_str(text).map_err(|e| PresetError::Parse(e.to_string()))?
+            }
+        };
+        let Value::Object(mut map) = value else {
+            return Err(PresetError::Parse("preset must be a table".to_string()));
+        };
+
+        let version = match map.remove("version") {
+            None => 0,
+            Some(v) => v
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .ok_or_else(|| PresetError::Parse("version must be a non-negative integer".to_string()))?,
+        };
+        if version > PRESET_SCHEMA_VERSION {
+            return Err(PresetError::UnsupportedVersion(version));
+        }
+        for step in &MIGRATIONS[version as usize..] {
+            step(&mut map);
+        }
+
+        let known = ChainPreset::default().fields();
+        let unknown_keys = map
+            .keys()
+            .filter(|key| key.as_str() != "name" && !known.iter().any(|(name, _)| name == key))
+            .cloned()
+            .collect();
+
+        let raw: Self =
+            serde_json::from_value(Value::Object(map)).map_err(|e| PresetError::Parse(e.to_string()))?;
+        let chain = raw.chain.sanitized();
+        let clamped = raw
+            .chain
+            .fields()
+            .iter()
+            .zip(chain.fields())
+            .filter(|(before, after)| before.1 != after.1)
+            .map(|(before, _)| before.0)
+            .collect();
+
+        let report = LoadReport {
+            migrated_from: (version < PRESET_SCHEMA_VERSION).then_some(version),
+            clamped,
+            unknown_keys,
+        };
+        Ok((Self::new(raw.name, chain), report))
+    }
+
+    /// 현재 스키마 버전으로 직렬화
+    pub fn to_string(&self, format: PresetFormat) -> Result<String, PresetError> {
+        let value = self.to_value();
+        match format {
+            PresetFormat::Toml => {
+                toml::to_string_pretty(&value).map_err(|e| PresetError::Parse(e.to_string()))
+            }
+            PresetFormat::Json => {
+                serde_json::to_string_pretty(&value).map_err(|e| PresetError::Parse(e.to_string()))
+            }
+        }
+    }
+
+    /// 확장자에 맞는 형식으로 저장
+    pub fn save(&self, path: &Path) -> Result<(), PresetError> {
+        let mut text = self.to_string(PresetFormat::from_path(path)?)?;
+        if !text.ends_with('\n') {
+            text.push('\n');
+        }
+        std::fs::write(path, text)?;
+        Ok(())
+    }
+
+    fn to_value(&self) -> Value {
+        let mut map = Map::new();
+        map.insert("version".to_string(), PRESET_SCHEMA_VERSION.into());
+        map.insert("name".to_string(), self.name.clone().into());
+        for (key, value) in self.chain.fields() {
+            if let Some(number) = float_value(value) {
+                map.insert(key.to_string(), number);
+            }
+        }
+        Value::Object(map)
+    }
+}
+
+/// f32의 최단 10진 표현 유지 (0.707 -> 0.7070000171661377 방지)
+fn float_value(value: f32) -> Option<Value> {
+    let wide: f64 = value.to_string().parse().ok()?;
+    serde_json::Number::from_f64(wide).map(Value::Number)
+}
+
+/// 파일 이름용 슬러그 ("Dusty Vinyl" -> "dusty-vinyl")
+pub fn preset_slug(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_matches('-');
+    if slug.is_empty() {
+        "preset".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// 프리셋 출처
+#[derive(Clone, Debug, PartialEq)]
+pub enum PresetSource {
+    Builtin,
+    File(PathBuf),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PresetEntry {
+    pub name: String,
+    pub chain: ChainPreset,
+    pub source: PresetSource,
+}
+
+/// 내장 프리셋 + 사용자 프리셋 디렉터리
+#[derive(Clone, Debug, Default)]
+pub struct PresetBank {
+    entries: Vec<PresetEntry>,
+}
+
+impl PresetBank {
+    /// 내장 프리셋만 포함
+    pub fn builtin() -> Self {
+        let entries = BUILTIN_PRESET_NAMES
+            .iter()
+            .filter_map(|name| {
+                ChainPreset::builtin(name).map(|chain| PresetEntry {
+                    name: name.to_string(),
+                    chain,
+                    source: PresetSource::Builtin,
+                })
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// 내장 프리셋 + 기본 사용자 디렉터리
+    pub fn with_user_presets() -> (Self, Vec<(PathBuf, PresetError)>) {
+        let mut bank = Self::builtin();
+        let errors = match Self::user_dir() {
+            Some(dir) => bank.load_dir(&dir),
+            None => Vec::new(),
+        };
+        (bank, errors)
+    }
+
+    /// 사용자 프리셋 디렉터리
+    /// RUSTROVER_PRESET_DIR > $XDG_CONFIG_HOME/rustrover/presets > ~/.config/rustrover/presets
+    pub fn user_dir() -> Option<PathBuf> {
+        if let Some(dir) = std::env::var_os("RUSTROVER_PRESET_DIR") {
+            return Some(PathBuf::from(dir));
+        }
+        let config = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(config.join("rustrover").join("presets"))
+    }
+
+    /// 디렉터리의 .toml/.json 파일 추가 (이름순)
+    /// 읽을 수 없는 파일은 건너뛰고 (경로, 에러) 목록으로 반환
+    pub fn load_dir(&mut self, dir: &Path) -> Vec<(PathBuf, PresetError)> {
+        let mut errors = Vec::new();
+        let mut paths: Vec<PathBuf> = match std::fs::read_dir(dir) {
+            Ok(read_dir) => read_dir
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.is_file() && PresetFormat::from_path(path).is_ok())
+                .collect(),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return errors,
+            Err(err) => {
+                errors.push((dir.to_path_buf(), PresetError::Io(err)));
+                return errors;
+            }
+        };
+        paths.sort();
+
+        for path in paths {
+            match PresetFile::load(&path) {
+                Ok(file) => self.entries.push(PresetEntry {
+                    name: file.name,
+                    chain: file.chain,
+                    source: PresetSource::File(path),
+                }),
+                Err(err) => errors.push((path, err)),
+            }
+        }
+        errors
+    }
+
+    pub fn entries(&self) -> &[PresetEntry] {
+        &self.entries
+    }
+
+    /// 이름으로 조회 (대소문자 무시, 같은 이름이면 나중에 추가된 사용자 프리셋 우선)
+    pub fn find(&self, name: &str) -> Option<&PresetEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.name.eq_ignore_ascii_case(name) || preset_slug(&entry.name) == name)
+    }
+
+    /// find()와 같지만 없는 이름이면 사용 가능한 이름 목록을 담은 UnknownPreset
+    pub fn get(&self, name: &str) -> Result<&PresetEntry, PresetError> {
+        self.find(name).ok_or_else(|| PresetError::UnknownPreset {
+            name: name.to_string(),
+            known: self.entries.iter().map(|entry| entry.name.clone()).collect(),
+        })
+    }
+
+    /// 프리셋을 디렉터리에 `<slug>.toml` 로 저장하고 경로 반환
+    /// 같은 슬러그의 파일이 있으면 overwrite 가 아닐 때 AlreadyExists
+    pub fn install(file: &PresetFile, dir: &Path, overwrite: bool) -> Result<PathBuf, PresetError> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(format!("{}.{}", preset_slug(&file.name), PresetFormat::Toml.extension()));
+        if !overwrite && path.exists() {
+            return Err(PresetError::AlreadyExists(path));
+        }
+        file.save(&path)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_toml_uses_defaults() {
+        let file = PresetFile::from_toml_str("version = 1\nname = \"Dusty\"\ndrive_db = 7\nhardness = 0.9\n").unwrap();
+        assert_eq!(file.name, "Dusty");
+        assert_eq!(file.chain.drive_db, 7.0);
+        assert_eq!(file.chain.hardness, 0.9);
+        assert_eq!(file.chain.lowpass_hz, ChainPreset::default().lowpass_hz);
+    }
+
+    #[test]
+    fn test_v0_migration() {
+        let (file, report) =
+            PresetFile::parse(r#"{"name": "Old", "drive": 6.5, "hardnes": 0.2}"#, PresetFormat::Json).unwrap();
+        assert_eq!(file.chain.drive_db, 6.5);
+        assert_eq!(report.migrated_from, Some(0));
+        assert_eq!(report.unknown_keys, vec!["hardnes".to_string()]);
+    }
+
+    #[test]
+    fn test_rejects_newer_version_and_missing_name() {
+        let newer = format!("version = {}\nname = \"x\"\n", PRESET_SCHEMA_VERSION + 1);
+        assert!(matches!(
+            PresetFile::from_toml_str(&newer),
+            Err(PresetError::UnsupportedVersion(_))
+        ));
+        assert!(PresetFile::from_json_str("{}").is_err());
+    }
+
+    #[test]
+    fn test_round_trip_and_clamp_report() {
+        let file = PresetFile::new("Round Trip", ChainPreset::vinyl());
+        for format in [PresetFormat::Toml, PresetFormat::Json] {
+            let text = file.to_string(format).unwrap();
+            assert!(text.contains("0.707"));
+            let (parsed, report) = PresetFile::parse(&text, format).unwrap();
+            assert_eq!(parsed, file);
+            assert_eq!(report, LoadReport::default());
+        }
+
+        let (_, report) = PresetFile::parse("name = \"Hot\"\nhardness = 4.0\n", PresetFormat::Toml).unwrap();
+        assert_eq!(report.clamped, vec!["hardness"]);
+
+        // 플러그인 파라미터 범위 밖의 값도 clamped 로 보고 (CLI와 플러그인이 같은 값으로 렌더)
+        let text =
+            "name = \"Wide\"\ndrive_db = -3.0\nlimiter_ceiling = 0.05\nlowpass_hz = 40000.0\n";
+        let (parsed, report) = PresetFile::parse(text, PresetFormat::Toml).unwrap();
+        assert_eq!(
+            report.clamped,
+            vec!["drive_db", "limiter_ceiling", "lowpass_hz"]
+        );
+        assert_eq!(parsed.chain.lowpass_hz, ChainPreset::LOWPASS_HZ.max);
+    }
+
+    #[test]
+    fn test_preset_slug() {
+        assert_eq!(preset_slug("Dusty Vinyl #2"), "dusty-vinyl-2");
+        assert_eq!(preset_slug("  "), "preset");
+    }
+}
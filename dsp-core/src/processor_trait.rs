@@ -1,5 +1,7 @@
-/// 공통 DSP Processor 트레이트
-/// 모든 DSP 처리 모듈이 구현해야 할 인터페이스
+//! 공통 DSP Processor 트레이트
+//! 모든 DSP 처리 모듈이 구현해야 할 인터페이스
+
+use crate::math;
 
 /// DSP 파라미터 구조
 #[derive(Clone, Debug)]
@@ -34,13 +36,18 @@ pub trait Processor {
     fn process_sample(&mut self, input: f32, params: &ProcessParams) -> f32;
 
     /// 파라미터 업데이트
-    fn update_params(&mut self, params: &ProcessParams) {}
+    fn update_params(&mut self, _params: &ProcessParams) {}
 
     /// 리셋 (상태 초기화)
     fn reset(&mut self) {}
 
     /// 프로세서 이름
     fn name(&self) -> &str;
+
+    /// 입력 대비 출력 지연 (샘플, 룩어헤드/프레임 처리 등)
+    fn latency_samples(&self) -> usize {
+        0
+    }
 }
 
 /// Dry/Wet 믹싱 헬퍼
@@ -51,16 +58,7 @@ pub fn mix_dry_wet(dry: f32, wet: f32, mix: f32) -> f32 {
 /// dB를 Linear로 변환
 #[inline]
 pub fn db_to_linear(db: f32) -> f32 {
-    #[cfg(feature = "std")]
-    {
-        10.0_f32.powf(db / 20.0)
-    }
-
-    #[cfg(not(feature = "std"))]
-    {
-        use libm::pow10f;
-        pow10f(db / 20.0)
-    }
+    math::exp10(db / 20.0)
 }
 
 #[cfg(test)]
@@ -89,4 +87,15 @@ mod tests {
         let linear_6db = db_to_linear(6.0);
         assert!(linear_6db > 1.0);
     }
+
+    #[test]
+    fn test_latency_through_trait() {
+        let limiter = crate::Limiter::<f32>::new(0.9, 100.0);
+        let declicker = crate::Declicker::new(48000.0, 0.5, 1.0);
+        let chain: [&dyn Processor; 2] = [&limiter, &declicker];
+        let total: usize = chain.iter().map(|p| p.latency_samples()).sum();
+        assert_eq!(limiter.latency_samples(), 0);
+        assert_eq!(total, declicker.latency_samples());
+        assert!(total > 0);
+    }
 }
@@ -0,0 +1,56 @@
+//! 처리 품질 단계 (실시간 / 오프라인 렌더링)
+//! 오버샘플링 배율, 분석 윈도우 길이, 선형 위상 EQ 사용 여부를 한 곳에서 결정
+
+/// 품질 단계 (Eco < Normal < High < Ultra)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Quality {
+    /// CPU 절약: 오버샘플링 없음
+    Eco,
+    #[default]
+    Normal,
+    High,
+    /// 오프라인 전용 권장 (실시간에는 무거움)
+    Ultra,
+}
+
+impl Quality {
+    /// 비선형 단계 오버샘플링 배율
+    pub fn oversampling(self) -> usize {
+        match self {
+            Quality::Eco => 1,
+            Quality::Normal => 2,
+            Quality::High => 4,
+            Quality::Ultra => 8,
+        }
+    }
+
+    /// 험 검출 윈도우 (초, 길수록 주파수 해상도 향상)
+    pub fn analysis_window_seconds(self) -> f32 {
+        match self {
+            Quality::Eco | Quality::Normal => 1.0,
+            Quality::High => 2.0,
+            Quality::Ultra => 4.0,
+        }
+    }
+
+    /// 출력 Lowpass를 선형 위상 FIR로 대체 (지연 증가)
+    pub fn linear_phase_eq(self) -> bool {
+        self >= Quality::High
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quality_is_monotonic() {
+        let levels = [Quality::Eco, Quality::Normal, Quality::High, Quality::Ultra];
+        for pair in levels.windows(2) {
+            assert!(pair[0].oversampling() < pair[1].oversampling());
+            assert!(pair[0].analysis_window_seconds() <= pair[1].analysis_window_seconds());
+        }
+        assert!(!Quality::Normal.linear_phase_eq());
+        assert!(Quality::Ultra.linear_phase_eq());
+    }
+}
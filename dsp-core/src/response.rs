@@ -0,0 +1,129 @@
+//! 체인 주파수 응답 계산 (에디터 표시용)
+//! 소신호 기준 선형 경로만 계산: 포화/리미터는 제외
+
+use crate::biquad_wrapper::BiquadWrapper;
+use crate::math;
+use crate::preset::ChainPreset;
+use crate::processor_trait::db_to_linear;
+use crate::riaa_eq_advanced::RIAACoefficients;
+
+/// 최소 표시 레벨 (dB)
+const FLOOR_DB: f32 = -120.0;
+
+#[derive(Clone, Copy)]
+struct Complex {
+    re: f32,
+    im: f32,
+}
+
+impl Complex {
+    const ONE: Self = Self { re: 1.0, im: 0.0 };
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            re: self.re + other.re,
+            im: self.im + other.im,
+        }
+    }
+
+    fn scale(self, k: f32) -> Self {
+        Self {
+            re: self.re * k,
+            im: self.im * k,
+        }
+    }
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+        }
+    }
+
+    fn div(self, other: Self) -> Self {
+        let norm = other.re * other.re + other.im * other.im;
+        Self {
+            re: (self.re * other.re + self.im * other.im) / norm,
+            im: (self.im * other.re - self.re * other.im) / norm,
+        }
+    }
+
+    fn norm(self) -> f32 {
+        math::sqrt(self.re * self.re + self.im * self.im)
+    }
+}
+
+/// z^-1, z^-2 에서 biquad 전달 함수 값
+fn biquad(c: [f32; 5], z1: Complex, z2: Complex) -> Complex {
+    let num = Complex::ONE
+        .scale(c[0])
+        .add(z1.scale(c[1]))
+        .add(z2.scale(c[2]));
+    let den = Complex::ONE.add(z1.scale(c[3])).add(z2.scale(c[4]));
+    num.div(den)
+}
+
+/// 프리셋 체인의 freq_hz 응답 (dB)
+/// RIAA 크로스페이드 -> Parallel HF (게이트 열림 기준) -> Lowpass -> Dry/Wet
+pub fn chain_response_db(preset: &ChainPreset, sample_rate: f32, freq_hz: f32) -> f32 {
+    let w = 2.0 * core::f32::consts::PI * freq_hz / sample_rate;
+    let z1 = Complex {
+        re: math::cos(w),
+        im: -math::sin(w),
+    };
+    let z2 = z1.mul(z1);
+
+    let riaa = RIAACoefficients::calculate(sample_rate as u32);
+    let riaa = biquad(riaa.to_array().map(|c| c as f32), z1, z2);
+    let riaa_path = Complex::ONE.add(
+        riaa.add(Complex::ONE.scale(-1.0))
+            .scale(preset.riaa_intensity),
+    );
+
+    // ParallelFilterAdvanced: x + 0.5 * intensity * (x - x[n-1])
+    let hf = Complex::ONE
+        .add(z1.scale(-1.0))
+        .scale(0.5 * preset.parallel_mix);
+    let parallel = Complex::ONE.add(hf);
+
+    let lowpass = BiquadWrapper::lowpass(preset.lowpass_normalized(sample_rate), preset.lowpass_q);
+    let lowpass = biquad(lowpass.coefficients(), z1, z2);
+
+    let wet = riaa_path
+        .mul(parallel)
+        .mul(lowpass)
+        .scale(db_to_linear(preset.drive_db));
+    let total = Complex::ONE.add(wet.add(Complex::ONE.scale(-1.0)).scale(preset.dry_wet));
+    (20.0 * math::log10(total.norm())).max(FLOOR_DB)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dry_only_is_flat() {
+        let preset = ChainPreset {
+            dry_wet: 0.0,
+            ..ChainPreset::vinyl()
+        };
+        for freq in [20.0, 1000.0, 15000.0] {
+            assert!(chain_response_db(&preset, 48000.0, freq).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_lowpass_rolls_off() {
+        let preset = ChainPreset {
+            riaa_intensity: 0.0,
+            parallel_mix: 0.0,
+            drive_db: 0.0,
+            lowpass_hz: 2000.0,
+            ..ChainPreset::clean()
+        };
+        let pass = chain_response_db(&preset, 48000.0, 100.0);
+        let stop = chain_response_db(&preset, 48000.0, 16000.0);
+        assert!(pass.abs() < 0.1, "{pass}");
+        assert!(stop < -20.0, "{stop}");
+    }
+}
@@ -1,89 +1,134 @@
-/// RIAA EQ - 고급 샘플레이트별 정확한 구현
+//! RIAA EQ - 고급 샘플레이트별 정확한 구현
 
-/// RIAA EQ 계수 (biquad)
+use crate::math;
+use crate::sample::Sample;
+
+/// RIAA 재생 곡선 시정수 (초): 3180us / 318us / 75us
+const T1: f64 = 3180e-6;
+const T2: f64 = 318e-6;
+const T3: f64 = 75e-6;
+/// 이 주파수에서 0dB로 정규화 (RIAA 기준점)
+const REFERENCE_HZ: f64 = 1000.0;
+/// 고역 보정 영점을 맞추는 주파수 (최대 0.4 * 샘플레이트)
+const MATCH_HZ: f64 = 20000.0;
+
+/// RIAA EQ 계수 (biquad, a0 = 1로 정규화)
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct RIAACoefficients {
-    pub b0: f32,
-    pub b1: f32,
-    pub b2: f32,
-    pub a1: f32,
-    pub a2: f32,
+    pub b0: f64,
+    pub b1: f64,
+    pub b2: f64,
+    pub a1: f64,
+    pub a2: f64,
 }
 
 impl RIAACoefficients {
-    /// 44.1kHz RIAA 계수
-    pub fn riaa_44100() -> Self {
-        // RIAA 곡선: 44.1kHz에 맞춘 biquad 계수
+    /// 샘플레이트별 RIAA 재생 계수 (f64 설계, 1kHz에서 0dB)
+    /// H(s) = (1 + s*T2) / ((1 + s*T1)(1 + s*T3)) 를 쌍선형 변환하고,
+    /// 변환이 만드는 나이퀴스트 영점 (1 + z^-1) 대신 (1 + r*z^-1) 사용:
+    /// r은 MATCH_HZ에서 아날로그 곡선과 크기가 같도록 선택 (44.1kHz 가청대역 오차 0.35dB 이내)
+    pub fn calculate(sample_rate: u32) -> Self {
+        let fs = sample_rate.max(1) as f64;
+        let k = 2.0 * fs;
+        let zero = [1.0 + k * T2, 1.0 - k * T2, 0.0];
+        let (sum, product) = (k * (T1 + T3), k * k * T1 * T3);
+        let den = [1.0 + sum + product, 2.0 - 2.0 * product, 1.0 - sum + product];
+
+        let match_hz = MATCH_HZ.min(0.4 * fs);
+        let (w_ref, w_match) = (omega(REFERENCE_HZ, fs), omega(match_hz, fs));
+        let target = analog_magnitude(match_hz) / analog_magnitude(REFERENCE_HZ);
+        let first_order = magnitude(zero, den, w_match) / magnitude(zero, den, w_ref);
+        let r = hf_zero(target / first_order, w_match, w_ref);
+
+        let num = [zero[0], zero[0] * r + zero[1], zero[1] * r];
+        let gain = 1.0 / magnitude(num, den, w_ref);
         Self {
-            b0: 0.8831,
-            b1: 0.0,
-            b2: -0.8831,
-            a1: -1.9663,
-            a2: 0.9665,
+            b0: num[0] * gain / den[0],
+            b1: num[1] * gain / den[0],
+            b2: num[2] * gain / den[0],
+            a1: den[1] / den[0],
+            a2: den[2] / den[0],
         }
     }
 
-    /// 48kHz RIAA 계수
-    pub fn riaa_48000() -> Self {
-        // RIAA 곡선: 48kHz에 맞춘 biquad 계수
-        Self {
-            b0: 0.8756,
-            b1: 0.0,
-            b2: -0.8756,
-            a1: -1.9680,
-            a2: 0.9742,
-        }
+    /// [b0, b1, b2, a1, a2]
+    pub fn to_array(&self) -> [f64; 5] {
+        [self.b0, self.b1, self.b2, self.a1, self.a2]
     }
 
-    /// 임의 샘플레이트에서 RIAA 계수 계산
-    pub fn calculate(sample_rate: u32) -> Self {
-        match sample_rate {
-            44100 => Self::riaa_44100(),
-            48000 => Self::riaa_48000(),
-            _ => {
-                // 가장 가까운 샘플레이트로 근사
-                if (sample_rate as i32 - 44100).abs() < (sample_rate as i32 - 48000).abs() {
-                    Self::riaa_44100()
-                } else {
-                    Self::riaa_48000()
-                }
-            }
-        }
+    /// 정규화 각주파수 w (rad/sample) 에서의 크기 응답
+    pub fn magnitude(&self, w: f64) -> f64 {
+        magnitude([self.b0, self.b1, self.b2], [1.0, self.a1, self.a2], w)
     }
 }
 
+fn omega(hz: f64, sample_rate: f64) -> f64 {
+    2.0 * core::f64::consts::PI * hz / sample_rate
+}
+
+/// 아날로그 RIAA 곡선의 크기
+fn analog_magnitude(hz: f64) -> f64 {
+    let w = 2.0 * core::f64::consts::PI * hz;
+    let square = |t: f64| 1.0 + (w * t) * (w * t);
+    math::double::sqrt(square(T2) / (square(T1) * square(T3)))
+}
+
+/// 2차 전달 함수의 크기 |num(z) / den(z)|, z = e^jw
+fn magnitude(num: [f64; 3], den: [f64; 3], w: f64) -> f64 {
+    let power = |c: [f64; 3]| {
+        let re = c[0] + c[1] * math::double::cos(w) + c[2] * math::double::cos(2.0 * w);
+        let im = c[1] * math::double::sin(w) + c[2] * math::double::sin(2.0 * w);
+        re * re + im * im
+    };
+    math::double::sqrt(power(num) / power(den))
+}
+
+/// |1 + r*e^-jw_match| / |1 + r*e^-jw_ref| = ratio 를 만족하는 0 <= r <= 1
+/// (1 - g^2) r^2 + 2 (cos w_match - g^2 cos w_ref) r + (1 - g^2) = 0 의 단위원 안쪽 근
+fn hf_zero(ratio: f64, w_match: f64, w_ref: f64) -> f64 {
+    let g2 = ratio * ratio;
+    let a = 1.0 - g2;
+    let b = 2.0 * (math::double::cos(w_match) - g2 * math::double::cos(w_ref));
+    let disc = b * b - 4.0 * a * a;
+    if a.abs() < 1e-12 || disc < 0.0 {
+        return 1.0;
+    }
+    // 두 근의 곱은 1: 절댓값이 큰 근 q / a 의 역수
+    let q = -0.5 * (b + b.signum() * math::double::sqrt(disc));
+    (a / q).clamp(0.0, 1.0)
+}
+
 /// RIAA EQ 필터 (Direct Form II Transposed)
-pub struct RIAAEQAdvanced {
-    coeffs: RIAACoefficients,
-    x1: f32,
-    x2: f32,
-    y1: f32,
-    y2: f32,
+/// 극점이 단위원에 가까우므로 높은 샘플레이트에서는 T = f64 권장
+pub struct RIAAEQAdvanced<T: Sample = f32> {
+    /// [b0, b1, b2, a1, a2]
+    coeffs: [T; 5],
+    y1: T,
+    y2: T,
 }
 
-impl RIAAEQAdvanced {
+impl<T: Sample> RIAAEQAdvanced<T> {
     pub fn new(sample_rate: u32) -> Self {
+        let c = RIAACoefficients::calculate(sample_rate);
         Self {
-            coeffs: RIAACoefficients::calculate(sample_rate),
-            x1: 0.0,
-            x2: 0.0,
-            y1: 0.0,
-            y2: 0.0,
+            coeffs: c.to_array().map(T::from_f64),
+            y1: T::ZERO,
+            y2: T::ZERO,
         }
     }
 
-    /// Direct Form II Transposed 구조
-    pub fn process(&mut self, x: f32) -> f32 {
-        let y = self.coeffs.b0 * x + self.y1;
-        self.y1 = self.coeffs.b1 * x - self.coeffs.a1 * y + self.y2;
-        self.y2 = self.coeffs.b2 * x - self.coeffs.a2 * y;
+    /// Direct Form II Transposed 구조 (상태 flush-to-zero)
+    pub fn process(&mut self, x: T) -> T {
+        let [b0, b1, b2, a1, a2] = self.coeffs;
+        let y = b0 * x + self.y1;
+        self.y1 = (b1 * x - a1 * y + self.y2).flush_denormal();
+        self.y2 = (b2 * x - a2 * y).flush_denormal();
         y
     }
 
     pub fn reset(&mut self) {
-        self.x1 = 0.0;
-        self.x2 = 0.0;
-        self.y1 = 0.0;
-        self.y2 = 0.0;
+        self.y1 = T::ZERO;
+        self.y2 = T::ZERO;
     }
 }
 
@@ -92,22 +137,40 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_riaa_44100_coeffs() {
-        let coeffs = RIAACoefficients::riaa_44100();
-        assert!(coeffs.b0.abs() < 1.0);
-        assert!(coeffs.a1.abs() < 2.0);
+    fn test_riaa_curve_matches_standard() {
+        // RIAA 재생 곡선 (1kHz 기준): 20Hz +19.3dB, 100Hz +13.1dB, 10kHz -13.7dB
+        for sample_rate in [44100, 48000, 88200, 96000, 192000] {
+            let c = RIAACoefficients::calculate(sample_rate);
+            let db = |hz: f64| {
+                let w = 2.0 * core::f64::consts::PI * hz / sample_rate as f64;
+                20.0 * c.magnitude(w).log10()
+            };
+            assert!(db(1000.0).abs() < 1e-9, "{sample_rate}");
+            assert!((db(20.0) - 19.27).abs() < 0.05, "{sample_rate}: {}", db(20.0));
+            assert!((db(100.0) - 13.09).abs() < 0.05, "{sample_rate}: {}", db(100.0));
+            assert!((db(10000.0) + 13.73).abs() < 0.3, "{sample_rate}: {}", db(10000.0));
+            // 가청대역 전체 (나이퀴스트 근처 제외)
+            let mut hz = 20.0;
+            while hz < 20000.0f64.min(0.45 * sample_rate as f64) {
+                let error = db(hz) - 20.0 * (analog_magnitude(hz) / analog_magnitude(1000.0)).log10();
+                assert!(error.abs() < 0.35, "{sample_rate} Hz @ {hz}: {error} dB");
+                hz *= 1.1;
+            }
+            // 극점은 단위원 안 (안정성 삼각형)
+            assert!(c.a2.abs() < 1.0 && c.a1.abs() < 1.0 + c.a2, "{sample_rate}");
+        }
     }
 
     #[test]
     fn test_riaa_eq_process() {
         let mut eq = RIAAEQAdvanced::new(44100);
-        let output = eq.process(0.5);
+        let output: f32 = eq.process(0.5);
         assert!(!output.is_nan());
     }
 
     #[test]
     fn test_riaa_eq_reset() {
-        let mut eq = RIAAEQAdvanced::new(48000);
+        let mut eq = RIAAEQAdvanced::<f32>::new(48000);
         eq.process(1.0);
         eq.reset();
         assert_eq!(eq.y1, 0.0);
@@ -0,0 +1,239 @@
+//! 샘플 타입 추상화 (f32 / f64)
+//! 필터 상태와 계수를 샘플 타입으로 계산: 저역 극점이 단위원에 가까운 필터(RIAA 등)는
+//! 높은 샘플레이트에서 f64 권장. 설계 파라미터(컷오프, Q, 임계값)는 f32로 받음
+
+use crate::math;
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// dsp-core 필터/프로세서의 샘플 타입
+pub trait Sample:
+    Copy
+    + Default
+    + Debug
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + Send
+    + Sync
+    + 'static
+{
+    const ZERO: Self;
+    const ONE: Self;
+    /// 이 크기 미만의 필터 상태는 0으로 (비정규화 수 진입 전, 가청 범위 훨씬 아래)
+    const DENORMAL_THRESHOLD: Self;
+    /// 필터 설계 최저 정규화 주파수: 이보다 낮으면 계수 양자화로 극점이 단위원에 붙음
+    const MIN_DESIGN_FREQ: f32;
+
+    fn from_f32(x: f32) -> Self;
+    fn from_f64(x: f64) -> Self;
+    fn to_f32(self) -> f32;
+    fn to_f64(self) -> f64;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn powf(self, exponent: Self) -> Self;
+    fn log10(self) -> Self;
+
+    /// 이식 가능한 flush-to-zero (FTZ 레지스터와 무관하게 모든 타깃에서 동작)
+    #[inline]
+    fn flush_denormal(self) -> Self {
+        if self.abs() < Self::DENORMAL_THRESHOLD {
+            Self::ZERO
+        } else {
+            self
+        }
+    }
+}
+
+impl Sample for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const DENORMAL_THRESHOLD: Self = 1e-15;
+    const MIN_DESIGN_FREQ: f32 = 1e-4;
+
+    #[inline]
+    fn from_f32(x: f32) -> Self {
+        x
+    }
+
+    #[inline]
+    fn from_f64(x: f64) -> Self {
+        x as f32
+    }
+
+    #[inline]
+    fn to_f32(self) -> f32 {
+        self
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+
+    #[inline]
+    fn signum(self) -> Self {
+        f32::signum(self)
+    }
+
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        f32::max(self, other)
+    }
+
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        f32::min(self, other)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        math::sqrt(self)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        math::exp(self)
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        math::sin(self)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        math::cos(self)
+    }
+
+    #[inline]
+    fn powf(self, exponent: Self) -> Self {
+        math::powf(self, exponent)
+    }
+
+    #[inline]
+    fn log10(self) -> Self {
+        math::log10(self)
+    }
+}
+
+impl Sample for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const DENORMAL_THRESHOLD: Self = 1e-30;
+    const MIN_DESIGN_FREQ: f32 = 1e-5;
+
+    #[inline]
+    fn from_f32(x: f32) -> Self {
+        x as f64
+    }
+
+    #[inline]
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+
+    #[inline]
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+
+    #[inline]
+    fn signum(self) -> Self {
+        f64::signum(self)
+    }
+
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        f64::max(self, other)
+    }
+
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        f64::min(self, other)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        math::double::sqrt(self)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        math::double::exp(self)
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        math::double::sin(self)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        math::double::cos(self)
+    }
+
+    #[inline]
+    fn powf(self, exponent: Self) -> Self {
+        math::double::powf(self, exponent)
+    }
+
+    #[inline]
+    fn log10(self) -> Self {
+        math::double::log10(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hypot<T: Sample>(a: T, b: T) -> T {
+        (a * a + b * b).sqrt()
+    }
+
+    #[test]
+    fn test_f32_and_f64_agree() {
+        assert_eq!(hypot(3.0f32, 4.0), 5.0);
+        assert_eq!(hypot(3.0f64, 4.0), 5.0);
+        let x = 0.3;
+        assert!((Sample::sin(x as f32).to_f64() - Sample::sin(x)).abs() < 1e-7);
+        assert_eq!(f64::from_f32(0.5).to_f32(), 0.5);
+    }
+
+    #[test]
+    fn test_flush_denormal() {
+        assert_eq!(1e-20f32.flush_denormal(), 0.0);
+        assert_eq!((-1e-40f64).flush_denormal(), 0.0);
+        assert_eq!(1e-10f32.flush_denormal(), 1e-10);
+        assert_eq!(1e-20f64.flush_denormal(), 1e-20);
+    }
+}
@@ -0,0 +1,500 @@
+//! 4레인 f32 SIMD (x86_64 SSE, aarch64 NEON, 그 외 타깃은 스칼라 대체)
+//! - 채널 방향: 4채널 lock-step Biquad / RIAA (f32 블록 API, 4채널 이상 f32 호스트용)
+//! - 블록 방향: 웨이브셰이퍼, 미터 피크/제곱합
+//!
+//! 플러그인 체인(PresetChain / TapeChain)은 f64 재귀 필터와 샘플별 비선형 단을 채널마다 돌리므로
+//! 여기의 f32 경로를 쓰지 않음 (정밀도 유지). 스칼라 대비 성능은 benches/simd.rs
+
+use crate::biquad_wrapper::BiquadWrapper;
+use crate::riaa_eq_advanced::RIAACoefficients;
+use crate::sample::Sample;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg(target_arch = "x86_64")]
+mod imp {
+    // SAFETY (모듈 전체): SSE/SSE2는 x86_64 기본 기능이므로 항상 사용 가능
+    use core::arch::x86_64::*;
+
+    pub type V = __m128;
+
+    #[inline(always)]
+    pub fn splat(x: f32) -> V {
+        unsafe { _mm_set1_ps(x) }
+    }
+
+    #[inline(always)]
+    pub fn load(a: [f32; 4]) -> V {
+        unsafe { _mm_loadu_ps(a.as_ptr()) }
+    }
+
+    #[inline(always)]
+    pub fn store(v: V) -> [f32; 4] {
+        let mut out = [0.0; 4];
+        unsafe { _mm_storeu_ps(out.as_mut_ptr(), v) };
+        out
+    }
+
+    #[inline(always)]
+    pub fn add(a: V, b: V) -> V {
+        unsafe { _mm_add_ps(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn sub(a: V, b: V) -> V {
+        unsafe { _mm_sub_ps(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn mul(a: V, b: V) -> V {
+        unsafe { _mm_mul_ps(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn div(a: V, b: V) -> V {
+        unsafe { _mm_div_ps(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn min(a: V, b: V) -> V {
+        unsafe { _mm_min_ps(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn max(a: V, b: V) -> V {
+        unsafe { _mm_max_ps(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn abs(a: V) -> V {
+        unsafe { _mm_andnot_ps(_mm_set1_ps(-0.0), a) }
+    }
+
+    #[inline(always)]
+    pub fn select_lt(x: V, y: V, a: V, b: V) -> V {
+        unsafe {
+            let mask = _mm_cmplt_ps(x, y);
+            _mm_or_ps(_mm_and_ps(mask, a), _mm_andnot_ps(mask, b))
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod imp {
+    // SAFETY (모듈 전체): NEON은 aarch64 기본 기능이므로 항상 사용 가능
+    use core::arch::aarch64::*;
+
+    pub type V = float32x4_t;
+
+    #[inline(always)]
+    pub fn splat(x: f32) -> V {
+        unsafe { vdupq_n_f32(x) }
+    }
+
+    #[inline(always)]
+    pub fn load(a: [f32; 4]) -> V {
+        unsafe { vld1q_f32(a.as_ptr()) }
+    }
+
+    #[inline(always)]
+    pub fn store(v: V) -> [f32; 4] {
+        let mut out = [0.0; 4];
+        unsafe { vst1q_f32(out.as_mut_ptr(), v) };
+        out
+    }
+
+    #[inline(always)]
+    pub fn add(a: V, b: V) -> V {
+        unsafe { vaddq_f32(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn sub(a: V, b: V) -> V {
+        unsafe { vsubq_f32(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn mul(a: V, b: V) -> V {
+        unsafe { vmulq_f32(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn div(a: V, b: V) -> V {
+        unsafe { vdivq_f32(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn min(a: V, b: V) -> V {
+        unsafe { vminq_f32(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn max(a: V, b: V) -> V {
+        unsafe { vmaxq_f32(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn abs(a: V) -> V {
+        unsafe { vabsq_f32(a) }
+    }
+
+    #[inline(always)]
+    pub fn select_lt(x: V, y: V, a: V, b: V) -> V {
+        unsafe { vbslq_f32(vcltq_f32(x, y), a, b) }
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+mod imp {
+    pub type V = [f32; 4];
+
+    #[inline(always)]
+    fn zip(a: V, b: V, f: impl Fn(f32, f32) -> f32) -> V {
+        core::array::from_fn(|i| f(a[i], b[i]))
+    }
+
+    #[inline(always)]
+    pub fn splat(x: f32) -> V {
+        [x; 4]
+    }
+
+    #[inline(always)]
+    pub fn load(a: [f32; 4]) -> V {
+        a
+    }
+
+    #[inline(always)]
+    pub fn store(v: V) -> [f32; 4] {
+        v
+    }
+
+    #[inline(always)]
+    pub fn add(a: V, b: V) -> V {
+        zip(a, b, |a, b| a + b)
+    }
+
+    #[inline(always)]
+    pub fn sub(a: V, b: V) -> V {
+        zip(a, b, |a, b| a - b)
+    }
+
+    #[inline(always)]
+    pub fn mul(a: V, b: V) -> V {
+        zip(a, b, |a, b| a * b)
+    }
+
+    #[inline(always)]
+    pub fn div(a: V, b: V) -> V {
+        zip(a, b, |a, b| a / b)
+    }
+
+    #[inline(always)]
+    pub fn min(a: V, b: V) -> V {
+        zip(a, b, f32::min)
+    }
+
+    #[inline(always)]
+    pub fn max(a: V, b: V) -> V {
+        zip(a, b, f32::max)
+    }
+
+    #[inline(always)]
+    pub fn abs(a: V) -> V {
+        a.map(f32::abs)
+    }
+
+    #[inline(always)]
+    pub fn select_lt(x: V, y: V, a: V, b: V) -> V {
+        core::array::from_fn(|i| if x[i] < y[i] { a[i] } else { b[i] })
+    }
+}
+
+/// f32 4개 레인
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct F32x4(imp::V);
+
+impl F32x4 {
+    pub const LANES: usize = 4;
+
+    #[inline(always)]
+    pub fn splat(x: f32) -> Self {
+        Self(imp::splat(x))
+    }
+
+    #[inline(always)]
+    pub fn from_array(a: [f32; 4]) -> Self {
+        Self(imp::load(a))
+    }
+
+    /// 앞 4개 샘플 (slice.len() >= 4)
+    #[inline(always)]
+    pub fn from_slice(slice: &[f32]) -> Self {
+        Self::from_array([slice[0], slice[1], slice[2], slice[3]])
+    }
+
+    #[inline(always)]
+    pub fn to_array(self) -> [f32; 4] {
+        imp::store(self.0)
+    }
+
+    #[inline(always)]
+    pub fn write_to_slice(self, slice: &mut [f32]) {
+        slice[..4].copy_from_slice(&self.to_array());
+    }
+
+    #[inline(always)]
+    pub fn min(self, other: Self) -> Self {
+        Self(imp::min(self.0, other.0))
+    }
+
+    #[inline(always)]
+    pub fn max(self, other: Self) -> Self {
+        Self(imp::max(self.0, other.0))
+    }
+
+    #[inline(always)]
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        self.max(lo).min(hi)
+    }
+
+    #[inline(always)]
+    pub fn abs(self) -> Self {
+        Self(imp::abs(self.0))
+    }
+
+    /// self < other인 레인은 if_less, 나머지는 otherwise
+    #[inline(always)]
+    pub fn select_lt(self, other: Self, if_less: Self, otherwise: Self) -> Self {
+        Self(imp::select_lt(self.0, other.0, if_less.0, otherwise.0))
+    }
+
+    /// |x| < f32 DENORMAL_THRESHOLD 인 레인은 0 (Sample::flush_denormal 과 같은 기준)
+    #[inline(always)]
+    pub fn flush_denormals(self) -> Self {
+        let zero = Self::splat(0.0);
+        self.abs()
+            .select_lt(Self::splat(<f32 as Sample>::DENORMAL_THRESHOLD), zero, self)
+    }
+
+    pub fn horizontal_max(self) -> f32 {
+        self.to_array().into_iter().fold(f32::MIN, f32::max)
+    }
+
+    pub fn horizontal_sum(self) -> f32 {
+        let [a, b, c, d] = self.to_array();
+        (a + b) + (c + d)
+    }
+}
+
+macro_rules! binary_op {
+    ($trait:ident, $method:ident) => {
+        impl $trait for F32x4 {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, other: Self) -> Self {
+                Self(imp::$method(self.0, other.0))
+            }
+        }
+    };
+}
+
+binary_op!(Add, add);
+binary_op!(Sub, sub);
+binary_op!(Mul, mul);
+binary_op!(Div, div);
+
+impl Neg for F32x4 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self::splat(0.0) - self
+    }
+}
+
+/// 4채널 lock-step Biquad (채널별 계수, Direct Form II Transposed)
+#[derive(Clone, Debug)]
+pub struct BiquadX4 {
+    b0: F32x4,
+    b1: F32x4,
+    b2: F32x4,
+    a1: F32x4,
+    a2: F32x4,
+    y1: F32x4,
+    y2: F32x4,
+}
+
+impl BiquadX4 {
+    /// 채널별 계수 [b0, b1, b2, a1, a2]
+    pub fn from_coefficients(coeffs: [[f32; 5]; 4]) -> Self {
+        let lane = |k: usize| F32x4::from_array(coeffs.map(|c| c[k]));
+        Self {
+            b0: lane(0),
+            b1: lane(1),
+            b2: lane(2),
+            a1: lane(3),
+            a2: lane(4),
+            y1: F32x4::splat(0.0),
+            y2: F32x4::splat(0.0),
+        }
+    }
+
+    /// 채널마다 다른 필터
+    pub fn from_biquads(filters: [&BiquadWrapper; 4]) -> Self {
+        Self::from_coefficients(filters.map(BiquadWrapper::coefficients))
+    }
+
+    /// 모든 채널에 같은 필터
+    pub fn splat(filter: &BiquadWrapper) -> Self {
+        Self::from_coefficients([filter.coefficients(); 4])
+    }
+
+    /// RIAAEQAdvanced와 같은 곡선 (4채널)
+    pub fn riaa(sample_rate: u32) -> Self {
+        let c = RIAACoefficients::calculate(sample_rate);
+        Self::from_coefficients([c.to_array().map(|c| c as f32); 4])
+    }
+
+    /// 레인 = 채널, 한 프레임 처리
+    #[inline]
+    pub fn process(&mut self, x: F32x4) -> F32x4 {
+        let y = self.b0 * x + self.y1;
+        self.y1 = (self.b1 * x - self.a1 * y + self.y2).flush_denormals();
+        self.y2 = (self.b2 * x - self.a2 * y).flush_denormals();
+        y
+    }
+
+    /// 최대 4채널 블록을 제자리 처리 (채널 길이는 같아야 함, 빈 레인은 0 입력)
+    pub fn process_channels(&mut self, channels: &mut [&mut [f32]]) {
+        let used = channels.len().min(F32x4::LANES);
+        let channels = &mut channels[..used];
+        let len = channels
+            .iter()
+            .map(|channel| channel.len())
+            .min()
+            .unwrap_or(0);
+        for n in 0..len {
+            let mut frame = [0.0; 4];
+            for (lane, channel) in frame.iter_mut().zip(channels.iter()) {
+                *lane = channel[n];
+            }
+            let y = self.process(F32x4::from_array(frame)).to_array();
+            for (channel, lane) in channels.iter_mut().zip(y) {
+                channel[n] = lane;
+            }
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.y1 = F32x4::splat(0.0);
+        self.y2 = F32x4::splat(0.0);
+    }
+}
+
+/// crate::soft_clip의 블록 버전 (|x| >= 1 구간은 클램프 후 같은 다항식 = ±2/3)
+pub fn soft_clip_block(samples: &mut [f32]) {
+    let one = F32x4::splat(1.0);
+    let three = F32x4::splat(3.0);
+    let mut chunks = samples.chunks_exact_mut(F32x4::LANES);
+    for chunk in &mut chunks {
+        let x = F32x4::from_slice(chunk).clamp(-one, one);
+        (x - (x * x * x) / three).write_to_slice(chunk);
+    }
+    for sample in chunks.into_remainder() {
+        *sample = crate::soft_clip(*sample);
+    }
+}
+
+/// 블록 피크(절대값 최대)와 제곱합
+pub fn peak_and_sum_squares(samples: &[f32]) -> (f32, f32) {
+    let mut peak = F32x4::splat(0.0);
+    let mut sum_sq = F32x4::splat(0.0);
+    let mut chunks = samples.chunks_exact(F32x4::LANES);
+    for chunk in &mut chunks {
+        let x = F32x4::from_slice(chunk);
+        peak = peak.max(x.abs());
+        sum_sq = sum_sq + x * x;
+    }
+    let mut peak = peak.horizontal_max();
+    let mut sum_sq = sum_sq.horizontal_sum();
+    for &sample in chunks.remainder() {
+        peak = peak.max(sample.abs());
+        sum_sq += sample * sample;
+    }
+    (peak, sum_sq)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{soft_clip, MagneticEQ};
+
+    fn signal(len: usize, seed: f32) -> Vec<f32> {
+        (0..len)
+            .map(|n| 1.5 * (0.013 * seed * n as f32).sin() + 0.2 * (0.31 * n as f32).cos())
+            .collect()
+    }
+
+    #[test]
+    fn test_biquad_x4_matches_scalar() {
+        let filters = [
+            BiquadWrapper::lowpass(0.05, 0.707),
+            BiquadWrapper::highpass(0.01, 0.5),
+            BiquadWrapper::peaking(0.1, 2.0, 6.0),
+            BiquadWrapper::notch(0.02, 10.0),
+        ];
+        let mut vector =
+            BiquadX4::from_biquads([&filters[0], &filters[1], &filters[2], &filters[3]]);
+        let mut scalar = filters;
+        let mut channels: Vec<Vec<f32>> = (1..=4).map(|seed| signal(1000, seed as f32)).collect();
+        let expected: Vec<Vec<f32>> = channels
+            .iter()
+            .zip(scalar.iter_mut())
+            .map(|(channel, filter)| channel.iter().map(|&x| filter.process(x)).collect())
+            .collect();
+        let mut slices: Vec<&mut [f32]> = channels.iter_mut().map(|c| c.as_mut_slice()).collect();
+        vector.process_channels(&mut slices);
+        for (output, expected) in channels.iter().zip(&expected) {
+            for (y, e) in output.iter().zip(expected) {
+                assert!((y - e).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_riaa_x4_two_channels() {
+        let mut vector = BiquadX4::riaa(48000);
+        let mut reference = crate::RIAAEQAdvanced::<f32>::new(48000);
+        let mut left = signal(500, 1.0);
+        let mut right = left.clone();
+        let expected: Vec<f32> = left.iter().map(|&x| reference.process(x)).collect();
+        vector.process_channels(&mut [&mut left, &mut right]);
+        for ((l, r), e) in left.iter().zip(&right).zip(&expected) {
+            assert!((l - e).abs() < 1e-5 && l == r);
+        }
+    }
+
+    #[test]
+    fn test_block_shapers_and_meter_match_scalar() {
+        let input = signal(1027, 3.0);
+
+        let mut clipped = input.clone();
+        soft_clip_block(&mut clipped);
+        for (y, &x) in clipped.iter().zip(&input) {
+            assert!((y - soft_clip(x)).abs() < 1e-6);
+        }
+
+        let eq = MagneticEQ::<f32>::new(0.7, 0.4);
+        let mut shaped = input.clone();
+        eq.process_block(&mut shaped);
+        for (y, &x) in shaped.iter().zip(&input) {
+            assert!((y - eq.process(x)).abs() < 1e-6);
+        }
+
+        let (peak, sum_sq) = peak_and_sum_squares(&input);
+        let scalar_sum: f32 = input.iter().map(|x| x * x).sum();
+        assert_eq!(peak, input.iter().fold(0.0f32, |p, x| p.max(x.abs())));
+        assert!((sum_sq - scalar_sum).abs() < 1e-3 * scalar_sum);
+    }
+}
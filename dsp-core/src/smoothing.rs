@@ -0,0 +1,223 @@
+//! 파라미터 스무딩 (지퍼 노이즈 방지)
+//! 호스트와 무관하게 프로세서 내부에서 샘플 단위 램프 생성
+
+use crate::math;
+
+/// 램프 곡선 (램프 시간 ms)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RampStyle {
+    /// 즉시 변경
+    None,
+    /// 일정한 증분
+    Linear(f32),
+    /// 1차 지수 접근 (램프 끝에서 목표값으로 스냅)
+    Exponential(f32),
+    /// 일정한 비율 (게인/주파수용, 부호가 바뀌면 Linear로 동작)
+    Logarithmic(f32),
+}
+
+impl RampStyle {
+    fn steps(self, sample_rate: f32) -> u32 {
+        match self {
+            RampStyle::None => 0,
+            RampStyle::Linear(ms) | RampStyle::Exponential(ms) | RampStyle::Logarithmic(ms) => {
+                math::round(ms.max(0.0) * 0.001 * sample_rate) as u32
+            }
+        }
+    }
+}
+
+/// 스무딩되는 파라미터 값
+#[derive(Clone, Debug)]
+pub struct SmoothedParam {
+    style: RampStyle,
+    sample_rate: f32,
+    current: f32,
+    target: f32,
+    /// Linear: 증분, Exponential: 계수, Logarithmic: 배율
+    step: f32,
+    multiplicative: bool,
+    steps_left: u32,
+}
+
+impl SmoothedParam {
+    pub fn new(style: RampStyle, value: f32) -> Self {
+        Self {
+            style,
+            sample_rate: 48000.0,
+            current: value,
+            target: value,
+            step: 0.0,
+            multiplicative: false,
+            steps_left: 0,
+        }
+    }
+
+    /// 진행 중인 램프는 목표값으로 스냅
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+        self.set_immediate(self.target);
+    }
+
+    pub fn set_target(&mut self, target: f32) {
+        if target == self.target {
+            return;
+        }
+        self.target = target;
+        let steps = self.style.steps(self.sample_rate);
+        if steps == 0 {
+            self.set_immediate(target);
+            return;
+        }
+
+        self.steps_left = steps;
+        self.multiplicative = false;
+        let n = steps as f32;
+        match self.style {
+            RampStyle::Exponential(_) => {
+                // n 스텝 후 남은 오차 -80dB
+                self.step = 1.0 - math::powf(1e-4, 1.0 / n);
+            }
+            RampStyle::Logarithmic(_) if self.current * target > 0.0 => {
+                self.multiplicative = true;
+                self.step = math::powf(target / self.current, 1.0 / n);
+            }
+            _ => self.step = (target - self.current) / n,
+        }
+    }
+
+    /// 램프 없이 바로 변경
+    pub fn set_immediate(&mut self, value: f32) {
+        self.current = value;
+        self.target = value;
+        self.steps_left = 0;
+    }
+
+    /// 한 샘플 진행 후 현재 값
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> f32 {
+        if self.steps_left > 0 {
+            self.steps_left -= 1;
+            if self.steps_left == 0 {
+                self.current = self.target;
+            } else if self.multiplicative {
+                self.current *= self.step;
+            } else if let RampStyle::Exponential(_) = self.style {
+                self.current += (self.target - self.current) * self.step;
+            } else {
+                self.current += self.step;
+            }
+        }
+        self.current
+    }
+
+    /// 블록 길이만큼 램프 값 채우기
+    pub fn next_block(&mut self, values: &mut [f32]) {
+        for value in values {
+            *value = self.next();
+        }
+    }
+
+    pub fn value(&self) -> f32 {
+        self.current
+    }
+
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+
+    pub fn is_smoothing(&self) -> bool {
+        self.steps_left > 0
+    }
+}
+
+/// 블록 처리용 파라미터: 고정값 또는 샘플별 램프
+#[derive(Clone, Copy, Debug)]
+pub enum ParamRamp<'a> {
+    /// 블록 전체 목표값 (프로세서 내부 스무딩 적용)
+    Constant(f32),
+    /// 호스트가 이미 스무딩한 샘플별 값 (그대로 사용)
+    PerSample(&'a [f32]),
+}
+
+impl ParamRamp<'_> {
+    /// index 번째 샘플 값 (램프가 짧으면 마지막 값 유지)
+    #[inline]
+    pub fn at(&self, index: usize) -> f32 {
+        match self {
+            ParamRamp::Constant(value) => *value,
+            ParamRamp::PerSample(values) => {
+                values.get(index).or(values.last()).copied().unwrap_or(0.0)
+            }
+        }
+    }
+
+    /// SmoothedParam에 적용 (Constant: 목표값, PerSample: index 번째 값으로 즉시 설정)
+    #[inline]
+    pub fn apply(&self, param: &mut SmoothedParam, index: usize) {
+        match self {
+            ParamRamp::Constant(value) => param.set_target(*value),
+            ParamRamp::PerSample(_) => param.set_immediate(self.at(index)),
+        }
+    }
+}
+
+impl From<f32> for ParamRamp<'_> {
+    fn from(value: f32) -> Self {
+        ParamRamp::Constant(value)
+    }
+}
+
+impl<'a> From<&'a [f32]> for ParamRamp<'a> {
+    fn from(values: &'a [f32]) -> Self {
+        ParamRamp::PerSample(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_ramp_reaches_target() {
+        let mut param = SmoothedParam::new(RampStyle::Linear(1.0), 0.0);
+        param.set_sample_rate(1000.0);
+        param.set_target(1.0);
+        // 1ms @ 1kHz = 1 스텝
+        assert_eq!(param.next(), 1.0);
+
+        let mut param = SmoothedParam::new(RampStyle::Linear(10.0), 0.0);
+        param.set_sample_rate(1000.0);
+        param.set_target(1.0);
+        let mut values = [0.0; 12];
+        param.next_block(&mut values);
+        assert!((values[4] - 0.5).abs() < 1e-6);
+        assert_eq!(values[9], 1.0);
+        assert!(!param.is_smoothing());
+    }
+
+    #[test]
+    fn test_logarithmic_and_exponential_are_monotonic() {
+        for style in [RampStyle::Logarithmic(5.0), RampStyle::Exponential(5.0)] {
+            let mut param = SmoothedParam::new(style, 0.1);
+            param.set_target(2.0);
+            let mut previous = param.value();
+            while param.is_smoothing() {
+                let value = param.next();
+                assert!(value >= previous && value <= 2.0);
+                previous = value;
+            }
+            assert_eq!(param.value(), 2.0);
+        }
+    }
+
+    #[test]
+    fn test_param_ramp() {
+        let values = [1.0, 2.0, 3.0];
+        let ramp = ParamRamp::from(&values[..]);
+        assert_eq!(ramp.at(1), 2.0);
+        assert_eq!(ramp.at(10), 3.0);
+        assert_eq!(ParamRamp::from(0.5).at(7), 0.5);
+    }
+}
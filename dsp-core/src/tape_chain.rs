@@ -0,0 +1,403 @@
+//! 테이프 체인 (Warm Tape 플러그인용)
+//! 입력 드라이브 -> 바이어스 + MagneticEQ 포화 -> 헤드 범프 / 톤 EQ -> 출력 트림
+//! -> (프리셋 출력 단계: Lowpass -> 리미터) -> Dry/Wet
+
+use crate::biquad_wrapper::BiquadWrapper;
+use crate::delay_line::DelayLine;
+use crate::magnetic_eq::MagneticEQ;
+use crate::oversampler::Oversampler;
+use crate::preset::ChainPreset;
+use crate::preset_chain::OutputStage;
+use crate::processor_trait::{db_to_linear, mix_dry_wet, ProcessParams, Processor};
+use crate::smoothing::{ParamRamp, RampStyle, SmoothedParam};
+
+/// 헤드 범프 중심 주파수 (Hz)
+const HEAD_BUMP_HZ: f32 = 90.0;
+const HEAD_BUMP_Q: f32 = 1.2;
+/// warmth = 1.0 일 때 헤드 범프 (dB)
+const HEAD_BUMP_MAX_DB: f32 = 4.0;
+/// warmth = 1.0 일 때 바이어스 (짝수 고조파 생성)
+const BIAS_MAX: f32 = 0.15;
+/// 톤 쉘프 주파수 (Hz) 및 최대 부스트/컷 (dB)
+const TONE_HZ: f32 = 4000.0;
+const TONE_RANGE_DB: f32 = 6.0;
+/// MagneticEQ 포화량 (레벨은 입력 드라이브로 조절)
+const TAPE_SATURATION: f32 = 0.5;
+/// 내부 파라미터 램프 시간
+const RAMP_MS: f32 = 20.0;
+/// 램프 중 필터 계수 재계산 간격 (샘플)
+const COEFF_INTERVAL: u32 = 16;
+/// Dry 정렬 지연 최대값 (8x 오버샘플링 지연 이상)
+const DRY_DELAY_MAX: usize = 64;
+/// 드라이브 / 출력 게인 범위 (dB, set_* 와 램프 모두 적용, 게인 오버플로 방지)
+const MAX_GAIN_DB: f32 = 48.0;
+
+/// TapeChain::process_block 파라미터 (샘플별 램프 또는 고정 목표값)
+#[derive(Clone, Copy, Debug)]
+pub struct TapeRamps<'a> {
+    pub drive_db: ParamRamp<'a>,
+    pub warmth: ParamRamp<'a>,
+    pub tone: ParamRamp<'a>,
+    pub output_db: ParamRamp<'a>,
+    pub dry_wet: ParamRamp<'a>,
+}
+
+/// 채널 하나의 테이프 체인 상태
+/// set_* 는 목표값만 바꾸고 샘플 단위로 램프 적용
+pub struct TapeChain {
+    sample_rate: f32,
+    magnetic: MagneticEQ,
+    /// 포화 단계 오버샘플링
+    oversampler: Oversampler,
+    /// 오버샘플링 지연만큼 Dry 정렬
+    dry_delay: DelayLine<DRY_DELAY_MAX>,
+    /// 저역 피킹(90Hz)은 높은 샘플레이트에서 극점이 단위원에 가까우므로 f64
+    head_bump: BiquadWrapper<f64>,
+    tone_shelf: BiquadWrapper,
+    /// 프리셋 출력 단계 (None이면 출력 트림 뒤 바로 Dry/Wet)
+    output: Option<OutputStage>,
+    drive_db: SmoothedParam,
+    warmth: SmoothedParam,
+    tone: SmoothedParam,
+    output_db: SmoothedParam,
+    dry_wet: SmoothedParam,
+    drive_gain: f32,
+    output_gain: f32,
+    bias: f32,
+    bias_offset: f32,
+    /// 현재 필터 계수에 반영된 값
+    applied: [f32; 4],
+    /// 직전 샘플의 (warmth, tone): 샘플별 램프는 SmoothedParam을 즉시 설정하므로 값 변화로 램프 판단
+    filter_inputs: (f32, f32),
+    coeff_counter: u32,
+}
+
+impl TapeChain {
+    pub fn new(sample_rate: f32) -> Self {
+        let param = |style, value| {
+            let mut param = SmoothedParam::new(style, value);
+            param.set_sample_rate(sample_rate);
+            param
+        };
+        let mut chain = Self {
+            sample_rate,
+            magnetic: MagneticEQ::new(TAPE_SATURATION, 0.5),
+            oversampler: Oversampler::new(1),
+            dry_delay: DelayLine::new(0),
+            head_bump: BiquadWrapper::new(1.0, 0.0, 0.0, 0.0, 0.0),
+            tone_shelf: BiquadWrapper::new(1.0, 0.0, 0.0, 0.0, 0.0),
+            output: None,
+            drive_db: param(RampStyle::Linear(RAMP_MS), 0.0),
+            warmth: param(RampStyle::Linear(RAMP_MS), 0.5),
+            tone: param(RampStyle::Linear(RAMP_MS), 0.0),
+            output_db: param(RampStyle::Linear(RAMP_MS), 0.0),
+            dry_wet: param(RampStyle::Linear(RAMP_MS), 1.0),
+            drive_gain: 1.0,
+            output_gain: 1.0,
+            bias: 0.0,
+            bias_offset: 0.0,
+            applied: [f32::NAN; 4],
+            filter_inputs: (0.5, 0.0),
+            coeff_counter: 0,
+        };
+        chain.update_gains(0.0, 0.0);
+        chain.update_filters(0.5, 0.0);
+        chain
+    }
+
+    pub fn set_drive_db(&mut self, drive_db: f32) {
+        self.drive_db.set_target(drive_db);
+    }
+
+    /// warmth: 0.0-1.0 (포화 하드니스, 바이어스, 헤드 범프)
+    pub fn set_warmth(&mut self, warmth: f32) {
+        self.warmth.set_target(warmth.clamp(0.0, 1.0));
+    }
+
+    /// tone: -1.0(어둡게)-1.0(밝게)
+    pub fn set_tone(&mut self, tone: f32) {
+        self.tone.set_target(tone.clamp(-1.0, 1.0));
+    }
+
+    pub fn set_output_db(&mut self, output_db: f32) {
+        self.output_db.set_target(output_db);
+    }
+
+    pub fn set_dry_wet(&mut self, dry_wet: f32) {
+        self.dry_wet.set_target(dry_wet.clamp(0.0, 1.0));
+    }
+
+    /// 포화 단계 오버샘플링 배율 (1, 2, 4, 8), 지연이 바뀜
+    pub fn set_oversampling(&mut self, factor: usize) {
+        if factor != self.oversampler.factor() {
+            self.oversampler.set_factor(factor);
+            self.dry_delay.set_delay(self.latency_samples());
+            self.dry_delay.reset();
+        }
+    }
+
+    /// 프리셋의 출력 단계(Lowpass -> 리미터) 설정, None이면 출력 단계 제거
+    /// 최소 위상 Lowpass만 사용하므로 지연은 바뀌지 않음
+    pub fn set_output_stage(&mut self, preset: Option<&ChainPreset>) {
+        match (preset, &mut self.output) {
+            (Some(preset), Some(stage)) => stage.configure(preset, self.sample_rate),
+            (Some(preset), None) => self.output = Some(OutputStage::new(preset, self.sample_rate)),
+            (None, _) => self.output = None,
+        }
+    }
+
+    /// 출력 단계 리미터 게인 리덕션 (dB, 출력 단계가 없으면 0)
+    pub fn gain_reduction_db(&self) -> f32 {
+        self.output
+            .as_ref()
+            .map_or(0.0, OutputStage::gain_reduction_db)
+    }
+
+    pub fn process(&mut self, input: f32) -> f32 {
+        let drive_db = self.drive_db.next();
+        let output_db = self.output_db.next();
+        self.update_gains(drive_db, output_db);
+
+        let warmth = self.warmth.next();
+        let tone = self.tone.next();
+        // 값이 움직이는 동안(내부 스무딩 / 호스트 샘플별 램프)은 COEFF_INTERVAL 마다, 멈추면 바로 재계산
+        let moving = (warmth, tone) != self.filter_inputs;
+        self.filter_inputs = (warmth, tone);
+        self.coeff_counter += 1;
+        if self.coeff_counter >= COEFF_INTERVAL || !moving {
+            self.coeff_counter = 0;
+            self.update_filters(warmth, tone);
+        }
+
+        let driven = input * self.drive_gain;
+        let (bias, bias_offset) = (self.bias, self.bias_offset);
+        let magnetic = &mut self.magnetic;
+        let saturated = self
+            .oversampler
+            .process(driven, |x| magnetic.process(x + bias) - bias_offset);
+        let y = self.head_bump.process(saturated as f64) as f32;
+        let mut y = self.tone_shelf.process(y) * self.output_gain;
+        if let Some(output) = &mut self.output {
+            y = output.process(y);
+        }
+        let dry = self.dry_delay.process(input);
+        mix_dry_wet(dry, y, self.dry_wet.next())
+    }
+
+    /// 블록 처리: PerSample 램프는 그대로, Constant는 내부 스무딩 후 적용
+    pub fn process_block(&mut self, samples: &mut [f32], ramps: &TapeRamps) {
+        for (n, sample) in samples.iter_mut().enumerate() {
+            ramps.drive_db.apply(&mut self.drive_db, n);
+            ramps.warmth.apply(&mut self.warmth, n);
+            ramps.tone.apply(&mut self.tone, n);
+            ramps.output_db.apply(&mut self.output_db, n);
+            ramps.dry_wet.apply(&mut self.dry_wet, n);
+            *sample = self.process(*sample);
+        }
+    }
+
+    /// 필터 상태 초기화, 진행 중인 램프는 목표값으로 스냅
+    pub fn reset(&mut self) {
+        self.oversampler.reset();
+        self.dry_delay.reset();
+        self.head_bump.reset();
+        self.tone_shelf.reset();
+        if let Some(output) = &mut self.output {
+            output.reset();
+        }
+        for param in [
+            &mut self.drive_db,
+            &mut self.warmth,
+            &mut self.tone,
+            &mut self.output_db,
+            &mut self.dry_wet,
+        ] {
+            param.set_immediate(param.target());
+        }
+        let (warmth, tone) = (self.warmth.target(), self.tone.target());
+        self.filter_inputs = (warmth, tone);
+        self.coeff_counter = 0;
+        self.update_filters(warmth, tone);
+    }
+
+    fn update_gains(&mut self, drive_db: f32, output_db: f32) {
+        if drive_db != self.applied[2] {
+            self.applied[2] = drive_db;
+            self.drive_gain = db_to_linear(drive_db.clamp(-MAX_GAIN_DB, MAX_GAIN_DB));
+        }
+        if output_db != self.applied[3] {
+            self.applied[3] = output_db;
+            self.output_gain = db_to_linear(output_db.clamp(-MAX_GAIN_DB, MAX_GAIN_DB));
+        }
+    }
+
+    fn update_filters(&mut self, warmth: f32, tone: f32) {
+        if warmth != self.applied[0] {
+            self.applied[0] = warmth;
+            self.set_warmth_coefficients(warmth);
+        }
+        if tone != self.applied[1] {
+            self.applied[1] = tone;
+            let cutoff = (TONE_HZ / self.sample_rate).min(0.45);
+            self.tone_shelf
+                .set_coefficients(&BiquadWrapper::high_shelf(cutoff, TONE_RANGE_DB * tone));
+        }
+    }
+
+    fn set_warmth_coefficients(&mut self, warmth: f32) {
+        self.magnetic.set_hardness(warmth);
+        self.bias = BIAS_MAX * warmth;
+        // 바이어스로 생기는 정적 DC 제거
+        self.bias_offset = self.magnetic.process(self.bias);
+        let center = HEAD_BUMP_HZ / self.sample_rate;
+        let gain_db = HEAD_BUMP_MAX_DB * warmth;
+        self.head_bump
+            .set_coefficients(&BiquadWrapper::peaking(center, HEAD_BUMP_Q, gain_db));
+    }
+}
+
+/// 오버샘플링 지연만 발생 (출력 단계는 최소 위상, Dry 경로는 체인 내부에서 정렬됨)
+impl Processor for TapeChain {
+    fn process(&mut self, input: &[f32], output: &mut [f32], _params: &ProcessParams) {
+        for (x, y) in input.iter().zip(output.iter_mut()) {
+            *y = TapeChain::process(self, *x);
+        }
+    }
+
+    fn process_sample(&mut self, input: f32, _params: &ProcessParams) -> f32 {
+        TapeChain::process(self, input)
+    }
+
+    fn reset(&mut self) {
+        TapeChain::reset(self);
+    }
+
+    fn name(&self) -> &str {
+        "Tape Chain"
+    }
+
+    fn latency_samples(&self) -> usize {
+        self.oversampler.latency_samples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dry_only_is_transparent() {
+        let mut chain = TapeChain::new(48000.0);
+        chain.set_drive_db(12.0);
+        chain.set_dry_wet(0.0);
+        chain.reset();
+        for n in 0..1000 {
+            let x = (n as f32 * 0.01).sin() * 0.5;
+            assert!((chain.process(x) - x).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_oversampled_dry_is_aligned() {
+        let mut chain = TapeChain::new(48000.0);
+        chain.set_oversampling(4);
+        chain.set_dry_wet(0.0);
+        chain.reset();
+        let latency = chain.latency_samples();
+        assert!(latency > 0);
+        let output: Vec<f32> = (0..200).map(|n| chain.process(n as f32)).collect();
+        assert_eq!(output[latency + 10], 10.0);
+    }
+
+    #[test]
+    fn test_silence_stays_silent_with_bias() {
+        let mut chain = TapeChain::new(48000.0);
+        chain.set_warmth(1.0);
+        chain.reset();
+        for _ in 0..4800 {
+            assert!(chain.process(0.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_warmth_adds_even_harmonics() {
+        // 바이어스에 의한 비대칭: 양/음 반주기 피크가 달라짐
+        let peaks = |warmth: f32| {
+            let mut chain = TapeChain::new(48000.0);
+            chain.set_drive_db(12.0);
+            chain.set_warmth(warmth);
+            chain.reset();
+            let (mut max, mut min) = (0.0f32, 0.0f32);
+            for n in 0..48000 {
+                let x = 0.5 * (2.0 * core::f32::consts::PI * 1000.0 * n as f32 / 48000.0).sin();
+                let y = chain.process(x);
+                if n > 24000 {
+                    max = max.max(y);
+                    min = min.min(y);
+                }
+            }
+            (max + min).abs()
+        };
+        assert!(peaks(1.0) > peaks(0.0) + 0.01);
+    }
+
+    #[test]
+    fn test_output_stage_limits() {
+        let mut chain = TapeChain::new(48000.0);
+        chain.set_drive_db(18.0);
+        let preset = ChainPreset {
+            limiter_ceiling: 0.5,
+            ..ChainPreset::warm()
+        };
+        chain.set_output_stage(Some(&preset));
+        chain.reset();
+        for n in 0..9600 {
+            let x = 0.8 * (2.0 * core::f32::consts::PI * 200.0 * n as f32 / 48000.0).sin();
+            assert!(chain.process(x).abs() <= 0.5 + 1e-6);
+        }
+        assert!(chain.gain_reduction_db() >= 0.0);
+    }
+
+    #[test]
+    fn test_block_ramp_has_no_step() {
+        // 드라이브 0 -> 12dB 점프를 고정값으로 주면 내부 램프로 부드럽게 변화
+        let mut chain = TapeChain::new(48000.0);
+        chain.set_warmth(0.0);
+        chain.reset();
+        let mut block = [0.1f32; 2048];
+        let ramps = TapeRamps {
+            drive_db: ParamRamp::Constant(12.0),
+            warmth: ParamRamp::Constant(0.0),
+            tone: ParamRamp::Constant(0.0),
+            output_db: ParamRamp::Constant(0.0),
+            dry_wet: ParamRamp::Constant(1.0),
+        };
+        chain.process_block(&mut block, &ramps);
+        let max_step = block
+            .windows(2)
+            .skip(64)
+            .map(|w| (w[1] - w[0]).abs())
+            .fold(0.0, f32::max);
+        assert!(max_step < 0.01, "step {max_step}");
+        assert!(block[2047] > block[100]);
+    }
+
+    #[test]
+    fn test_per_sample_ramp_decimates_coefficients() {
+        let mut chain = TapeChain::new(48000.0);
+        let tone: Vec<f32> = (1..=40).map(|n| n as f32 / 64.0).collect();
+        let ramps = |tone| TapeRamps {
+            drive_db: ParamRamp::Constant(0.0),
+            warmth: ParamRamp::Constant(0.5),
+            tone,
+            output_db: ParamRamp::Constant(0.0),
+            dry_wet: ParamRamp::Constant(1.0),
+        };
+        // 호스트 램프 중에는 COEFF_INTERVAL 샘플마다 (16, 32번째 샘플)
+        chain.process_block(&mut [0.1; 40], &ramps(ParamRamp::PerSample(&tone)));
+        assert_eq!(chain.applied[1], tone[31]);
+        // 램프가 멈추면 바로 최종값
+        chain.process_block(&mut [0.1; 1], &ramps(ParamRamp::PerSample(&tone[39..])));
+        assert_eq!(chain.applied[1], tone[39]);
+    }
+}
@@ -0,0 +1,290 @@
+//! 골든 파일 회귀 테스트
+//! 고정된 테스트 신호를 각 프리셋 / 체인에 통과시켜 tests/golden/*.wav 와 비교
+//! (최대 절대 오차, 스펙트럼 차이, 널 테스트 깊이)
+//!
+//! 소리가 의도적으로 바뀐 경우에만 골든을 다시 만든다: `cargo xtask golden --bless`
+//! (GOLDEN_BLESS=1 로 이 테스트를 실행하는 것과 같음)
+
+use dsp_core::fft::Fft;
+use dsp_core::{
+    process_block, ChainPreset, ChainRamps, DspParams, MagneticEQ, PresetChain, Quality,
+    Restoration, TapeChain, BUILTIN_PRESET_NAMES,
+};
+use std::f32::consts::PI;
+use std::path::{Path, PathBuf};
+
+const SAMPLE_RATE: u32 = 48000;
+const LEN: usize = 8192;
+
+/// 허용 오차 (플랫폼 간 libm 차이는 통과, 음색 변화는 실패)
+const MAX_ABS_DIFF: f32 = 1e-4;
+const MAX_SPECTRAL_DIFF_DB: f32 = 0.1;
+const MAX_NULL_DEPTH_DB: f32 = -80.0;
+
+/// 스펙트럼 비교 프레임 (Hann 창, 50% 겹침)
+const FRAME: usize = 2048;
+/// 골든 피크 대비 이 레벨 아래 빈은 스펙트럼 비교에서 제외
+const SPECTRAL_RANGE_DB: f32 = 60.0;
+
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden")
+}
+
+fn blessing() -> bool {
+    std::env::var_os("GOLDEN_BLESS").is_some_and(|value| value != "0")
+}
+
+/// 테스트 신호
+fn signals() -> Vec<(&'static str, Vec<f32>)> {
+    let sr = SAMPLE_RATE as f32;
+    // 20Hz -> 20kHz 로그 스윕
+    let (f0, f1) = (20.0f32, 20000.0f32);
+    let duration = LEN as f32 / sr;
+    let k = (f1 / f0).ln() / duration;
+    let sweep = (0..LEN)
+        .map(|n| {
+            let t = n as f32 / sr;
+            0.5 * (2.0 * PI * f0 * ((k * t).exp() - 1.0) / k).sin()
+        })
+        .collect();
+    // 두 톤 + 주기적 클릭 + 감쇠 엔벌로프 (포화 구간까지 구동)
+    let program = (0..LEN)
+        .map(|n| {
+            let t = n as f32 / sr;
+            let envelope = 0.9 * (-3.0 * t).exp();
+            let tones = 0.7 * (2.0 * PI * 110.0 * t).sin() + 0.3 * (2.0 * PI * 1760.0 * t).sin();
+            let click = if n % 2048 == 100 { 0.5 } else { 0.0 };
+            envelope * tones + click
+        })
+        .collect();
+    vec![("sweep", sweep), ("program", program)]
+}
+
+/// 프리셋 체인 처리 블록 (플러그인 버퍼 크기, Velocity 판정 단위)
+const BLOCK: usize = 512;
+
+/// 플러그인 / CLI와 같은 PresetChain으로 렌더링 (지연 보정 없음)
+fn render_preset(
+    preset: &ChainPreset,
+    setup: impl Fn(&mut PresetChain),
+    input: &[f32],
+) -> Vec<f32> {
+    let mut chain = PresetChain::new(SAMPLE_RATE as f32, preset);
+    setup(&mut chain);
+    let ramps = ChainRamps::from_preset(preset);
+    let mut output = input.to_vec();
+    for block in output.chunks_mut(BLOCK) {
+        chain.process_block(block, &ramps, None);
+    }
+    output
+}
+
+type Render = Box<dyn Fn(&[f32]) -> Vec<f32>>;
+
+/// 렌더링 대상 체인
+fn chains() -> Vec<(String, Render)> {
+    let sr = SAMPLE_RATE as f32;
+    let mut chains: Vec<(String, Render)> = BUILTIN_PRESET_NAMES
+        .iter()
+        .map(|&name| {
+            let render: Render = Box::new(move |input: &[f32]| {
+                let preset = ChainPreset::builtin(name).unwrap();
+                render_preset(&preset, |chain| chain.set_quality(Quality::Normal), input)
+            });
+            (format!("preset_{name}"), render)
+        })
+        .collect();
+    // 오프라인 품질(선형 위상 Lowpass, 4x) + 복원 단계 전체
+    chains.push((
+        "preset_vinyl_restored_high".into(),
+        Box::new(|input| {
+            let restoration = Restoration {
+                rumble: true,
+                dehum: true,
+                declick: true,
+                denoise: true,
+                ..Restoration::default()
+            };
+            let setup = |chain: &mut PresetChain| {
+                chain.set_quality(Quality::High);
+                chain.set_restoration(&restoration);
+            };
+            render_preset(&ChainPreset::vinyl(), setup, input)
+        }),
+    ));
+    chains.push((
+        "tape_chain".into(),
+        Box::new(move |input| {
+            let mut chain = TapeChain::new(sr);
+            chain.set_drive_db(6.0);
+            chain.set_warmth(0.7);
+            chain.set_tone(0.3);
+            chain.reset();
+            input.iter().map(|&x| chain.process(x)).collect()
+        }),
+    ));
+    chains.push((
+        "magnetic_eq".into(),
+        Box::new(|input| {
+            let eq = MagneticEQ::<f32>::new(0.5, 0.5);
+            input.iter().map(|&x| eq.process(x)).collect()
+        }),
+    ));
+    chains.push((
+        "soft_clip".into(),
+        Box::new(move |input| {
+            let mut output = vec![0.0; input.len()];
+            process_block(input, &mut output, sr, &DspParams::new(6.0));
+            output
+        }),
+    ));
+    chains
+}
+
+fn read_wav(path: &Path) -> Vec<f32> {
+    let mut reader = hound::WavReader::open(path).unwrap_or_else(|err| {
+        panic!(
+            "{}: {err}\nrun `cargo xtask golden --bless` to create it",
+            path.display()
+        )
+    });
+    reader.samples::<f32>().map(Result::unwrap).collect()
+}
+
+fn write_wav(path: &Path, samples: &[f32]) {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: SAMPLE_RATE,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let mut writer = hound::WavWriter::create(path, spec).unwrap();
+    for &sample in samples {
+        writer.write_sample(sample).unwrap();
+    }
+    writer.finalize().unwrap();
+}
+
+/// Hann 창 평균 파워 스펙트럼 (dB)
+fn spectrum_db(signal: &[f32]) -> Vec<f32> {
+    let fft = Fft::<FRAME>::new();
+    let mut power = vec![0.0f32; FRAME / 2 + 1];
+    for start in (0..=signal.len() - FRAME).step_by(FRAME / 2) {
+        let mut re = [0.0; FRAME];
+        let mut im = [0.0; FRAME];
+        for (i, (re, &x)) in re.iter_mut().zip(&signal[start..]).enumerate() {
+            let window = 0.5 - 0.5 * (2.0 * PI * i as f32 / FRAME as f32).cos();
+            *re = x * window;
+        }
+        fft.forward(&mut re, &mut im);
+        for (bin, power) in power.iter_mut().enumerate() {
+            *power += re[bin] * re[bin] + im[bin] * im[bin];
+        }
+    }
+    power.iter().map(|p| 10.0 * (p + 1e-20).log10()).collect()
+}
+
+#[derive(Debug)]
+struct Metrics {
+    max_abs_diff: f32,
+    /// 골든 피크에서 SPECTRAL_RANGE_DB 이내 빈의 최대 레벨 차이
+    spectral_diff_db: f32,
+    /// 차이 신호 RMS / 골든 RMS (dB, 낮을수록 일치)
+    null_depth_db: f32,
+}
+
+impl Metrics {
+    fn measure(output: &[f32], golden: &[f32]) -> Self {
+        let max_abs_diff = output
+            .iter()
+            .zip(golden)
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f32::max);
+
+        let (out_db, golden_db) = (spectrum_db(output), spectrum_db(golden));
+        let peak = golden_db.iter().copied().fold(f32::MIN, f32::max);
+        let spectral_diff_db = out_db
+            .iter()
+            .zip(&golden_db)
+            .filter(|(_, &g)| g > peak - SPECTRAL_RANGE_DB)
+            .map(|(o, g)| (o - g).abs())
+            .fold(0.0, f32::max);
+
+        let residual: f64 = output
+            .iter()
+            .zip(golden)
+            .map(|(a, b)| ((a - b) as f64).powi(2))
+            .sum();
+        let reference: f64 = golden.iter().map(|&x| (x as f64).powi(2)).sum();
+        let residual = residual / reference.max(1e-30);
+        let null_depth_db = (10.0 * residual.max(1e-20).log10()) as f32;
+
+        Self {
+            max_abs_diff,
+            spectral_diff_db,
+            null_depth_db,
+        }
+    }
+
+    fn passes(&self) -> bool {
+        self.max_abs_diff <= MAX_ABS_DIFF
+            && self.spectral_diff_db <= MAX_SPECTRAL_DIFF_DB
+            && self.null_depth_db <= MAX_NULL_DEPTH_DB
+    }
+}
+
+#[test]
+fn test_outputs_match_golden_files() {
+    let dir = golden_dir();
+    let bless = blessing();
+    if bless {
+        std::fs::create_dir_all(&dir).unwrap();
+    }
+
+    let mut failures = Vec::new();
+    for (signal_name, signal) in signals() {
+        for (chain_name, render) in chains() {
+            let output = render(&signal);
+            assert!(
+                output.iter().all(|x| x.is_finite()),
+                "{chain_name}/{signal_name}"
+            );
+            let path = dir.join(format!("{chain_name}__{signal_name}.wav"));
+            if bless {
+                write_wav(&path, &output);
+                println!("blessed {}", path.display());
+                continue;
+            }
+            let golden = read_wav(&path);
+            assert_eq!(
+                golden.len(),
+                output.len(),
+                "{}: length changed",
+                path.display()
+            );
+            let metrics = Metrics::measure(&output, &golden);
+            if !metrics.passes() {
+                failures.push(format!("{chain_name}/{signal_name}: {metrics:?}"));
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "output differs from golden files (re-bless with `cargo xtask golden --bless` if intended):\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn test_metrics_detect_changes() {
+    let (_, signal) = signals().remove(0);
+    let same = Metrics::measure(&signal, &signal);
+    assert!(same.passes());
+    assert_eq!(same.max_abs_diff, 0.0);
+
+    // -60dB 변화: 널 깊이에서 걸림
+    let quieter: Vec<f32> = signal.iter().map(|x| x * 0.999).collect();
+    let metrics = Metrics::measure(&quieter, &signal);
+    assert!(!metrics.passes(), "{metrics:?}");
+    assert!((metrics.null_depth_db + 60.0).abs() < 0.5, "{metrics:?}");
+}
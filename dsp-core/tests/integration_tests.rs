@@ -0,0 +1,164 @@
+//! 통합 테스트: 기준 신호를 전체 체인에 통과시켜 기준 출력과 비교
+//! - 레거시 드라이브 체인 vs 해석적 soft clip
+//! - PresetChain 소신호 경로 (RIAA -> Parallel HF -> Lowpass -> Dry/Wet) vs chain_response_db
+//! - 복원 체인 (럼블 -> 험 -> 클릭) 의 성분별 감쇠
+
+use dsp_core::{
+    chain_response_db, db_to_linear_simple, process_block, process_block_ramped, soft_clip,
+    ChainPreset, ChainRamps, Declicker, DspParams, HumRemover, ParamRamp, PresetChain,
+    RumbleFilter, TapeChain, BUILTIN_PRESET_NAMES,
+};
+use std::f32::consts::PI;
+
+fn sine(freq: f32, sample_rate: f32, len: usize, amplitude: f32) -> Vec<f32> {
+    (0..len)
+        .map(|n| amplitude * (2.0 * PI * freq * n as f32 / sample_rate).sin())
+        .collect()
+}
+
+/// 신호 끝부분(정수 주기)에서 freq 성분의 진폭 (직교 상관)
+fn amplitude_at(signal: &[f32], freq: f32, sample_rate: f32) -> f32 {
+    let period = sample_rate / freq;
+    let periods = ((signal.len() / 4) as f32 / period).floor().max(1.0);
+    let len = (periods * period).round() as usize;
+    let start = signal.len() - len;
+    let (mut re, mut im) = (0.0f64, 0.0f64);
+    for (i, &y) in signal[start..].iter().enumerate() {
+        let phase =
+            2.0 * std::f64::consts::PI * freq as f64 * (start + i) as f64 / sample_rate as f64;
+        re += y as f64 * phase.cos();
+        im += y as f64 * phase.sin();
+    }
+    (2.0 * (re * re + im * im).sqrt() / len as f64) as f32
+}
+
+fn to_db(ratio: f32) -> f32 {
+    20.0 * ratio.max(1e-9).log10()
+}
+
+#[test]
+fn test_legacy_chain_matches_soft_clip_reference() {
+    let sample_rate = 48000.0;
+    let input = sine(440.0, sample_rate, 4800, 0.9);
+    for drive_db in [0.0, 6.0, 18.0] {
+        let mut output = vec![0.0; input.len()];
+        process_block(&input, &mut output, sample_rate, &DspParams::new(drive_db));
+        let drive = db_to_linear_simple(drive_db);
+        for (y, &x) in output.iter().zip(&input) {
+            assert_eq!(*y, soft_clip(x * drive));
+        }
+        assert!(output.iter().all(|y| y.abs() <= 2.0 / 3.0 + 1e-6));
+    }
+
+    // 샘플별 램프: 각 샘플이 그 시점의 드라이브로 처리됨
+    let ramp: Vec<f32> = (0..input.len())
+        .map(|n| 12.0 * n as f32 / input.len() as f32)
+        .collect();
+    let mut output = vec![0.0; input.len()];
+    process_block_ramped(
+        &input,
+        &mut output,
+        sample_rate,
+        ParamRamp::PerSample(&ramp),
+    );
+    for ((y, &x), &db) in output.iter().zip(&input).zip(&ramp) {
+        assert_eq!(*y, soft_clip(x * db_to_linear_simple(db)));
+    }
+}
+
+#[test]
+fn test_preset_linear_path_matches_response() {
+    for sample_rate in [44100.0, 48000.0] {
+        for name in BUILTIN_PRESET_NAMES {
+            let preset = ChainPreset::builtin(name).unwrap();
+            for freq in [100.0, 1000.0, 5000.0, 12000.0] {
+                // RIAA 경로 + Parallel HF 게이트 열림 (chain_response_db 기준)
+                let preset = ChainPreset {
+                    magnetic_threshold: 0.0,
+                    parallel_threshold: 1.0,
+                    ..preset
+                };
+                let mut chain = PresetChain::new(sample_rate, &preset);
+                let ramps = ChainRamps::from_preset(&preset);
+                // RIAA 저역 부스트 후에도 리미터 아래에 머무는 레벨
+                let mut output = sine(freq, sample_rate, sample_rate as usize, 0.001);
+                for block in output.chunks_mut(512) {
+                    chain.process_block(block, &ramps, Some(0.5));
+                }
+                let measured = to_db(amplitude_at(&output, freq, sample_rate) / 0.001);
+                let expected = chain_response_db(&preset, sample_rate, freq);
+                assert!(
+                    (measured - expected).abs() < 0.25,
+                    "{name} @ {sample_rate} Hz, {freq} Hz: measured {measured} dB, expected {expected} dB"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_restoration_chain_removes_rumble_hum_and_clicks() {
+    let sample_rate = 48000.0;
+    let len = sample_rate as usize * 3;
+    let tone = sine(1000.0, sample_rate, len, 0.3);
+    let hum = sine(60.0, sample_rate, len, 0.1);
+    let rumble = sine(5.0, sample_rate, len, 0.1);
+    let mut input: Vec<f32> = (0..len).map(|n| tone[n] + hum[n] + rumble[n]).collect();
+    let clicks: Vec<usize> = (1..30).map(|k| k * len / 30 + 17).collect();
+    for &n in &clicks {
+        input[n] += 0.9;
+    }
+
+    let mut rumble_filter = RumbleFilter::new(sample_rate, 20.0);
+    let mut dehum = HumRemover::new(sample_rate, 4, 30.0);
+    let mut declicker = Declicker::new(sample_rate, 0.5, 1.0);
+    let output: Vec<f32> = input
+        .iter()
+        .map(|&x| declicker.process(dehum.process(rumble_filter.process(x))))
+        .collect();
+
+    let fundamental = dehum.fundamental().expect("hum detected");
+    assert!(
+        (fundamental - 60.0).abs() < 1.0,
+        "detected {fundamental} Hz"
+    );
+    assert!(declicker.clicks_repaired() >= clicks.len() / 2);
+
+    let level = |freq| to_db(amplitude_at(&output, freq, sample_rate));
+    assert!(
+        (level(1000.0) - to_db(0.3)).abs() < 0.5,
+        "tone {}",
+        level(1000.0)
+    );
+    assert!(level(60.0) < to_db(0.1) - 30.0, "hum {}", level(60.0));
+    assert!(level(5.0) < to_db(0.1) - 40.0, "rumble {}", level(5.0));
+
+    // 마지막 1초: 클릭 스파이크 없이 톤 진폭 근처
+    let peak = output[len - sample_rate as usize..]
+        .iter()
+        .fold(0.0f32, |p, y| p.max(y.abs()));
+    assert!(peak < 0.36, "peak {peak}");
+}
+
+#[test]
+fn test_tape_chain_distortion_grows_with_drive() {
+    let sample_rate = 48000.0;
+    let input = sine(200.0, sample_rate, sample_rate as usize / 2, 0.25);
+    let distortion = |drive_db: f32| {
+        let mut chain = TapeChain::new(sample_rate);
+        chain.set_drive_db(drive_db);
+        chain.reset();
+        let output: Vec<f32> = input.iter().map(|&x| chain.process(x)).collect();
+        let fundamental = amplitude_at(&output, 200.0, sample_rate);
+        let harmonics: f32 = (2..=7)
+            .map(|h| amplitude_at(&output, 200.0 * h as f32, sample_rate).powi(2))
+            .sum();
+        assert!(
+            fundamental > 0.01,
+            "drive {drive_db}: fundamental {fundamental}"
+        );
+        harmonics.sqrt() / fundamental
+    };
+    let thd: Vec<f32> = [0.0, 12.0, 24.0].map(distortion).to_vec();
+    assert!(thd[0] < thd[1] && thd[1] < thd[2], "THD {thd:?}");
+}
@@ -0,0 +1,595 @@
+//! 속성 기반 테스트 (결정적 의사 난수, 실패하면 시드를 출력)
+//! - 유계 입력 + 범위 안 고정 파라미터 -> 유계 출력
+//! - 임의의 유한 파라미터 (범위 밖, 0, 음수, 극단값) -> 유한 출력
+//! - reset 후 출력 == 같은 설정의 새 인스턴스 출력
+//!
+//! 실패한 케이스 재현: `PROPERTY_SEED=<시드> cargo test -p dsp-core --test property_tests`
+//! 케이스 수 늘리기: `PROPERTY_CASES=<n>`
+
+use dsp_core::{
+    process_block, soft_clip, BiquadWrapper, Declicker, DelayLine, DspParams, Fir, HumRemover,
+    Limiter, MagneticEQ, NoiseProfileMode, NoiseReducer, Oversampler, ParallelFilterAdvanced,
+    RIAAEQAdvanced, RumbleFilter, Sample, TapeChain,
+};
+use std::panic::{self, AssertUnwindSafe};
+
+const DEFAULT_CASES: u64 = 16;
+const SAMPLE_RATES: [f32; 5] = [22050.0, 44100.0, 48000.0, 96000.0, 192000.0];
+/// 한 케이스의 신호 길이 (NoiseReducer 지연 1024 이상)
+const LEN: usize = 3072;
+/// 신호 도중 파라미터를 바꾸는 간격
+const RETUNE_INTERVAL: usize = 512;
+
+/// xorshift64* (외부 크레이트 없이 재현 가능한 난수)
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// 0.0..1.0
+    fn unit(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    fn range(&mut self, low: f32, high: f32) -> f32 {
+        low + (high - low) * self.unit()
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    fn chance(&mut self, probability: f32) -> bool {
+        self.unit() < probability
+    }
+
+    /// 범위와 무관한 유한 값 (0, 음수, 아주 작은 값, 아주 큰 값)
+    fn any_finite(&mut self) -> f32 {
+        match self.below(4) {
+            0 => [0.0, -0.0, 1.0, -1.0, 0.5][self.below(5)],
+            1 => self.range(-2.0, 2.0),
+            2 => {
+                let sign = if self.chance(0.5) { 1.0 } else { -1.0 };
+                sign * 10f32.powf(self.range(-30.0, 30.0))
+            }
+            _ => [f32::MIN_POSITIVE, f32::EPSILON, 1e30, -1e30, 0.4999, 0.5001][self.below(6)],
+        }
+    }
+
+    /// |x| <= amplitude 인 테스트 신호 (잡음, 사인, 구형파, 임펄스, DC 계단 혼합)
+    fn signal(&mut self, len: usize, amplitude: f32) -> Vec<f32> {
+        let mut signal = vec![0.0; len];
+        let mut start = 0;
+        while start < len {
+            let end = (start + 64 + self.below(len / 2)).min(len);
+            let level = amplitude * self.range(0.1, 1.0);
+            let freq = self.range(0.0005, 0.5);
+            let kind = self.below(5);
+            for (n, x) in signal[start..end].iter_mut().enumerate() {
+                let phase = freq * n as f32;
+                *x = match kind {
+                    0 => self.range(-level, level),
+                    1 => level * (2.0 * std::f32::consts::PI * phase).sin(),
+                    2 => level * if phase.fract() < 0.5 { 1.0 } else { -1.0 },
+                    3 if n % 97 == 0 => level,
+                    3 => 0.0,
+                    _ => level,
+                };
+            }
+            start = end;
+        }
+        signal
+    }
+}
+
+/// 파라미터 추출기: wild 이면 절반은 범위를 무시한 유한 값
+struct Draw<'a> {
+    rng: &'a mut Rng,
+    wild: bool,
+}
+
+impl Draw<'_> {
+    fn param(&mut self, low: f32, high: f32) -> f32 {
+        if self.wild && self.rng.chance(0.5) {
+            self.rng.any_finite()
+        } else {
+            self.rng.range(low, high)
+        }
+    }
+
+    fn count(&mut self, low: usize, high: usize) -> usize {
+        if self.wild && self.rng.chance(0.5) {
+            [0, 3, 1000, usize::MAX][self.rng.below(4)]
+        } else {
+            low + self.rng.below(high - low + 1)
+        }
+    }
+}
+
+/// 테스트 대상 프로세서
+trait Subject {
+    fn process(&mut self, x: f32) -> f32;
+    /// 처리 도중 파라미터 변경
+    fn retune(&mut self, draw: &mut Draw);
+    fn reset(&mut self);
+}
+
+#[derive(Clone, Copy)]
+enum Design {
+    Lowpass,
+    Highpass,
+    Notch,
+    Peaking,
+    HighShelf,
+}
+
+struct Biquad<T: Sample> {
+    design: Design,
+    sample_rate: f32,
+    filter: BiquadWrapper<T>,
+}
+
+impl<T: Sample> Biquad<T> {
+    fn new(design: Design, draw: &mut Draw, sample_rate: f32) -> Self {
+        Self {
+            design,
+            sample_rate,
+            filter: Self::design(design, draw, sample_rate),
+        }
+    }
+
+    fn design(design: Design, draw: &mut Draw, sample_rate: f32) -> BiquadWrapper<T> {
+        let freq = draw.param(20.0 / sample_rate, 0.45);
+        let q = draw.param(0.1, 10.0);
+        let gain_db = draw.param(-24.0, 24.0);
+        match design {
+            Design::Lowpass => BiquadWrapper::lowpass(freq, q),
+            Design::Highpass => BiquadWrapper::highpass(freq, q),
+            Design::Notch => BiquadWrapper::notch(freq, q),
+            Design::Peaking => BiquadWrapper::peaking(freq, q, gain_db),
+            Design::HighShelf => BiquadWrapper::high_shelf(freq, gain_db),
+        }
+    }
+}
+
+impl<T: Sample> Subject for Biquad<T> {
+    fn process(&mut self, x: f32) -> f32 {
+        self.filter.process(T::from_f32(x)).to_f32()
+    }
+
+    fn retune(&mut self, draw: &mut Draw) {
+        let filter = Self::design(self.design, draw, self.sample_rate);
+        self.filter.set_coefficients(&filter);
+    }
+
+    fn reset(&mut self) {
+        self.filter.reset();
+    }
+}
+
+impl<T: Sample> Subject for RIAAEQAdvanced<T> {
+    fn process(&mut self, x: f32) -> f32 {
+        RIAAEQAdvanced::process(self, T::from_f32(x)).to_f32()
+    }
+
+    fn retune(&mut self, _draw: &mut Draw) {}
+
+    fn reset(&mut self) {
+        RIAAEQAdvanced::reset(self);
+    }
+}
+
+impl Subject for ParallelFilterAdvanced {
+    fn process(&mut self, x: f32) -> f32 {
+        ParallelFilterAdvanced::process(self, x)
+    }
+
+    fn retune(&mut self, draw: &mut Draw) {
+        self.set_intensity(draw.param(0.0, 1.0));
+    }
+
+    fn reset(&mut self) {
+        ParallelFilterAdvanced::reset(self);
+    }
+}
+
+impl Subject for MagneticEQ {
+    fn process(&mut self, x: f32) -> f32 {
+        MagneticEQ::process(self, x)
+    }
+
+    fn retune(&mut self, draw: &mut Draw) {
+        self.set_saturation(draw.param(0.0, 1.0));
+        self.set_hardness(draw.param(0.0, 1.0));
+    }
+
+    fn reset(&mut self) {}
+}
+
+/// 설정자가 없으므로 retune 은 새 인스턴스로 교체
+struct LimiterSubject(Limiter, f32);
+
+impl LimiterSubject {
+    fn new(draw: &mut Draw, sample_rate: f32) -> Self {
+        Self(Self::limiter(draw, sample_rate), sample_rate)
+    }
+
+    /// release: 1ms-1s (샘플 단위)
+    fn limiter(draw: &mut Draw, sample_rate: f32) -> Limiter {
+        Limiter::new(draw.param(0.1, 1.0), draw.param(0.001, 1.0) * sample_rate)
+    }
+}
+
+impl Subject for LimiterSubject {
+    fn process(&mut self, x: f32) -> f32 {
+        self.0.process(x)
+    }
+
+    fn retune(&mut self, draw: &mut Draw) {
+        self.0 = Self::limiter(draw, self.1);
+    }
+
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+struct DeclickerSubject(Declicker, f32);
+
+impl Subject for DeclickerSubject {
+    fn process(&mut self, x: f32) -> f32 {
+        self.0.process(x)
+    }
+
+    fn retune(&mut self, draw: &mut Draw) {
+        self.0.set_sensitivity(draw.param(0.0, 1.0));
+        self.0.set_max_click_ms(draw.param(0.1, 2.0), self.1);
+    }
+
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Subject for NoiseReducer {
+    fn process(&mut self, x: f32) -> f32 {
+        NoiseReducer::process(self, x)
+    }
+
+    fn retune(&mut self, draw: &mut Draw) {
+        self.set_over_subtraction(draw.param(0.0, 4.0));
+        self.set_floor_db(draw.param(-60.0, 0.0));
+        self.set_smoothing(draw.param(0.0, 0.95));
+        let modes = [
+            NoiseProfileMode::Auto,
+            NoiseProfileMode::Learn,
+            NoiseProfileMode::Hold,
+        ];
+        self.set_profile_mode(modes[draw.rng.below(modes.len())]);
+    }
+
+    fn reset(&mut self) {
+        NoiseReducer::reset(self);
+    }
+}
+
+impl Subject for HumRemover {
+    fn process(&mut self, x: f32) -> f32 {
+        HumRemover::process(self, x)
+    }
+
+    fn retune(&mut self, draw: &mut Draw) {
+        self.set_harmonics(draw.count(1, 8));
+        self.set_q(draw.param(1.0, 100.0));
+        self.set_detection_window(draw.param(0.5, 2.0));
+        let fundamental = draw.param(45.0, 65.0);
+        self.set_fundamental(draw.rng.chance(0.8).then_some(fundamental));
+    }
+
+    fn reset(&mut self) {
+        HumRemover::reset(self);
+    }
+}
+
+impl Subject for RumbleFilter {
+    fn process(&mut self, x: f32) -> f32 {
+        RumbleFilter::process(self, x)
+    }
+
+    fn retune(&mut self, draw: &mut Draw) {
+        self.set_cutoff(draw.param(10.0, 60.0));
+    }
+
+    fn reset(&mut self) {
+        RumbleFilter::reset(self);
+    }
+}
+
+impl Subject for Fir<63> {
+    fn process(&mut self, x: f32) -> f32 {
+        Fir::process(self, x)
+    }
+
+    fn retune(&mut self, draw: &mut Draw) {
+        self.set_lowpass(draw.param(0.001, 0.499));
+    }
+
+    fn reset(&mut self) {
+        Fir::reset(self);
+    }
+}
+
+impl Subject for Oversampler {
+    fn process(&mut self, x: f32) -> f32 {
+        Oversampler::process(self, x, soft_clip)
+    }
+
+    fn retune(&mut self, draw: &mut Draw) {
+        self.set_factor(draw.count(1, 8));
+    }
+
+    fn reset(&mut self) {
+        Oversampler::reset(self);
+    }
+}
+
+impl Subject for DelayLine<64> {
+    fn process(&mut self, x: f32) -> f32 {
+        DelayLine::process(self, x)
+    }
+
+    fn retune(&mut self, draw: &mut Draw) {
+        self.set_delay(draw.count(0, 63));
+    }
+
+    fn reset(&mut self) {
+        DelayLine::reset(self);
+    }
+}
+
+impl Subject for TapeChain {
+    fn process(&mut self, x: f32) -> f32 {
+        TapeChain::process(self, x)
+    }
+
+    fn retune(&mut self, draw: &mut Draw) {
+        self.set_drive_db(draw.param(0.0, 20.0));
+        self.set_warmth(draw.param(0.0, 1.0));
+        self.set_tone(draw.param(-1.0, 1.0));
+        self.set_output_db(draw.param(-12.0, 12.0));
+        self.set_dry_wet(draw.param(0.0, 1.0));
+        if draw.rng.chance(0.2) {
+            self.set_oversampling(draw.count(1, 8));
+        }
+    }
+
+    fn reset(&mut self) {
+        TapeChain::reset(self);
+    }
+}
+
+/// 레거시 드라이브 + soft clip (샘플 단위 호출)
+struct Legacy(DspParams);
+
+impl Subject for Legacy {
+    fn process(&mut self, x: f32) -> f32 {
+        let mut y = [0.0];
+        process_block(&[x], &mut y, 48000.0, &self.0);
+        y[0]
+    }
+
+    fn retune(&mut self, draw: &mut Draw) {
+        self.0.drive_db = draw.param(-24.0, 24.0);
+    }
+
+    fn reset(&mut self) {}
+}
+
+type Build = fn(&mut Draw, f32) -> Box<dyn Subject>;
+
+/// (이름, |x| <= 1 이고 파라미터가 범위 안에 고정일 때의 출력 한계, 생성자)
+/// 생성자는 초기 파라미터를 뽑고 retune 한 번과 같은 범위를 사용
+fn subjects() -> Vec<(&'static str, f32, Build)> {
+    vec![
+        ("lowpass", 64.0, |d, sr| {
+            Box::new(Biquad::<f32>::new(Design::Lowpass, d, sr))
+        }),
+        ("highpass", 64.0, |d, sr| {
+            Box::new(Biquad::<f32>::new(Design::Highpass, d, sr))
+        }),
+        ("notch", 64.0, |d, sr| {
+            Box::new(Biquad::<f32>::new(Design::Notch, d, sr))
+        }),
+        ("peaking", 64.0, |d, sr| {
+            Box::new(Biquad::<f32>::new(Design::Peaking, d, sr))
+        }),
+        ("high_shelf", 64.0, |d, sr| {
+            Box::new(Biquad::<f32>::new(Design::HighShelf, d, sr))
+        }),
+        ("lowpass_f64", 64.0, |d, sr| {
+            Box::new(Biquad::<f64>::new(Design::Lowpass, d, sr))
+        }),
+        ("riaa", 64.0, |_, sr| {
+            Box::new(RIAAEQAdvanced::<f32>::new(sr as u32))
+        }),
+        ("riaa_f64", 64.0, |_, sr| {
+            Box::new(RIAAEQAdvanced::<f64>::new(sr as u32))
+        }),
+        ("parallel", 8.0, |d, _| {
+            Box::new(ParallelFilterAdvanced::new(d.param(0.0, 1.0)))
+        }),
+        ("magnetic", 4.0, |d, _| {
+            Box::new(MagneticEQ::new(d.param(0.0, 1.0), d.param(0.0, 1.0)))
+        }),
+        ("limiter", 1.0, |d, sr| Box::new(LimiterSubject::new(d, sr))),
+        ("declicker", 4.0, |d, sr| {
+            let declicker = Declicker::new(sr, d.param(0.0, 1.0), d.param(0.1, 2.0));
+            Box::new(DeclickerSubject(declicker, sr))
+        }),
+        ("denoiser", 4.0, |d, _| {
+            Box::new(NoiseReducer::new(
+                d.param(0.0, 4.0),
+                d.param(-60.0, 0.0),
+                d.param(0.0, 0.95),
+            ))
+        }),
+        ("dehum", 8.0, |d, sr| {
+            let mut dehum = HumRemover::new(sr, d.count(1, 8), d.param(1.0, 100.0));
+            dehum.set_fundamental(Some(d.param(45.0, 65.0)));
+            Box::new(dehum)
+        }),
+        ("rumble", 8.0, |d, sr| {
+            Box::new(RumbleFilter::new(sr, d.param(10.0, 60.0)))
+        }),
+        ("fir", 8.0, |d, _| {
+            Box::new(Fir::<63>::lowpass(d.param(0.001, 0.499)))
+        }),
+        ("oversampler", 4.0, |d, _| {
+            Box::new(Oversampler::new(d.count(1, 8)))
+        }),
+        ("delay", 1.0, |d, _| {
+            Box::new(DelayLine::<64>::new(d.count(0, 63)))
+        }),
+        ("tape_chain", 64.0, |d, sr| {
+            let mut tape = TapeChain::new(sr);
+            tape.set_oversampling(d.count(1, 8));
+            Subject::retune(&mut tape, d);
+            tape.reset();
+            Box::new(tape)
+        }),
+        ("legacy_chain", 1.0, |d, _| {
+            Box::new(Legacy(DspParams::new(d.param(-24.0, 24.0))))
+        }),
+    ]
+}
+
+fn env_u64(name: &str) -> Option<u64> {
+    let value = std::env::var(name).ok()?;
+    let value = value.trim();
+    match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    }
+}
+
+/// 케이스마다 새 시드로 속성 검사, 실패하면 재현용 시드 출력
+fn check(property: &str, mut case: impl FnMut(&mut Rng)) {
+    let seeds: Vec<u64> = match env_u64("PROPERTY_SEED") {
+        Some(seed) => vec![seed],
+        None => {
+            let cases = env_u64("PROPERTY_CASES").unwrap_or(DEFAULT_CASES);
+            let base = property.bytes().fold(0xcbf2_9ce4_8422_2325u64, |h, b| {
+                (h ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+            });
+            (0..cases).map(|i| base.wrapping_add(i)).collect()
+        }
+    };
+    for seed in seeds {
+        let mut rng = Rng::new(seed);
+        if let Err(err) = panic::catch_unwind(AssertUnwindSafe(|| case(&mut rng))) {
+            eprintln!("{property}: failed with PROPERTY_SEED={seed:#x}");
+            panic::resume_unwind(err);
+        }
+    }
+}
+
+/// 신호를 처리하면서 RETUNE_INTERVAL 마다 파라미터 변경
+fn run(subject: &mut dyn Subject, input: &[f32], draw: &mut Draw) -> Vec<f32> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(n, &x)| {
+            if n > 0 && n % RETUNE_INTERVAL == 0 {
+                subject.retune(draw);
+            }
+            subject.process(x)
+        })
+        .collect()
+}
+
+/// 파라미터 고정 (처리 도중 계수 전환의 과도 응답은 LTI 한계를 넘을 수 있어
+/// 유한성 속성에서만 검사)
+#[test]
+fn test_bounded_input_gives_bounded_output() {
+    check("bounded", |rng| {
+        let sample_rate = SAMPLE_RATES[rng.below(SAMPLE_RATES.len())];
+        let input = rng.signal(LEN, 1.0);
+        for (name, bound, build) in subjects() {
+            let mut draw = Draw {
+                rng: &mut *rng,
+                wild: false,
+            };
+            let mut subject = build(&mut draw, sample_rate);
+            for (n, &x) in input.iter().enumerate() {
+                let y = subject.process(x);
+                assert!(
+                    y.abs() <= bound,
+                    "{name} @ {sample_rate} Hz: sample {n} = {y} exceeds {bound}"
+                );
+            }
+        }
+    });
+}
+
+#[test]
+fn test_finite_parameters_give_finite_output() {
+    check("finite", |rng| {
+        let sample_rate = SAMPLE_RATES[rng.below(SAMPLE_RATES.len())];
+        let input = rng.signal(LEN, 1.0);
+        for (name, _, build) in subjects() {
+            let mut draw = Draw {
+                rng: &mut *rng,
+                wild: true,
+            };
+            let mut subject = build(&mut draw, sample_rate);
+            let output = run(subject.as_mut(), &input, &mut draw);
+            for (n, y) in output.iter().enumerate() {
+                assert!(y.is_finite(), "{name} @ {sample_rate} Hz: sample {n} = {y}");
+            }
+        }
+    });
+}
+
+#[test]
+fn test_reset_matches_fresh_instance() {
+    check("reset", |rng| {
+        let sample_rate = SAMPLE_RATES[rng.below(SAMPLE_RATES.len())];
+        let history = rng.signal(LEN / 2, 1.0);
+        let probe = rng.signal(LEN, 1.0);
+        for (name, _, build) in subjects() {
+            // 같은 시드로 두 번 생성 -> 같은 파라미터
+            let seed = rng.next_u64();
+            let make = || {
+                build(
+                    &mut Draw {
+                        rng: &mut Rng::new(seed),
+                        wild: false,
+                    },
+                    sample_rate,
+                )
+            };
+            let (mut used, mut fresh) = (make(), make());
+
+            for &x in &history {
+                used.process(x);
+            }
+            used.reset();
+            for (n, &x) in probe.iter().enumerate() {
+                let (a, b) = (used.process(x), fresh.process(x));
+                assert!(
+                    a == b,
+                    "{name} @ {sample_rate} Hz: sample {n} after reset {a} != fresh {b}"
+                );
+            }
+        }
+    });
+}
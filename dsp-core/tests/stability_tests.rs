@@ -0,0 +1,273 @@
+//! 안정성 테스트
+//! - 모든 필터 설계의 극점이 단위원 안에 있는지 (샘플레이트 전 범위)
+//! - 무음 / DC / 풀스케일 잡음 / 임펄스 입력에서 NaN, inf, 발산이 없는지
+//! - 긴 무음 꼬리에서 비정규화 수로 인한 CPU 급증이 없는지
+
+use dsp_core::riaa_eq_advanced::RIAACoefficients;
+use dsp_core::{
+    process_block, soft_clip, BiquadWrapper, ChainPreset, Declicker, DelayLine, DspParams, Fir,
+    HumRemover, Limiter, MagneticEQ, NoiseReducer, Oversampler, ParallelFilterAdvanced,
+    RIAAEQAdvanced, RumbleFilter, TapeChain, BUILTIN_PRESET_NAMES,
+};
+use std::hint::black_box;
+use std::time::Instant;
+
+const SAMPLE_RATES: [f32; 7] = [
+    22050.0, 32000.0, 44100.0, 48000.0, 88200.0, 96000.0, 192000.0,
+];
+
+/// z^2 + a1 z + a2 의 근이 단위원 안에 있는지 (Jury 조건)
+fn assert_poles_inside(coefficients: [f64; 5], what: &str) {
+    let [_, _, _, a1, a2] = coefficients;
+    assert!(
+        a2.abs() < 1.0 && a1.abs() < 1.0 + a2,
+        "{what}: poles outside unit circle (a1 = {a1}, a2 = {a2})"
+    );
+}
+
+fn f32_poles(filter: &BiquadWrapper, what: &str) {
+    assert_poles_inside(filter.coefficients().map(f64::from), what);
+}
+
+/// 20Hz부터 Nyquist 직전(0.45)까지 로그 간격 정규화 주파수
+fn sweep(sample_rate: f32) -> impl Iterator<Item = f32> {
+    let low = 20.0 / sample_rate;
+    (0..=24).map(move |i| low * (0.45 / low).powf(i as f32 / 24.0))
+}
+
+#[test]
+fn test_biquad_designs_are_stable() {
+    for sample_rate in SAMPLE_RATES {
+        for cutoff in sweep(sample_rate) {
+            for q in [0.5, 0.707, 1.0, 2.5, 10.0] {
+                let at = format!("{sample_rate} Hz, cutoff {cutoff}, q {q}");
+                f32_poles(&BiquadWrapper::lowpass(cutoff, q), &format!("lowpass {at}"));
+                f32_poles(
+                    &BiquadWrapper::highpass(cutoff, q),
+                    &format!("highpass {at}"),
+                );
+                for gain_db in [-12.0, 0.0, 12.0] {
+                    let peaking = BiquadWrapper::peaking(cutoff, q, gain_db);
+                    f32_poles(&peaking, &format!("peaking {at}, {gain_db} dB"));
+                }
+            }
+            for gain_db in [-6.0, 0.0, 6.0] {
+                let shelf = BiquadWrapper::high_shelf(cutoff, gain_db);
+                f32_poles(
+                    &shelf,
+                    &format!("high shelf {sample_rate} Hz, {cutoff}, {gain_db} dB"),
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_chain_filters_are_stable() {
+    for sample_rate in SAMPLE_RATES {
+        // RIAA (샘플레이트별 계수)
+        let c = RIAACoefficients::calculate(sample_rate as u32);
+        assert_poles_inside(c.to_array(), &format!("riaa {sample_rate} Hz"));
+
+        // 프리셋 출력 Lowpass
+        for name in BUILTIN_PRESET_NAMES {
+            let preset = ChainPreset::builtin(name).unwrap();
+            let lowpass =
+                BiquadWrapper::lowpass(preset.lowpass_normalized(sample_rate), preset.lowpass_q);
+            f32_poles(&lowpass, &format!("{name} lowpass {sample_rate} Hz"));
+        }
+
+        // 험 노치: 50/60Hz 계열 고조파, Q 전 범위 (0.45 이상은 통과 처리)
+        for fundamental in [45.0, 50.0, 60.0, 65.0] {
+            for harmonic in 1..=8 {
+                let center = fundamental * harmonic as f32 / sample_rate;
+                for q in [1.0, 30.0, 100.0] {
+                    let notch = BiquadWrapper::notch(center, q);
+                    f32_poles(&notch, &format!("notch {center} q {q} @ {sample_rate} Hz"));
+                }
+            }
+        }
+
+        // 럼블 하이패스 섹션 (f64 설계, 10-60Hz)
+        for cutoff_hz in [10.0, 20.0, 60.0] {
+            for q in [0.5098, 0.6013, 0.9, 2.5629] {
+                let section = BiquadWrapper::<f64>::highpass(cutoff_hz / sample_rate, q);
+                assert_poles_inside(
+                    section.coefficients(),
+                    &format!("rumble {cutoff_hz} Hz q {q} @ {sample_rate} Hz"),
+                );
+            }
+        }
+    }
+}
+
+type Process = Box<dyn FnMut(f32) -> f32>;
+
+/// 테스트 대상 프로세서 (샘플레이트별 새 인스턴스)
+fn processors(sample_rate: f32) -> Vec<(&'static str, Process)> {
+    let sr = sample_rate;
+    let preset = ChainPreset::vinyl();
+    let mut lowpass = BiquadWrapper::lowpass(preset.lowpass_normalized(sr), preset.lowpass_q);
+    let mut highpass = BiquadWrapper::highpass(30.0 / sr, 0.707);
+    let mut riaa = RIAAEQAdvanced::<f32>::new(sr as u32);
+    let mut riaa_f64 = RIAAEQAdvanced::<f64>::new(sr as u32);
+    let mut parallel = ParallelFilterAdvanced::<f32>::new(1.0);
+    let magnetic = MagneticEQ::<f32>::new(1.0, 1.0);
+    let mut limiter = Limiter::<f32>::new(0.95, preset.limiter_release_samples(sr));
+    let mut declicker = Declicker::new(sr, 1.0, 1.0);
+    let mut denoiser = NoiseReducer::new(4.0, -40.0, 0.5);
+    let mut dehum = HumRemover::new(sr, 8, 100.0);
+    dehum.set_fundamental(Some(60.0));
+    let mut rumble = RumbleFilter::new(sr, 10.0);
+    let mut fir = Fir::<127>::lowpass(0.45);
+    let mut oversampler = Oversampler::new(2);
+    let mut delay = DelayLine::<64>::new(64);
+    let mut tape = TapeChain::new(sr);
+    tape.set_drive_db(24.0);
+    tape.set_warmth(1.0);
+    tape.set_tone(1.0);
+    tape.set_oversampling(2);
+    tape.reset();
+    let legacy = DspParams::new(24.0);
+
+    vec![
+        ("lowpass", Box::new(move |x| lowpass.process(x))),
+        ("highpass", Box::new(move |x| highpass.process(x))),
+        ("riaa", Box::new(move |x| riaa.process(x))),
+        (
+            "riaa_f64",
+            Box::new(move |x| riaa_f64.process(x as f64) as f32),
+        ),
+        ("parallel", Box::new(move |x| parallel.process(x))),
+        ("magnetic", Box::new(move |x| magnetic.process(x))),
+        ("limiter", Box::new(move |x| limiter.process(x))),
+        ("declicker", Box::new(move |x| declicker.process(x))),
+        ("denoiser", Box::new(move |x| denoiser.process(x))),
+        ("dehum", Box::new(move |x| dehum.process(x))),
+        ("rumble", Box::new(move |x| rumble.process(x))),
+        ("fir", Box::new(move |x| fir.process(x))),
+        (
+            "oversampler",
+            Box::new(move |x| oversampler.process(x, soft_clip)),
+        ),
+        ("delay", Box::new(move |x| delay.process(x))),
+        ("tape_chain", Box::new(move |x| tape.process(x))),
+        (
+            "legacy_chain",
+            Box::new(move |x| {
+                let mut y = [0.0];
+                process_block(&[x], &mut y, sr, &legacy);
+                y[0]
+            }),
+        ),
+    ]
+}
+
+/// 결정적 풀스케일 잡음 (-1.0..1.0)
+fn noise(len: usize) -> Vec<f32> {
+    let mut seed = 0x9e37_79b9u32;
+    (0..len)
+        .map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            seed as f32 / u32::MAX as f32 * 2.0 - 1.0
+        })
+        .collect()
+}
+
+fn stimuli(sample_rate: f32) -> Vec<(&'static str, Vec<f32>)> {
+    let len = sample_rate as usize / 4;
+    let mut impulses = vec![0.0; len];
+    for n in (0..len).step_by(len / 8) {
+        impulses[n] = 1.0;
+    }
+    vec![
+        ("silence", vec![0.0; len]),
+        ("dc", vec![1.0; len]),
+        ("noise", noise(len)),
+        ("impulses", impulses),
+    ]
+}
+
+/// 입력 뒤에 무음 꼬리까지 처리, 출력은 유한하고 유계
+#[test]
+fn test_processors_stay_finite_and_bounded() {
+    for sample_rate in [22050.0, 96000.0] {
+        for (stimulus, input) in stimuli(sample_rate) {
+            for (name, mut process) in processors(sample_rate) {
+                let tail = vec![0.0; sample_rate as usize / 4];
+                for (n, &x) in input.iter().chain(&tail).enumerate() {
+                    let y = process(x);
+                    assert!(
+                        y.is_finite() && y.abs() < 100.0,
+                        "{name} @ {sample_rate} Hz, {stimulus}: sample {n} = {y}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// 임펄스 후 무음: 재귀 필터의 출력은 감쇠해야 함 (발산 / 한계 순환 없음)
+/// 고-Q 노치는 울림이 길어 기준을 -30dB로 둠
+#[test]
+fn test_impulse_response_decays() {
+    for sample_rate in [22050.0, 48000.0, 192000.0] {
+        for (name, mut process) in processors(sample_rate) {
+            let len = sample_rate as usize;
+            let response: Vec<f32> = (0..len)
+                .map(|n| process(if n == 0 { 0.5 } else { 0.0 }))
+                .collect();
+            let energy = |range: &[f32]| range.iter().map(|y| y * y).sum::<f32>();
+            let early = energy(&response[..len / 4]);
+            let late = energy(&response[len * 3 / 4..]);
+            assert!(
+                late <= early * 1e-3 + 1e-12,
+                "{name} @ {sample_rate} Hz: late energy {late} vs early {early}"
+            );
+        }
+    }
+}
+
+/// 무음 꼬리의 비정규화 수 (FTZ 없이, 이식 가능한 상태 flush만으로):
+/// 출력이 비정규화 수가 되지 않고, 무음 구간 처리 시간이 잡음 구간보다 크게 늘지 않아야 함
+/// (x86에서 비정규화 연산은 수십-수백 배 느림, 측정 잡음을 감안해 기준은 3배)
+/// ScopedFtz 가 아무것도 안 하는 타깃에서도 출력이 정규 수로 유지됨을 보장
+#[test]
+fn test_silent_tail_has_no_denormal_slowdown() {
+    // FTZ / DAZ 가 켜져 있으면 이 테스트는 이식 가능한 flush 를 검증하지 못함
+    let tiny = black_box(1e-30f32) * black_box(1e-10f32);
+    assert!(tiny.is_subnormal(), "FTZ is enabled on the test thread");
+
+    let sample_rate = 48000.0;
+    let len = sample_rate as usize;
+    let signal: Vec<f32> = noise(len / 4).into_iter().cycle().take(len).collect();
+    let silence = vec![0.0; 2 * len];
+    for (name, mut process) in processors(sample_rate) {
+        // 청크별 샘플당 시간의 최솟값 (스케줄링 지연에 강함)
+        let mut time = |input: &[f32]| {
+            input
+                .chunks(4096)
+                .map(|chunk| {
+                    let start = Instant::now();
+                    for &x in chunk {
+                        let y = process(x);
+                        assert!(!y.is_subnormal(), "{name}: denormal output {y:e}");
+                    }
+                    start.elapsed().as_secs_f64() / chunk.len() as f64
+                })
+                .fold(f64::MAX, f64::min)
+        };
+        let busy = time(&signal);
+        // 앞 절반: 감쇠 구간 (상태가 비정규화 범위로 내려가는 중), 뒤: 측정
+        time(&silence[..len / 2]);
+        let silent = time(&silence);
+        assert!(
+            silent < busy * 3.0,
+            "{name}: silence {:.1} ns/sample vs signal {:.1} ns/sample",
+            silent * 1e9,
+            busy * 1e9
+        );
+    }
+}
@@ -0,0 +1,315 @@
+//! nih-plug 플러그인 헤드리스 테스트 하네스
+//! 호스트 없이 initialize -> 파라미터 자동화 -> 가변 크기 버퍼 처리 -> 출력 캡처
+//!
+//! ProcessContext는 nih-plug 외부에서 만들 수 없으므로 플러그인이 제공하는
+//! 컨텍스트 없는 렌더 함수(`render`)를 호출한다.
+
+use dsp_core::{ParamEdits, ScopedFtz};
+use nih_plug::prelude::*;
+use std::cell::Cell;
+
+/// 플러그인의 컨텍스트 없는 버퍼 처리 (지연이 바뀌면 Some(새 지연))
+pub type RenderFn<P> = fn(&mut P, &mut Buffer, &mut AuxiliaryBuffers) -> Option<u32>;
+
+/// initialize 중 보고된 지연만 기록하는 InitContext
+struct HarnessInitContext {
+    latency: Cell<u32>,
+}
+
+impl<P: Plugin> InitContext<P> for HarnessInitContext {
+    fn plugin_api(&self) -> PluginApi {
+        PluginApi::Standalone
+    }
+
+    /// 백그라운드 작업은 실행하지 않음
+    fn execute(&self, _task: P::BackgroundTask) {}
+
+    fn set_latency_samples(&self, samples: u32) {
+        self.latency.set(samples);
+    }
+
+    fn set_current_voice_capacity(&self, _capacity: u32) {}
+}
+
+pub struct Harness<P: Plugin> {
+    plugin: P,
+    render: RenderFn<P>,
+    sample_rate: f32,
+    max_buffer_size: usize,
+    inputs: usize,
+    outputs: usize,
+    aux_inputs: usize,
+    latency: u32,
+    flush_denormals: bool,
+}
+
+impl<P: Plugin> Harness<P> {
+    pub fn new(plugin: P, render: RenderFn<P>) -> Self {
+        Self {
+            plugin,
+            render,
+            sample_rate: 0.0,
+            max_buffer_size: 0,
+            inputs: 0,
+            outputs: 0,
+            aux_inputs: 0,
+            latency: 0,
+            flush_denormals: true,
+        }
+    }
+
+    /// 처리 중 FTZ/DAZ 사용 여부 (기본: 사용, nih-plug 래퍼와 동일)
+    /// 끄면 DSP 코드 자체의 비정규화 수 처리를 검사할 수 있음
+    pub fn set_flush_denormals(&mut self, flush: bool) {
+        self.flush_denormals = flush;
+    }
+
+    /// 호스트와 같은 순서로 초기화: 스무더 초기화 -> initialize -> reset
+    pub fn initialize(
+        &mut self,
+        layout: &AudioIOLayout,
+        sample_rate: f32,
+        max_buffer_size: usize,
+        process_mode: ProcessMode,
+    ) -> bool {
+        self.sample_rate = sample_rate;
+        self.max_buffer_size = max_buffer_size.max(1);
+        self.inputs = layout.main_input_channels.map_or(0, NonZeroU32::get) as usize;
+        self.outputs = layout.main_output_channels.map_or(0, NonZeroU32::get) as usize;
+        self.aux_inputs = layout
+            .aux_input_ports
+            .first()
+            .map_or(0, |channels| channels.get() as usize);
+
+        for (_, param, _) in self.plugin.params().param_map() {
+            // SAFETY: 파라미터는 self.plugin이 소유한 Arc<Params> 안에 있음
+            unsafe { param.update_smoother(sample_rate, true) };
+        }
+
+        let config = BufferConfig {
+            sample_rate,
+            min_buffer_size: None,
+            max_buffer_size: self.max_buffer_size as u32,
+            process_mode,
+        };
+        let mut context = HarnessInitContext {
+            latency: Cell::new(0),
+        };
+        let initialized = self.plugin.initialize(layout, &config, &mut context);
+        self.latency = context.latency.get();
+        self.plugin.reset();
+        initialized
+    }
+
+    /// 정규화 값(0.0-1.0)으로 파라미터 변경 (다음 버퍼부터 스무딩 적용)
+    pub fn set_parameter(&mut self, id: &str, normalized: f32) {
+        let params = self.plugin.params();
+        let param = params
+            .param_map()
+            .into_iter()
+            .find(|(param_id, _, _)| param_id == id)
+            .map(|(_, param, _)| param)
+            .unwrap_or_else(|| panic!("unknown parameter id {id:?}"));
+        // SAFETY: 위와 같음
+        unsafe {
+            param.set_normalized_value(normalized);
+            param.update_smoother(self.sample_rate, false);
+        }
+    }
+
+    /// 현재 정규화 값 (MIDI 등 플러그인 내부 변경 확인용)
+    pub fn parameter(&self, id: &str) -> f32 {
+        let params = self.plugin.params();
+        let param = params
+            .param_map()
+            .into_iter()
+            .find(|(param_id, _, _)| param_id == id)
+            .map(|(_, param, _)| param)
+            .unwrap_or_else(|| panic!("unknown parameter id {id:?}"));
+        // SAFETY: 위와 같음
+        unsafe { param.unmodulated_normalized_value() }
+    }
+
+    /// 실제 값(dB, Hz 등)으로 파라미터 변경
+    pub fn set_parameter_plain(&mut self, id: &str, plain: f32) {
+        let params = self.plugin.params();
+        let normalized = params
+            .param_map()
+            .into_iter()
+            .find(|(param_id, _, _)| param_id == id)
+            // SAFETY: 위와 같음
+            .map(|(_, param, _)| unsafe { param.preview_normalized(plain) })
+            .unwrap_or_else(|| panic!("unknown parameter id {id:?}"));
+        self.set_parameter(id, normalized);
+    }
+
+    /// 플러그인이 오디오 스레드에서 기록한 파라미터 변경을 호스트처럼 반영 (에디터의 보고 대신)
+    pub fn report_edits(&mut self, edits: &ParamEdits) {
+        let params = self.plugin.params();
+        let param_map = params.param_map();
+        edits.drain(|index, normalized| {
+            if let Some((_, param, _)) = param_map.get(index) {
+                // SAFETY: 위와 같음
+                unsafe {
+                    param.set_normalized_value(normalized);
+                    param.update_smoother(self.sample_rate, false);
+                }
+            }
+        });
+    }
+
+    /// 입력 채널들을 block_sizes 순서(반복)로 잘라 처리하고 출력 채널을 반환
+    /// 출력 채널 수는 레이아웃 기준, 부족한 입력 채널은 0으로 채움
+    pub fn process(&mut self, input: &[Vec<f32>], block_sizes: &[usize]) -> Vec<Vec<f32>> {
+        self.process_with_sidechain(input, &[], block_sizes)
+    }
+
+    /// 사이드체인(첫 번째 보조 입력) 포함 처리
+    pub fn process_with_sidechain(
+        &mut self,
+        input: &[Vec<f32>],
+        sidechain: &[Vec<f32>],
+        block_sizes: &[usize],
+    ) -> Vec<Vec<f32>> {
+        let total = input.iter().map(Vec::len).max().unwrap_or(0);
+        let mut output: Vec<Vec<f32>> = vec![Vec::with_capacity(total); self.outputs];
+        let mut sizes = block_sizes.iter().copied().cycle();
+        let mut position = 0;
+        while position < total {
+            let len = sizes
+                .next()
+                .unwrap_or(self.max_buffer_size)
+                .clamp(1, self.max_buffer_size)
+                .min(total - position);
+            let range = position..position + len;
+            let mut main = block(input, self.inputs.min(self.outputs), self.outputs, &range);
+            let mut aux = block(sidechain, self.aux_inputs, self.aux_inputs, &range);
+            self.render_block(&mut main, &mut aux);
+            for (channel, samples) in output.iter_mut().zip(&main) {
+                channel.extend_from_slice(samples);
+            }
+            position += len;
+        }
+        output
+    }
+
+    /// 출력 채널 수만큼의 블록 하나를 제자리 처리 (벤치마크용, 출력 복사 없음)
+    /// 블록 길이는 max_buffer_size 이하여야 함
+    pub fn process_block(&mut self, block: &mut [Vec<f32>]) {
+        assert_eq!(block.len(), self.outputs, "block must have one buffer per output channel");
+        assert!(block.first().map_or(0, Vec::len) <= self.max_buffer_size);
+        self.render_block(block, &mut []);
+    }
+
+    fn render_block(&mut self, main: &mut [Vec<f32>], aux: &mut [Vec<f32>]) {
+        let len = main.first().map_or(0, Vec::len);
+        let mut buffer = Buffer::default();
+        // SAFETY: 슬라이스는 이 함수가 끝날 때까지 유효하고 모두 len 길이
+        unsafe {
+            buffer.set_slices(len, |slices| {
+                slices.clear();
+                slices.extend(main.iter_mut().map(|channel| channel.as_mut_slice()));
+            });
+        }
+
+        let mut aux_buffers = Vec::new();
+        if !aux.is_empty() {
+            let mut sidechain = Buffer::default();
+            // SAFETY: 위와 같음
+            unsafe {
+                sidechain.set_slices(len, |slices| {
+                    slices.clear();
+                    slices.extend(aux.iter_mut().map(|channel| channel.as_mut_slice()));
+                });
+            }
+            aux_buffers.push(sidechain);
+        }
+        let mut aux = AuxiliaryBuffers {
+            inputs: aux_buffers.as_mut_slice(),
+            outputs: &mut [],
+        };
+
+        let _ftz = self.flush_denormals.then(ScopedFtz::enable);
+        if let Some(latency) = (self.render)(&mut self.plugin, &mut buffer, &mut aux) {
+            self.latency = latency;
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.plugin.reset();
+    }
+
+    /// 플러그인이 마지막으로 보고한 지연 (샘플)
+    pub fn latency(&self) -> u32 {
+        self.latency
+    }
+
+    pub fn plugin(&self) -> &P {
+        &self.plugin
+    }
+
+    /// 렌더 함수 밖의 플러그인 API 호출용 (MIDI 이벤트 등)
+    pub fn plugin_mut(&mut self) -> &mut P {
+        &mut self.plugin
+    }
+}
+
+/// range 구간을 channels 개 채널로 복사 (source 채널 이후는 0)
+fn block(
+    source: &[Vec<f32>],
+    used: usize,
+    channels: usize,
+    range: &std::ops::Range<usize>,
+) -> Vec<Vec<f32>> {
+    (0..channels)
+        .map(|index| match source.get(index).filter(|_| index < used) {
+            Some(samples) => range
+                .clone()
+                .map(|n| samples.get(n).copied().unwrap_or(0.0))
+                .collect(),
+            None => vec![0.0; range.len()],
+        })
+        .collect()
+}
+
+/// 모든 샘플이 유한하고 비정규화 수(denormal)가 아닌지 확인
+pub fn assert_finite_normal(output: &[Vec<f32>]) {
+    for (channel, samples) in output.iter().enumerate() {
+        for (n, sample) in samples.iter().enumerate() {
+            assert!(
+                sample.is_finite(),
+                "channel {channel} sample {n} is {sample}"
+            );
+            assert!(
+                !sample.is_subnormal(),
+                "channel {channel} sample {n} is denormal ({sample:e})"
+            );
+        }
+    }
+}
+
+/// 두 출력의 최대 절대 차이
+pub fn max_difference(a: &[Vec<f32>], b: &[Vec<f32>]) -> f32 {
+    a.iter()
+        .zip(b)
+        .flat_map(|(a, b)| a.iter().zip(b).map(|(x, y)| (x - y).abs()))
+        .fold(0.0, f32::max)
+}
+
+/// 테스트 신호: 정현파
+pub fn sine(freq: f32, sample_rate: f32, len: usize, amplitude: f32) -> Vec<f32> {
+    (0..len)
+        .map(|n| amplitude * (2.0 * std::f32::consts::PI * freq * n as f32 / sample_rate).sin())
+        .collect()
+}
+
+/// 테스트 신호: 재현 가능한 백색 잡음 (LCG)
+pub fn noise(seed: u32, len: usize, amplitude: f32) -> Vec<f32> {
+    let mut state = seed.wrapping_mul(747_796_405).wrapping_add(2_891_336_453);
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            amplitude * ((state >> 8) as f32 / (1u32 << 24) as f32 * 2.0 - 1.0)
+        })
+        .collect()
+}
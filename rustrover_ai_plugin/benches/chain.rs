@@ -0,0 +1,67 @@
+//! 전체 AI 플러그인 체인 벤치마크 (헤드리스 하네스, 스테레오)
+//! cargo bench -p rustrover_ai_plugin --bench chain
+//!
+//! ID 형식은 dsp-core 벤치마크와 같음: `<체인>/<샘플레이트>/<블록 크기>` (샘플 = 프레임)
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use nih_plug::prelude::*;
+use plugin_harness::{noise, sine, Harness};
+use rustrover_ai_plugin::RustroverAiPlugin;
+use std::time::Duration;
+
+const SAMPLE_RATES: [f32; 3] = [44100.0, 48000.0, 96000.0];
+const BLOCK_SIZES: [usize; 3] = [64, 512, 2048];
+
+fn harness(sample_rate: f32, block: usize, restoration: bool) -> Harness<RustroverAiPlugin> {
+    let mut harness = Harness::new(RustroverAiPlugin::default(), RustroverAiPlugin::render);
+    let stereo = &RustroverAiPlugin::AUDIO_IO_LAYOUTS[0];
+    assert!(harness.initialize(stereo, sample_rate, block, ProcessMode::Realtime));
+    if restoration {
+        for id in ["rumble", "dehum", "declick", "denoise"] {
+            harness.set_parameter(id, 1.0);
+        }
+    }
+    harness
+}
+
+fn bench_chain(c: &mut Criterion, name: &str, restoration: bool) {
+    let mut group = c.benchmark_group(name);
+    group
+        .warm_up_time(Duration::from_millis(500))
+        .measurement_time(Duration::from_secs(2));
+    for sample_rate in SAMPLE_RATES {
+        for block in BLOCK_SIZES {
+            let input: Vec<Vec<f32>> = (0..2)
+                .map(|channel| {
+                    let tone = sine(220.0 * (channel + 1) as f32, sample_rate, block, 0.5);
+                    let hiss = noise(channel + 1, block, 0.05);
+                    tone.iter().zip(&hiss).map(|(a, b)| a + b).collect()
+                })
+                .collect();
+            let mut buffers = input.clone();
+            let mut harness = harness(sample_rate, block, restoration);
+            group.throughput(Throughput::Elements(block as u64));
+            group.bench_function(
+                BenchmarkId::from_parameter(format!("{sample_rate}/{block}")),
+                |b| {
+                    b.iter(|| {
+                        for (buffer, input) in buffers.iter_mut().zip(&input) {
+                            buffer.copy_from_slice(input);
+                        }
+                        harness.process_block(&mut buffers);
+                        black_box(&buffers);
+                    })
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+fn bench_ai_chain(c: &mut Criterion) {
+    bench_chain(c, "ai_chain", false);
+    bench_chain(c, "ai_chain_restoration", true);
+}
+
+criterion_group!(benches, bench_ai_chain);
+criterion_main!(benches);
@@ -0,0 +1,452 @@
+//! 플러그인 에디터 (nih_plug_egui)
+//! 파라미터 노브 / 프리셋 선택 / Velocity 미터 / 체인 주파수 응답 / 게인 리덕션 미터
+//! 프리셋 목록은 내장 프리셋 + 사용자 프리셋 디렉터리 (`cli preset import` 로 설치한 파일)
+//! 노브와 토글은 우클릭 메뉴로 MIDI CC Learn
+//! 오디오 스레드의 파라미터 변경(프리셋 적용)은 프레임마다 호스트에 보고 (창이 닫혀 있으면 대기)
+
+use crate::{
+    DenoiseProfile, OfflineQuality, PluginParams, PresetType, RealtimeQuality, VelocitySource,
+};
+use dsp_core::preset_file::{PresetEntry, PresetSource};
+use dsp_core::{
+    chain_response_db, AtomicF32, ChainPreset, Meters, ParamEdits, PresetBank, ProcessingPath,
+};
+use nih_plug::prelude::*;
+use nih_plug_egui::egui::{
+    self, Align2, Color32, FontId, Pos2, Rect, Sense, Shape, Stroke, Ui, Vec2,
+};
+use nih_plug_egui::{create_egui_editor, EguiState};
+use std::sync::Arc;
+
+const WIDTH: u32 = 760;
+const HEIGHT: u32 = 680;
+const KNOB_SIZE: f32 = 52.0;
+/// 노브 전체 범위에 해당하는 드래그 거리 (px)
+const KNOB_DRAG_PX: f32 = 200.0;
+/// 게인 리덕션 미터 범위 (dB)
+const GR_RANGE_DB: f32 = 12.0;
+/// 주파수 응답 표시 범위
+const RESPONSE_MIN_HZ: f32 = 20.0;
+const RESPONSE_MAX_HZ: f32 = 20000.0;
+const RESPONSE_RANGE_DB: f32 = 18.0;
+const RESPONSE_POINTS: usize = 128;
+
+const ACCENT: Color32 = Color32::from_rgb(230, 160, 60);
+const TRACK: Color32 = Color32::from_gray(60);
+const MARKER: Color32 = Color32::from_rgb(120, 200, 255);
+
+pub(crate) fn default_state() -> Arc<EguiState> {
+    EguiState::from_size(WIDTH, HEIGHT)
+}
+
+/// 에디터 생성 (창은 호스트가 열 때만 만들어지므로 헤드리스 빌드/호스트에서도 안전)
+pub(crate) fn create(
+    params: Arc<PluginParams>,
+    meters: Arc<Meters>,
+    sample_rate: Arc<AtomicF32>,
+    edits: Arc<ParamEdits>,
+) -> Option<Box<dyn Editor>> {
+    let editor_state = params.editor_state.clone();
+    let ids: Vec<(ParamPtr, String)> = params
+        .param_map()
+        .into_iter()
+        .map(|(id, param, _)| (param, id))
+        .collect();
+    create_egui_editor(
+        editor_state,
+        scan_presets(),
+        |_, _| {},
+        move |egui_ctx, setter, bank| {
+            report_edits(setter, &edits, &ids);
+            egui::CentralPanel::default().show(egui_ctx, |ui| {
+                let midi = MidiLearn {
+                    params: &params,
+                    ids: &ids,
+                };
+                draw(ui, setter, &midi, bank, &meters, sample_rate.load());
+            });
+            // 미터는 오디오 스레드에서 계속 갱신됨
+            egui_ctx.request_repaint();
+        },
+    )
+}
+
+/// 오디오 스레드에서 바꾼 값을 호스트 파라미터 변경 경로로 보고 (호스트 자동화 / UI 갱신)
+fn report_edits(setter: &ParamSetter, edits: &ParamEdits, ids: &[(ParamPtr, String)]) {
+    edits.drain(|index, normalized| {
+        if let Some(&(param, _)) = ids.get(index) {
+            // SAFETY: 포인터는 params가 소유한 파라미터를 가리키고, 에디터 스레드에서 호출
+            unsafe {
+                setter.raw_context.raw_begin_set_parameter(param);
+                setter
+                    .raw_context
+                    .raw_set_parameter_normalized(param, normalized);
+                setter.raw_context.raw_end_set_parameter(param);
+            }
+        }
+    });
+}
+
+/// 내장 + 사용자 프리셋 목록 (읽을 수 없는 파일은 로그만 남기고 건너뜀)
+fn scan_presets() -> PresetBank {
+    let (bank, errors) = PresetBank::with_user_presets();
+    for (path, err) in errors {
+        nih_log!("skipped preset {}: {err}", path.display());
+    }
+    bank
+}
+
+/// 프리셋 목록 선택: 내장 프리셋은 Preset 파라미터, 파일은 User 슬롯에 로드 후 User 선택
+fn preset_selector(
+    ui: &mut Ui,
+    setter: &ParamSetter,
+    params: &PluginParams,
+    bank: &mut PresetBank,
+) {
+    let current = params.preset.value();
+    let user_name = params
+        .user_preset
+        .read()
+        .ok()
+        .and_then(|slot| slot.as_ref().map(|file| file.name.clone()));
+    let selected_text = match (current, &user_name) {
+        (PresetType::User, Some(name)) => name.clone(),
+        _ => PresetType::variants()[current.to_index()].to_string(),
+    };
+
+    let mut chosen: Option<PresetEntry> = None;
+    egui::ComboBox::from_label(params.preset.name())
+        .selected_text(selected_text)
+        .show_ui(ui, |ui| {
+            for entry in bank.entries() {
+                let selected = match &entry.source {
+                    PresetSource::Builtin => builtin_type(&entry.name) == Some(current),
+                    PresetSource::File(_) => {
+                        current == PresetType::User && user_name.as_deref() == Some(&entry.name)
+                    }
+                };
+                if ui.selectable_label(selected, entry.name.as_str()).clicked() {
+                    chosen = Some(entry.clone());
+                }
+            }
+        });
+    if ui.button("Rescan").clicked() {
+        *bank = scan_presets();
+    }
+
+    let Some(entry) = chosen else {
+        return;
+    };
+    let preset = match entry.source {
+        PresetSource::Builtin => builtin_type(&entry.name).unwrap_or(PresetType::User),
+        PresetSource::File(_) => {
+            params.load_user_entry(&entry);
+            PresetType::User
+        }
+    };
+    setter.begin_set_parameter(&params.preset);
+    setter.set_parameter(&params.preset, preset);
+    setter.end_set_parameter(&params.preset);
+}
+
+/// 내장 프리셋 이름 ("vinyl") -> PresetType
+fn builtin_type(name: &str) -> Option<PresetType> {
+    PresetType::variants()
+        .iter()
+        .position(|variant| variant.eq_ignore_ascii_case(name))
+        .map(PresetType::from_index)
+}
+
+fn draw(
+    ui: &mut Ui,
+    setter: &ParamSetter,
+    midi: &MidiLearn,
+    bank: &mut PresetBank,
+    meters: &Meters,
+    sample_rate: f32,
+) {
+    let params = midi.params;
+    let snapshot = meters.snapshot();
+    // 현재 파라미터 값으로 구성한 체인
+    let chain = params.chain(|_| None);
+
+    ui.horizontal(|ui| {
+        preset_selector(ui, setter, params, bank);
+        toggle(ui, setter, midi, &params.auto_velocity);
+        enum_selector::<VelocitySource>(ui, setter, &params.velocity_source);
+        toggle(ui, setter, midi, &params.midi_velocity);
+    });
+    ui.horizontal(|ui| {
+        enum_selector::<RealtimeQuality>(ui, setter, &params.realtime_quality);
+        enum_selector::<OfflineQuality>(ui, setter, &params.offline_quality);
+    });
+    ui.separator();
+
+    ui.columns(2, |columns| {
+        let ui = &mut columns[0];
+        ui.label("Chain");
+        ui.horizontal(|ui| {
+            knob(ui, setter, midi, &params.drive);
+            knob(ui, setter, midi, &params.drywet);
+            knob(ui, setter, midi, &params.riaa_intensity);
+            knob(ui, setter, midi, &params.parallel_mix);
+        });
+        ui.horizontal(|ui| {
+            knob(ui, setter, midi, &params.hardness);
+            knob(ui, setter, midi, &params.magnetic_threshold);
+            knob(ui, setter, midi, &params.parallel_threshold);
+        });
+        ui.horizontal(|ui| {
+            knob(ui, setter, midi, &params.lowpass_hz);
+            knob(ui, setter, midi, &params.lowpass_q);
+            knob(ui, setter, midi, &params.limiter_ceiling);
+            knob(ui, setter, midi, &params.limiter_release);
+        });
+
+        ui.separator();
+        ui.label("Restoration");
+        ui.horizontal(|ui| {
+            toggle(ui, setter, midi, &params.rumble);
+            knob(ui, setter, midi, &params.rumble_cutoff);
+            toggle(ui, setter, midi, &params.dehum);
+            knob(ui, setter, midi, &params.hum_harmonics);
+        });
+        ui.horizontal(|ui| {
+            toggle(ui, setter, midi, &params.declick);
+            knob(ui, setter, midi, &params.declick_sensitivity);
+            knob(ui, setter, midi, &params.declick_max_ms);
+        });
+        ui.horizontal(|ui| {
+            toggle(ui, setter, midi, &params.denoise);
+            knob(ui, setter, midi, &params.denoise_amount);
+            knob(ui, setter, midi, &params.denoise_floor);
+            enum_selector::<DenoiseProfile>(ui, setter, &params.denoise_profile);
+        });
+
+        let ui = &mut columns[1];
+        ui.label(match snapshot.path {
+            ProcessingPath::Riaa => "Velocity (RIAA path)",
+            ProcessingPath::Magnetic => "Velocity (Magnetic path)",
+        });
+        velocity_meter(ui, snapshot.velocity, &chain);
+        ui.label(format!(
+            "Gain reduction {:.1} dB",
+            snapshot.gain_reduction_db
+        ));
+        gain_reduction_meter(ui, snapshot.gain_reduction_db);
+
+        ui.separator();
+        ui.label("Response");
+        response_curve(ui, &chain, sample_rate);
+    });
+}
+
+/// 세로 드래그 노브 (더블클릭: 기본값)
+fn knob(ui: &mut Ui, setter: &ParamSetter, midi: &MidiLearn, param: &impl Param) {
+    ui.vertical(|ui| {
+        let (rect, response) =
+            ui.allocate_exact_size(Vec2::splat(KNOB_SIZE), Sense::click_and_drag());
+        let mut value = param.unmodulated_normalized_value();
+
+        if response.drag_started() {
+            setter.begin_set_parameter(param);
+        }
+        if response.dragged() {
+            value = (value - response.drag_delta().y / KNOB_DRAG_PX).clamp(0.0, 1.0);
+            setter.set_parameter_normalized(param, value);
+        }
+        if response.drag_stopped() {
+            setter.end_set_parameter(param);
+        }
+        if response.double_clicked() {
+            value = param.default_normalized_value();
+            setter.begin_set_parameter(param);
+            setter.set_parameter_normalized(param, value);
+            setter.end_set_parameter(param);
+        }
+
+        // 7시 -> 5시 방향 270도
+        let painter = ui.painter();
+        let center = rect.center();
+        let radius = rect.width() * 0.4;
+        let start = 0.75 * std::f32::consts::PI;
+        let angle = start + value * 1.5 * std::f32::consts::PI;
+        let point = |angle: f32| center + Vec2::angle(angle) * radius;
+        painter.circle_stroke(center, radius, Stroke::new(3.0, TRACK));
+        let arc: Vec<Pos2> = (0..=32)
+            .map(|i| point(start + (angle - start) * i as f32 / 32.0))
+            .collect();
+        painter.add(Shape::line(arc, Stroke::new(3.0, ACCENT)));
+        painter.line_segment([center, point(angle)], Stroke::new(2.0, Color32::WHITE));
+
+        ui.label(param.name());
+        ui.small(param.normalized_value_to_string(value, true));
+        midi.attach(ui, &response, param);
+    });
+}
+
+fn toggle(ui: &mut Ui, setter: &ParamSetter, midi: &MidiLearn, param: &BoolParam) {
+    let mut value = param.value();
+    let response = ui.checkbox(&mut value, param.name());
+    if response.changed() {
+        setter.begin_set_parameter(param);
+        setter.set_parameter(param, value);
+        setter.end_set_parameter(param);
+    }
+    midi.attach(ui, &response, param);
+}
+
+/// 위젯의 파라미터 ID 조회 + CC Learn 메뉴
+struct MidiLearn<'a> {
+    params: &'a PluginParams,
+    ids: &'a [(ParamPtr, String)],
+}
+
+impl MidiLearn<'_> {
+    fn id(&self, param: &impl Param) -> Option<&str> {
+        let ptr = param.as_ptr();
+        self.ids
+            .iter()
+            .find(|(param, _)| *param == ptr)
+            .map(|(_, id)| id.as_str())
+    }
+
+    /// 연결된 CC 표시 + 우클릭 메뉴 (Learn / 해제)
+    fn attach(&self, ui: &mut Ui, response: &egui::Response, param: &impl Param) {
+        let Some(id) = self.id(param) else {
+            return;
+        };
+        let (cc, learning) = {
+            let map = self
+                .params
+                .midi_map
+                .read()
+                .unwrap_or_else(|e| e.into_inner());
+            (map.cc_for(id), map.learning() == Some(id))
+        };
+        if learning {
+            ui.small("Learning...");
+        } else if let Some(cc) = cc {
+            ui.small(format!("CC {cc}"));
+        }
+
+        response.context_menu(|ui| {
+            if learning {
+                if ui.button("Cancel MIDI Learn").clicked() {
+                    self.params
+                        .midi_map
+                        .write()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .cancel_learn();
+                    ui.close_menu();
+                }
+            } else if ui.button("MIDI Learn").clicked() {
+                self.params.learn_cc(id);
+                ui.close_menu();
+            }
+            if cc.is_some() && ui.button("Clear MIDI CC").clicked() {
+                self.params
+                    .midi_map
+                    .write()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .clear(id);
+                ui.close_menu();
+            }
+        });
+    }
+}
+
+fn enum_selector<E: Enum + PartialEq + 'static>(
+    ui: &mut Ui,
+    setter: &ParamSetter,
+    param: &EnumParam<E>,
+) {
+    let current = param.value();
+    egui::ComboBox::from_label(param.name())
+        .selected_text(E::variants()[current.to_index()])
+        .show_ui(ui, |ui| {
+            for (index, name) in E::variants().iter().enumerate() {
+                let variant = E::from_index(index);
+                if ui.selectable_label(variant == current, *name).clicked() {
+                    setter.begin_set_parameter(param);
+                    setter.set_parameter(param, variant);
+                    setter.end_set_parameter(param);
+                }
+            }
+        });
+}
+
+fn bar(ui: &mut Ui) -> Rect {
+    let width = ui.available_width();
+    ui.allocate_exact_size(Vec2::new(width, 14.0), Sense::hover())
+        .0
+}
+
+/// Velocity 막대 + Magnetic / Parallel 임계값 마커
+fn velocity_meter(ui: &mut Ui, velocity: f32, chain: &ChainPreset) {
+    let rect = bar(ui);
+    let painter = ui.painter();
+    painter.rect_filled(rect, 2.0, TRACK);
+    let mut fill = rect;
+    fill.set_width(rect.width() * velocity.clamp(0.0, 1.0));
+    painter.rect_filled(fill, 2.0, ACCENT);
+
+    for (threshold, label) in [
+        (chain.magnetic_threshold, "M"),
+        (chain.parallel_threshold, "P"),
+    ] {
+        let x = rect.left() + rect.width() * threshold.clamp(0.0, 1.0);
+        painter.line_segment(
+            [
+                Pos2::new(x, rect.top() - 2.0),
+                Pos2::new(x, rect.bottom() + 2.0),
+            ],
+            Stroke::new(2.0, MARKER),
+        );
+        painter.text(
+            Pos2::new(x, rect.bottom() + 2.0),
+            Align2::CENTER_TOP,
+            label,
+            FontId::proportional(10.0),
+            MARKER,
+        );
+    }
+    ui.add_space(12.0);
+}
+
+/// 게인 리덕션 (오른쪽에서 왼쪽으로 채움)
+fn gain_reduction_meter(ui: &mut Ui, gain_reduction_db: f32) {
+    let rect = bar(ui);
+    let painter = ui.painter();
+    painter.rect_filled(rect, 2.0, TRACK);
+    let amount = (gain_reduction_db / GR_RANGE_DB).clamp(0.0, 1.0);
+    let mut fill = rect;
+    fill.set_left(rect.right() - rect.width() * amount);
+    painter.rect_filled(fill, 2.0, Color32::from_rgb(220, 70, 70));
+}
+
+/// 로그 주파수 축의 체인 응답 곡선 (±RESPONSE_RANGE_DB)
+fn response_curve(ui: &mut Ui, chain: &ChainPreset, sample_rate: f32) {
+    let width = ui.available_width();
+    let (rect, _) = ui.allocate_exact_size(Vec2::new(width, 160.0), Sense::hover());
+    let painter = ui.painter();
+    painter.rect_filled(rect, 2.0, Color32::from_gray(25));
+    painter.line_segment(
+        [rect.left_center(), rect.right_center()],
+        Stroke::new(1.0, TRACK),
+    );
+
+    let max_hz = RESPONSE_MAX_HZ.min(sample_rate * 0.5);
+    let span = (max_hz / RESPONSE_MIN_HZ).ln();
+    let points: Vec<Pos2> = (0..RESPONSE_POINTS)
+        .map(|i| {
+            let t = i as f32 / (RESPONSE_POINTS - 1) as f32;
+            let freq = RESPONSE_MIN_HZ * (span * t).exp();
+            let db = chain_response_db(chain, sample_rate, freq)
+                .clamp(-RESPONSE_RANGE_DB, RESPONSE_RANGE_DB);
+            let y = rect.center().y - db / RESPONSE_RANGE_DB * rect.height() * 0.5;
+            Pos2::new(rect.left() + t * rect.width(), y)
+        })
+        .collect();
+    painter.add(Shape::line(points, Stroke::new(2.0, ACCENT)));
+}
@@ -1,38 +1,155 @@
 // 2026형 VST3 플러그인 - 특허 기반 Velocity-Adaptive DSP
 // Advanced FeatureAnalyzer + RIAA/Parallel Crossfade + Sample-Rate Specific
 
+use nih_plug::params::persist::PersistentField;
 use nih_plug::prelude::*;
+use nih_plug_egui::EguiState;
 use dsp_core::{
-    MagneticEQ,
-    ParallelFilterAdvanced,
-    RIAAEQAdvanced,
+    AtomicF32,
+    ChainPreset,
+    ChainRamps,
+    DelayLine,
+    FieldRange,
+    LevelMeter,
+    MeterSnapshot,
+    Meters,
+    MidiMap,
+    NoiseProfileMode,
+    ParamEdits,
+    ParamRamp,
+    PresetBank,
+    PresetChain,
+    PresetError,
+    PresetFile,
+    Processor,
+    ProcessingPath,
+    Quality,
+    Restoration,
+    ScopedFtz,
     VelocityAnalyzer,
-    mix_dry_wet,
 };
-use std::{array, sync::Arc};
+use dsp_core::preset_file::PresetEntry;
+use std::{
+    ops::Range,
+    path::Path,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+mod editor;
 
 pub struct RustroverAiPlugin {
     params: Arc<PluginParams>,
     sample_rate: f32,
-    channels: [ChannelDsp; 2],
+    /// 협상된 출력 채널 수만큼 initialize에서 할당
+    channels: Vec<ChannelDsp>,
+    /// 메인 입력 채널 수 (출력보다 적으면 업믹스)
+    input_channels: usize,
+    /// 마지막으로 선택된 프리셋 (바뀌면 모든 필드를 파라미터로 보냄)
+    selected_preset: ChainPreset,
+    /// 현재 채널 상태에 적용된 체인 설정 (파라미터 값)
+    active_chain: ChainPreset,
+    ramps: RampBuffers,
+    /// 에디터/호스트와 공유하는 미터 (오디오 스레드에서 원자적으로 갱신)
+    meters: Arc<Meters>,
+    input_meter: LevelMeter,
+    output_meter: LevelMeter,
+    /// 에디터의 주파수 응답 계산용 샘플레이트
+    shared_sample_rate: Arc<AtomicF32>,
+    /// 호스트에 마지막으로 보고한 지연 (샘플)
+    reported_latency: u32,
+    /// 실시간 / 오프라인 렌더링 구분 (initialize에서 갱신)
+    process_mode: ProcessMode,
+    /// 현재 채널 상태에 적용된 품질
+    quality: Quality,
+    /// param_map() 순서의 파라미터 ID / 포인터 (ParamEdits 인덱스)
+    param_ptrs: Vec<(String, ParamPtr)>,
+    /// CC 번호 -> 파라미터 인덱스 (매핑이 바뀐 뒤 첫 CC에서 다시 계산)
+    cc_params: [Option<usize>; 128],
+    /// 오디오 스레드에서 바꾼 파라미터 값 (에디터가 호스트에 보고, 인덱스 = param_ptrs 순서)
+    edits: Arc<ParamEdits>,
+    /// ChainPreset::fields() 순서의 프리셋 파라미터 인덱스
+    preset_params: [usize; PRESET_FIELDS],
+    /// 지난 버퍼에서 보고 대기 중이던 프리셋 필드 값 (정규화)
+    preset_pending: [Option<f32>; PRESET_FIELDS],
+    /// 누르고 있는 노트 (비트 = 노트 번호)
+    held_notes: u128,
+    /// 마지막 Note On velocity (노트를 누르고 있는 동안만 Some)
+    note_velocity: Option<f32>,
+}
+
+/// 버퍼 단위 샘플별 파라미터 램프 (initialize에서 할당)
+#[derive(Default)]
+struct RampBuffers {
+    drive: Vec<f32>,
+    drywet: Vec<f32>,
+    riaa_intensity: Vec<f32>,
+    parallel_mix: Vec<f32>,
+}
+
+impl RampBuffers {
+    fn resize(&mut self, len: usize) {
+        for ramp in [
+            &mut self.drive,
+            &mut self.drywet,
+            &mut self.riaa_intensity,
+            &mut self.parallel_mix,
+        ] {
+            ramp.resize(len, 0.0);
+        }
+    }
 }
 
+/// 사이드체인 정렬 지연 최대값 (복원 단계 지연 이상)
+const SIDECHAIN_DELAY_MAX: usize = 4096;
+/// ChainPreset 필드 수 (필드마다 파라미터 하나)
+const PRESET_FIELDS: usize = 11;
+/// ChainPreset::fields() 안의 샘플별 램프 필드 위치
+const DRIVE_FIELD: usize = 0;
+const RIAA_FIELD: usize = 2;
+const PARALLEL_FIELD: usize = 3;
+const DRYWET_FIELD: usize = 4;
+/// Auto Velocity를 끄면 사용하는 고정 velocity (RIAA 경로)
+const FIXED_VELOCITY: f32 = 0.6;
+
 struct ChannelDsp {
-    riaa: RIAAEQAdvanced,
-    parallel: ParallelFilterAdvanced,
+    chain: PresetChain,
+    /// 같은 번호의 사이드체인 채널을 복원 단계 지연만큼 늦춤
+    sidechain_delay: DelayLine<SIDECHAIN_DELAY_MAX>,
+    /// 현재 구간의 사이드체인 velocity (None이면 메인 입력 분석)
+    key_velocity: Option<f32>,
 }
 
 impl ChannelDsp {
-    fn new(sample_rate: u32) -> Self {
-        Self {
-            riaa: RIAAEQAdvanced::new(sample_rate),
-            parallel: ParallelFilterAdvanced::new(0.0),
-        }
+    fn new(sample_rate: f32, preset: &ChainPreset) -> Self {
+        let mut dsp = Self {
+            chain: PresetChain::new(sample_rate, preset),
+            sidechain_delay: DelayLine::new(0),
+            key_velocity: None,
+        };
+        dsp.update_delays();
+        dsp
+    }
+
+    /// 처리 단계 지연이 바뀐 뒤 사이드체인 정렬 지연 갱신
+    /// Dry 신호는 복원 후에 분기하므로 사이드체인만 복원 단계 지연만큼 정렬
+    fn update_delays(&mut self) {
+        self.sidechain_delay.set_delay(self.chain.restoration_latency());
+    }
+
+    /// 품질 단계 적용, 지연이 바뀔 수 있음
+    fn set_quality(&mut self, quality: Quality) {
+        self.chain.set_quality(quality);
+        self.update_delays();
+    }
+
+    fn set_restoration(&mut self, restoration: &Restoration) {
+        self.chain.set_restoration(restoration);
+        self.update_delays();
     }
 
     fn reset(&mut self) {
-        self.riaa.reset();
-        self.parallel.reset();
+        self.chain.reset();
+        self.sidechain_delay.reset();
     }
 }
 
@@ -58,9 +175,188 @@ pub struct PluginParams {
     #[id = "parallel_mix"]
     pub parallel_mix: FloatParam,
 
+    /// 테이프 포화 하드니스
+    #[id = "hardness"]
+    pub hardness: FloatParam,
+
+    /// 이 velocity 미만이면 Magnetic 경로
+    #[id = "magnetic_threshold"]
+    pub magnetic_threshold: FloatParam,
+
+    /// 이 velocity 미만이면 Parallel HF Recovery 활성화
+    #[id = "parallel_threshold"]
+    pub parallel_threshold: FloatParam,
+
+    /// 리미터 천장 (linear)
+    #[id = "limiter_ceiling"]
+    pub limiter_ceiling: FloatParam,
+
+    /// 리미터 릴리즈
+    #[id = "limiter_release"]
+    pub limiter_release: FloatParam,
+
+    /// 출력 Lowpass 컷오프
+    #[id = "lowpass_hz"]
+    pub lowpass_hz: FloatParam,
+
+    /// 출력 Lowpass Q
+    #[id = "lowpass_q"]
+    pub lowpass_q: FloatParam,
+
     /// Auto Velocity Analysis (On/Off)
     #[id = "auto_velocity"]
     pub auto_velocity: BoolParam,
+
+    /// 서브소닉 럼블 필터 (On/Off)
+    #[id = "rumble"]
+    pub rumble: BoolParam,
+
+    /// 럼블 필터 차단 주파수
+    #[id = "rumble_cutoff"]
+    pub rumble_cutoff: FloatParam,
+
+    /// 험 제거 (50/60Hz 자동 검출)
+    #[id = "dehum"]
+    pub dehum: BoolParam,
+
+    /// 험 노치 고조파 수
+    #[id = "hum_harmonics"]
+    pub hum_harmonics: IntParam,
+
+    /// 클릭/크래클 제거 (On/Off, 꺼도 지연은 유지)
+    #[id = "declick"]
+    pub declick: BoolParam,
+
+    /// 클릭 검출 민감도
+    #[id = "declick_sensitivity"]
+    pub declick_sensitivity: FloatParam,
+
+    /// 보간할 최대 클릭 길이
+    #[id = "declick_max_ms"]
+    pub declick_max_ms: FloatParam,
+
+    /// 히스 제거 (On/Off, 꺼도 지연은 유지)
+    #[id = "denoise"]
+    pub denoise: BoolParam,
+
+    /// 과감산 계수 (클수록 강하게 제거)
+    #[id = "denoise_amount"]
+    pub denoise_amount: FloatParam,
+
+    /// 스펙트럼 바닥 (뮤지컬 노이즈 억제)
+    #[id = "denoise_floor"]
+    pub denoise_floor: FloatParam,
+
+    /// 잡음 프로파일: Auto / Learn / Hold
+    #[id = "denoise_profile"]
+    pub denoise_profile: EnumParam<DenoiseProfile>,
+
+    /// Velocity 분석 소스 (메인 입력 / 사이드체인)
+    #[id = "velocity_source"]
+    pub velocity_source: EnumParam<VelocitySource>,
+
+    /// 노트를 누르는 동안 Note On velocity가 분석값을 대체
+    #[id = "midi_velocity"]
+    pub midi_velocity: BoolParam,
+
+    /// 실시간 재생 품질
+    #[id = "realtime_quality"]
+    pub realtime_quality: EnumParam<RealtimeQuality>,
+
+    /// 오프라인 렌더링 품질 (호스트가 Offline 모드로 초기화할 때 사용)
+    #[id = "offline_quality"]
+    pub offline_quality: EnumParam<OfflineQuality>,
+
+    /// 사용자 프리셋 (PresetType::User) - 세션에 함께 저장
+    #[persist = "user_preset"]
+    pub user_preset: Arc<RwLock<Option<PresetFile>>>,
+    /// MIDI CC Learn 매핑 - 세션에 함께 저장
+    #[persist = "midi-map"]
+    pub midi_map: Arc<RwLock<MidiMap>>,
+    /// 에디터가 아직 호스트에 보고하지 않은 변경 - 에디터를 열지 않은 세션도 저장 후 복원
+    #[persist = "unreported-edits"]
+    pub unreported_edits: UnreportedEdits,
+    /// 에디터 창 크기 / 열림 상태
+    #[persist = "editor-state"]
+    pub editor_state: Arc<EguiState>,
+}
+
+impl PluginParams {
+    /// 프리셋 파일(.toml / .json)을 User 슬롯에 로드 (오디오 스레드 외부에서 호출)
+    pub fn load_user_preset(&self, path: &Path) -> Result<(), PresetError> {
+        let file = PresetFile::load(path)?;
+        self.set_user_preset(file);
+        Ok(())
+    }
+
+    /// 프리셋 뱅크 항목을 User 슬롯에 넣음 (에디터 프리셋 목록에서 선택)
+    pub fn load_user_entry(&self, entry: &PresetEntry) {
+        self.set_user_preset(PresetFile::new(entry.name.clone(), entry.chain));
+    }
+
+    fn set_user_preset(&self, file: PresetFile) {
+        *self.user_preset.write().unwrap_or_else(|e| e.into_inner()) = Some(file);
+    }
+
+    /// 선택된 프리셋의 체인 설정
+    /// User 슬롯이 잠겨 있으면 None (호출자가 이전 값 유지)
+    fn resolve_chain(&self, preset: PresetType) -> Option<ChainPreset> {
+        match preset {
+            PresetType::User => match self.user_preset.try_read() {
+                Ok(slot) => Some(slot.as_ref().map(|file| file.chain).unwrap_or_default()),
+                Err(_) => None,
+            },
+            builtin => Some(builtin.chain_preset()),
+        }
+    }
+
+    /// ChainPreset::fields() 순서의 프리셋 파라미터
+    pub fn preset_fields(&self) -> [&FloatParam; PRESET_FIELDS] {
+        [
+            &self.drive,
+            &self.hardness,
+            &self.riaa_intensity,
+            &self.parallel_mix,
+            &self.drywet,
+            &self.magnetic_threshold,
+            &self.parallel_threshold,
+            &self.limiter_ceiling,
+            &self.limiter_release,
+            &self.lowpass_hz,
+            &self.lowpass_q,
+        ]
+    }
+
+    /// 파라미터 값으로 구성한 체인 설정
+    /// pending(field): 아직 호스트에 보고되지 않은 정규화 값 (있으면 파라미터 값 대신 사용)
+    pub fn chain(&self, pending: impl Fn(usize) -> Option<f32>) -> ChainPreset {
+        let fields = self.preset_fields();
+        let value = |field: usize| match pending(field) {
+            Some(normalized) => fields[field].preview_plain(normalized),
+            None => fields[field].value(),
+        };
+        ChainPreset {
+            drive_db: value(0),
+            hardness: value(1),
+            riaa_intensity: value(2),
+            parallel_mix: value(3),
+            dry_wet: value(4),
+            magnetic_threshold: value(5),
+            parallel_threshold: value(6),
+            limiter_ceiling: value(7),
+            limiter_release_ms: value(8),
+            lowpass_hz: value(9),
+            lowpass_q: value(10),
+        }
+    }
+
+    /// 파라미터의 다음 CC를 Learn (에디터에서 호출)
+    pub fn learn_cc(&self, param_id: &str) {
+        self.midi_map
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .start_learn(param_id);
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -68,15 +364,16 @@ pub enum PresetType {
     Vinyl,
     Warm,
     Clean,
+    User,
 }
 
 impl Enum for PresetType {
     fn variants() -> &'static [&'static str] {
-        &["Vinyl", "Warm", "Clean"]
+        &["Vinyl", "Warm", "Clean", "User"]
     }
 
     fn ids() -> Option<&'static [&'static str]> {
-        Some(&["vinyl", "warm", "clean"])
+        Some(&["vinyl", "warm", "clean", "user"])
     }
 
     fn to_index(self) -> usize {
@@ -84,6 +381,7 @@ impl Enum for PresetType {
             PresetType::Vinyl => 0,
             PresetType::Warm => 1,
             PresetType::Clean => 2,
+            PresetType::User => 3,
         }
     }
 
@@ -91,22 +389,448 @@ impl Enum for PresetType {
         match index {
             0 => PresetType::Vinyl,
             1 => PresetType::Warm,
-            _ => PresetType::Clean,
+            2 => PresetType::Clean,
+            _ => PresetType::User,
+        }
+    }
+}
+
+/// VelocityAnalyzer 입력 선택
+/// 사이드체인 구간이 무음이면(연결되지 않은 입력은 0) 메인 입력 사용
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VelocitySource {
+    Main,
+    Sidechain,
+}
+
+impl Enum for VelocitySource {
+    fn variants() -> &'static [&'static str] {
+        &["Main", "Sidechain"]
+    }
+
+    fn ids() -> Option<&'static [&'static str]> {
+        Some(&["main", "sidechain"])
+    }
+
+    fn to_index(self) -> usize {
+        match self {
+            VelocitySource::Main => 0,
+            VelocitySource::Sidechain => 1,
+        }
+    }
+
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => VelocitySource::Main,
+            _ => VelocitySource::Sidechain,
+        }
+    }
+}
+
+/// 실시간 처리 품질
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RealtimeQuality {
+    Eco,
+    Normal,
+    High,
+}
+
+impl Enum for RealtimeQuality {
+    fn variants() -> &'static [&'static str] {
+        &["Eco", "Normal", "High"]
+    }
+
+    fn ids() -> Option<&'static [&'static str]> {
+        Some(&["eco", "normal", "high"])
+    }
+
+    fn to_index(self) -> usize {
+        match self {
+            RealtimeQuality::Eco => 0,
+            RealtimeQuality::Normal => 1,
+            RealtimeQuality::High => 2,
+        }
+    }
+
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => RealtimeQuality::Eco,
+            1 => RealtimeQuality::Normal,
+            _ => RealtimeQuality::High,
+        }
+    }
+}
+
+impl From<RealtimeQuality> for Quality {
+    fn from(quality: RealtimeQuality) -> Self {
+        match quality {
+            RealtimeQuality::Eco => Quality::Eco,
+            RealtimeQuality::Normal => Quality::Normal,
+            RealtimeQuality::High => Quality::High,
+        }
+    }
+}
+
+/// 오프라인 렌더링 품질
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OfflineQuality {
+    High,
+    Ultra,
+}
+
+impl Enum for OfflineQuality {
+    fn variants() -> &'static [&'static str] {
+        &["High", "Ultra"]
+    }
+
+    fn ids() -> Option<&'static [&'static str]> {
+        Some(&["high", "ultra"])
+    }
+
+    fn to_index(self) -> usize {
+        match self {
+            OfflineQuality::High => 0,
+            OfflineQuality::Ultra => 1,
+        }
+    }
+
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => OfflineQuality::High,
+            _ => OfflineQuality::Ultra,
+        }
+    }
+}
+
+impl From<OfflineQuality> for Quality {
+    fn from(quality: OfflineQuality) -> Self {
+        match quality {
+            OfflineQuality::High => Quality::High,
+            OfflineQuality::Ultra => Quality::Ultra,
+        }
+    }
+}
+
+/// 잡음 프로파일 모드 (Learn 구간을 재생한 뒤 Hold로 전환)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DenoiseProfile {
+    Auto,
+    Learn,
+    Hold,
+}
+
+impl Enum for DenoiseProfile {
+    fn variants() -> &'static [&'static str] {
+        &["Auto", "Learn", "Hold"]
+    }
+
+    fn ids() -> Option<&'static [&'static str]> {
+        Some(&["auto", "learn", "hold"])
+    }
+
+    fn to_index(self) -> usize {
+        match self {
+            DenoiseProfile::Auto => 0,
+            DenoiseProfile::Learn => 1,
+            DenoiseProfile::Hold => 2,
+        }
+    }
+
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => DenoiseProfile::Auto,
+            1 => DenoiseProfile::Learn,
+            _ => DenoiseProfile::Hold,
+        }
+    }
+}
+
+impl From<DenoiseProfile> for NoiseProfileMode {
+    fn from(profile: DenoiseProfile) -> Self {
+        match profile {
+            DenoiseProfile::Auto => NoiseProfileMode::Auto,
+            DenoiseProfile::Learn => NoiseProfileMode::Learn,
+            DenoiseProfile::Hold => NoiseProfileMode::Hold,
+        }
+    }
+}
+
+impl PresetType {
+    /// dsp-core 공유 프리셋 정의 (User는 슬롯이 비었을 때의 기본값)
+    pub fn chain_preset(self) -> ChainPreset {
+        match self {
+            PresetType::Vinyl => ChainPreset::vinyl(),
+            PresetType::Warm => ChainPreset::warm(),
+            PresetType::Clean => ChainPreset::clean(),
+            PresetType::User => ChainPreset::default(),
+        }
+    }
+}
+
+impl RustroverAiPlugin {
+    /// 전체 지연 (채널 간 동일하므로 첫 채널 기준)
+    fn latency(&self) -> u32 {
+        self.channels
+            .first()
+            .map_or(0, |dsp| dsp.chain.latency_samples()) as u32
+    }
+
+    /// 호스트 처리 모드에 맞는 품질 설정
+    fn target_quality(&self) -> Quality {
+        match self.process_mode {
+            ProcessMode::Offline => self.value(&self.params.offline_quality).into(),
+            ProcessMode::Realtime | ProcessMode::Buffered => {
+                self.value(&self.params.realtime_quality).into()
+            }
+        }
+    }
+
+    /// 미터 값 읽기용 핸들 (에디터, 테스트 하네스)
+    pub fn meters(&self) -> Arc<Meters> {
+        self.meters.clone()
+    }
+
+    /// 오디오 스레드에서 바꾼 파라미터 값 (에디터 / 테스트 하네스가 호스트에 보고)
+    pub fn param_edits(&self) -> Arc<ParamEdits> {
+        self.edits.clone()
+    }
+
+    /// param_map() 순서의 인덱스 (ParamEdits 슬롯)
+    fn param_index(&self, param: &impl Param) -> Option<usize> {
+        let ptr = param.as_ptr();
+        self.param_ptrs.iter().position(|(_, other)| *other == ptr)
+    }
+
+    /// 호스트가 가진 정규화 값 (ParamEdits 기록 이후 자동화로 바뀌었는지 비교)
+    fn host_normalized(&self, index: usize) -> f32 {
+        self.param_ptrs.get(index).map_or(0.0, |(_, ptr)| {
+            // SAFETY: 포인터는 self.params가 소유한 파라미터를 가리킴
+            unsafe { ptr.unmodulated_normalized_value() }
+        })
+    }
+
+    /// 보고 대기 중인 값 (기록 이후 호스트 값이 바뀌었으면 None, 호스트 값을 따름)
+    fn pending(&self, index: usize) -> Option<f32> {
+        self.edits.pending(index, self.host_normalized(index))
+    }
+
+    /// 오디오 스레드의 파라미터 변경 기록 (에디터가 호스트에 보고)
+    fn push_edit(&self, index: usize, normalized: f32) {
+        self.edits
+            .push(index, normalized, self.host_normalized(index));
+    }
+
+    /// 보고 대기 중인 값(MIDI CC, 프로그램 체인지)을 반영한 파라미터 값
+    fn value<P: Param>(&self, param: &P) -> P::Plain {
+        match self
+            .param_index(param)
+            .and_then(|index| self.pending(index))
+        {
+            Some(normalized) => param.preview_plain(normalized),
+            None => param.modulated_plain_value(),
+        }
+    }
+
+    /// 프리셋의 모든 필드를 프리셋 파라미터 값으로 기록
+    /// 호스트 보고 전에도 current_chain()과 램프가 새 값을 사용
+    fn push_preset(&self, preset: &ChainPreset) {
+        let fields = self.params.preset_fields();
+        for ((param, index), (_, value)) in fields
+            .iter()
+            .zip(self.preset_params)
+            .zip(preset.fields())
+        {
+            self.push_edit(index, param.preview_normalized(value));
+        }
+    }
+
+    /// 보고 대기 중인 값을 반영한 현재 체인 설정
+    fn current_chain(&self) -> ChainPreset {
+        self.params
+            .chain(|field| self.pending(self.preset_params[field]))
+    }
+
+    /// 보고가 끝나거나 자동화로 버려진 프리셋 필드는 스무더를 마지막 기록 값에서 시작
+    /// (보고된 경우 램프 없음, 자동화가 이긴 경우 기록 값 -> 호스트 값으로 램프)
+    fn settle_preset_edits(&mut self) {
+        let fields = self.params.preset_fields();
+        for (field, param) in fields.iter().enumerate() {
+            let pending = self.pending(self.preset_params[field]);
+            if let (Some(last), None) = (self.preset_pending[field], pending) {
+                param.smoothed.reset(param.preview_plain(last));
+                param.smoothed.set_target(self.sample_rate, param.value());
+            }
+            self.preset_pending[field] = pending;
+        }
+    }
+
+    /// User 프리셋 슬롯 핸들 (테스트 하네스)
+    pub fn user_preset(&self) -> Arc<RwLock<Option<PresetFile>>> {
+        self.params.user_preset.clone()
+    }
+
+    /// CC Learn 매핑 핸들 (에디터 외부 / 테스트 하네스)
+    pub fn midi_map(&self) -> Arc<RwLock<MidiMap>> {
+        self.params.midi_map.clone()
+    }
+
+    /// MIDI 이벤트 처리 (render_events가 이벤트 타이밍에서 호출)
+    /// CC / 프로그램 체인지는 ParamEdits에 기록 (에디터가 호스트에 보고, 그 전에는 pending 값 사용)
+    pub fn handle_midi(&mut self, event: NoteEvent<()>) {
+        match event {
+            NoteEvent::NoteOn { note, velocity, .. } => {
+                self.held_notes |= 1u128 << (note & 0x7f);
+                self.note_velocity = Some(velocity);
+            }
+            NoteEvent::NoteOff { note, .. } => {
+                self.held_notes &= !(1u128 << (note & 0x7f));
+                if self.held_notes == 0 {
+                    self.note_velocity = None;
+                }
+            }
+            NoteEvent::MidiCC { cc, value, .. } => {
+                // 에디터가 매핑을 수정 중이면 이전 매핑 표 사용 (Learn은 다음 CC에서)
+                if let Ok(mut map) = self.params.midi_map.try_write() {
+                    map.handle_cc(cc);
+                    if map.take_changed() {
+                        self.cc_params = resolve_cc(&map, &self.param_ptrs);
+                    }
+                }
+                if let Some(index) = self.cc_params.get(cc as usize).copied().flatten() {
+                    self.push_edit(index, value);
+                }
+            }
+            NoteEvent::MidiProgramChange { program, .. } => {
+                // 프로그램 번호 = 프리셋 인덱스 (Vinyl, Warm, Clean, User)
+                if (program as usize) < PresetType::variants().len() {
+                    let preset = PresetType::from_index(program as usize);
+                    let normalized = self.params.preset.preview_normalized(preset);
+                    if let Some(index) = self.param_index(&self.params.preset) {
+                        self.push_edit(index, normalized);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// 매핑의 파라미터 ID를 param_map() 인덱스로 변환 (오디오 스레드, 할당 없음)
+fn resolve_cc(map: &MidiMap, param_ptrs: &[(String, ParamPtr)]) -> [Option<usize>; 128] {
+    let mut cc_params = [None; 128];
+    for mapping in map.mappings() {
+        if let Some(slot) = cc_params.get_mut(mapping.cc as usize) {
+            *slot = param_ptrs.iter().position(|(id, _)| *id == mapping.param_id);
         }
     }
+    cc_params
+}
+
+/// 세션에 저장하는 미보고 변경: (파라미터 ID, 정규화 값, 기록 시점의 호스트 값)
+type SavedEdit = (String, f32, f32);
+
+/// ParamEdits의 미보고 변경을 세션 상태로 저장 / 복원
+/// 에디터를 열지 않으면 프리셋 전환, CC 값이 호스트에 보고되지 않으므로 세션에 따로 저장
+/// 복원 시 호스트 값이 저장 당시와 다르면(자동화) ParamEdits가 기록을 버림
+#[derive(Default)]
+pub struct UnreportedEdits {
+    /// 플러그인의 ParamEdits와 param_map() 순서의 파라미터 ID (플러그인 생성 시 연결)
+    target: OnceLock<(Arc<ParamEdits>, Vec<String>)>,
+}
+
+impl UnreportedEdits {
+    fn attach(&self, edits: Arc<ParamEdits>, ids: Vec<String>) {
+        let _ = self.target.set((edits, ids));
+    }
+}
+
+impl<'a> PersistentField<'a, Vec<SavedEdit>> for UnreportedEdits {
+    fn set(&self, saved: Vec<SavedEdit>) {
+        let Some((edits, ids)) = self.target.get() else {
+            return;
+        };
+        for (id, normalized, host) in saved {
+            if let Some(index) = ids.iter().position(|other| *other == id) {
+                edits.push(index, normalized, host);
+            }
+        }
+    }
+
+    fn map<F, R>(&self, f: F) -> R
+    where
+        F: Fn(&Vec<SavedEdit>) -> R,
+    {
+        let saved = self.target.get().map_or_else(Vec::new, |(edits, ids)| {
+            edits
+                .unreported()
+                .map(|(index, normalized, host)| (ids[index].clone(), normalized, host))
+                .collect()
+        });
+        f(&saved)
+    }
 }
 
 impl Default for RustroverAiPlugin {
     fn default() -> Self {
         let sample_rate = 48000.0;
+        let params = Arc::new(PluginParams::default());
+        let active_chain = params.preset.value().chain_preset();
+        let param_ptrs: Vec<(String, ParamPtr)> = params
+            .param_map()
+            .into_iter()
+            .map(|(id, param, _)| (id, param))
+            .collect();
+        let preset_params = params.preset_fields().map(|param| {
+            param_ptrs
+                .iter()
+                .position(|(_, ptr)| *ptr == param.as_ptr())
+                .unwrap_or(usize::MAX)
+        });
+        let edits = Arc::new(ParamEdits::new(param_ptrs.len()));
+        let ids = param_ptrs.iter().map(|(id, _)| id.clone()).collect();
+        params.unreported_edits.attach(edits.clone(), ids);
         Self {
-            params: Arc::new(PluginParams::default()),
+            edits,
+            preset_params,
+            preset_pending: [None; PRESET_FIELDS],
+            selected_preset: active_chain,
+            params,
             sample_rate,
-            channels: array::from_fn(|_| ChannelDsp::new(sample_rate as u32)),
+            channels: (0..2).map(|_| ChannelDsp::new(sample_rate, &active_chain)).collect(),
+            input_channels: 2,
+            meters: Arc::new(Meters::default()),
+            input_meter: LevelMeter::new(sample_rate),
+            output_meter: LevelMeter::new(sample_rate),
+            shared_sample_rate: Arc::new(AtomicF32::new(sample_rate)),
+            reported_latency: 0,
+            process_mode: ProcessMode::Realtime,
+            quality: Quality::default(),
+            param_ptrs,
+            cc_params: [None; 128],
+            held_notes: 0,
+            note_velocity: None,
+            active_chain,
+            ramps: RampBuffers::default(),
         }
     }
 }
 
+/// dsp-core 프리셋 필드 범위 -> 호스트 파라미터 범위
+fn linear_range(range: FieldRange) -> FloatRange {
+    FloatRange::Linear {
+        min: range.min,
+        max: range.max,
+    }
+}
+
+fn skewed_range(range: FieldRange, skew: f32) -> FloatRange {
+    FloatRange::Skewed {
+        min: range.min,
+        max: range.max,
+        factor: FloatRange::skew_factor(skew),
+    }
+}
+
 impl Default for PluginParams {
     fn default() -> Self {
         Self {
@@ -114,14 +838,14 @@ impl Default for PluginParams {
             drive: FloatParam::new(
                 "Drive",
                 5.0,
-                FloatRange::Linear { min: 1.0, max: 10.0 },
+                linear_range(ChainPreset::DRIVE_DB),
             )
             .with_unit("dB")
-            .with_smoother(SmoothingStyle::Logarithmic(50.0)),
+            .with_smoother(SmoothingStyle::Linear(50.0)),
             drywet: FloatParam::new(
                 "Dry/Wet",
                 1.0,
-                FloatRange::Linear { min: 0.0, max: 1.0 },
+                linear_range(ChainPreset::DRY_WET),
             )
             .with_unit("%")
             .with_value_to_string(formatters::v2s_f32_percentage(0))
@@ -129,7 +853,7 @@ impl Default for PluginParams {
             riaa_intensity: FloatParam::new(
                 "RIAA Intensity",
                 0.5,
-                FloatRange::Linear { min: 0.0, max: 1.0 },
+                linear_range(ChainPreset::RIAA_INTENSITY),
             )
             .with_unit("%")
             .with_value_to_string(formatters::v2s_f32_percentage(0))
@@ -137,16 +861,128 @@ impl Default for PluginParams {
             parallel_mix: FloatParam::new(
                 "Parallel Mix",
                 0.5,
-                FloatRange::Linear { min: 0.0, max: 1.0 },
+                linear_range(ChainPreset::PARALLEL_MIX),
             )
             .with_unit("%")
             .with_value_to_string(formatters::v2s_f32_percentage(0))
             .with_smoother(SmoothingStyle::Linear(50.0)),
+            // 프리셋 파라미터 기본값 = 기본 프리셋(Warm)
+            hardness: FloatParam::new(
+                "Hardness",
+                0.5,
+                linear_range(ChainPreset::HARDNESS),
+            )
+            .with_unit("%")
+            .with_value_to_string(formatters::v2s_f32_percentage(0)),
+            magnetic_threshold: FloatParam::new(
+                "Magnetic Threshold",
+                0.6,
+                linear_range(ChainPreset::MAGNETIC_THRESHOLD),
+            )
+            .with_step_size(0.01),
+            parallel_threshold: FloatParam::new(
+                "Parallel Threshold",
+                0.5,
+                linear_range(ChainPreset::PARALLEL_THRESHOLD),
+            )
+            .with_step_size(0.01),
+            limiter_ceiling: FloatParam::new(
+                "Limiter Ceiling",
+                0.95,
+                linear_range(ChainPreset::LIMITER_CEILING),
+            )
+            .with_step_size(0.01),
+            limiter_release: FloatParam::new(
+                "Limiter Release",
+                120.0,
+                skewed_range(ChainPreset::LIMITER_RELEASE_MS, -2.0),
+            )
+            .with_unit(" ms")
+            .with_step_size(1.0),
+            lowpass_hz: FloatParam::new(
+                "Lowpass",
+                12000.0,
+                skewed_range(ChainPreset::LOWPASS_HZ, -2.0),
+            )
+            .with_unit(" Hz")
+            .with_step_size(10.0),
+            lowpass_q: FloatParam::new(
+                "Lowpass Q",
+                0.707,
+                skewed_range(ChainPreset::LOWPASS_Q, -1.0),
+            )
+            .with_step_size(0.001),
             auto_velocity: BoolParam::new("Auto Velocity", true),
+            rumble: BoolParam::new("Rumble Filter", false),
+            rumble_cutoff: FloatParam::new(
+                "Rumble Cutoff",
+                20.0,
+                FloatRange::Linear { min: 10.0, max: 60.0 },
+            )
+            .with_unit(" Hz")
+            .with_step_size(1.0),
+            dehum: BoolParam::new("Dehum", false),
+            hum_harmonics: IntParam::new("Hum Harmonics", 4, IntRange::Linear { min: 1, max: 8 }),
+            declick: BoolParam::new("Declick", false),
+            declick_sensitivity: FloatParam::new(
+                "Declick Sensitivity",
+                0.5,
+                FloatRange::Linear { min: 0.0, max: 1.0 },
+            )
+            .with_unit("%")
+            .with_value_to_string(formatters::v2s_f32_percentage(0)),
+            declick_max_ms: FloatParam::new(
+                "Declick Max Length",
+                1.0,
+                FloatRange::Linear { min: 0.1, max: 2.5 },
+            )
+            .with_unit(" ms")
+            .with_step_size(0.1),
+            denoise: BoolParam::new("Denoise", false),
+            denoise_amount: FloatParam::new(
+                "Denoise Amount",
+                2.0,
+                FloatRange::Linear { min: 1.0, max: 4.0 },
+            )
+            .with_step_size(0.1),
+            denoise_floor: FloatParam::new(
+                "Denoise Floor",
+                -20.0,
+                FloatRange::Linear { min: -40.0, max: 0.0 },
+            )
+            .with_unit(" dB")
+            .with_step_size(1.0),
+            denoise_profile: EnumParam::new("Denoise Profile", DenoiseProfile::Auto),
+            velocity_source: EnumParam::new("Velocity Source", VelocitySource::Main),
+            midi_velocity: BoolParam::new("MIDI Velocity", false),
+            realtime_quality: EnumParam::new("Realtime Quality", RealtimeQuality::Normal),
+            offline_quality: EnumParam::new("Offline Quality", OfflineQuality::High),
+            user_preset: Arc::new(RwLock::new(None)),
+            midi_map: Arc::new(RwLock::new(MidiMap::default())),
+            unreported_edits: UnreportedEdits::default(),
+            editor_state: editor::default_state(),
         }
     }
 }
 
+const MONO_SIDECHAIN: &[NonZeroU32] = &[new_nonzero_u32(1)];
+const STEREO_SIDECHAIN: &[NonZeroU32] = &[new_nonzero_u32(2)];
+
+/// 메인 입출력 + 사이드체인 (모노 트랙은 모노 사이드체인)
+const fn io_layout(inputs: u32, outputs: u32, name: &'static str) -> AudioIOLayout {
+    AudioIOLayout {
+        main_input_channels: NonZeroU32::new(inputs),
+        main_output_channels: NonZeroU32::new(outputs),
+        aux_input_ports: if inputs == 1 { MONO_SIDECHAIN } else { STEREO_SIDECHAIN },
+        names: PortNames {
+            layout: Some(name),
+            aux_inputs: &["Sidechain"],
+            ..PortNames::const_default()
+        },
+        ..AudioIOLayout::const_default()
+    }
+}
+
 impl Plugin for RustroverAiPlugin {
     const NAME: &'static str = "Rustrover AI - 2026 Patent";
     const VENDOR: &'static str = "Rustrover Audio";
@@ -155,13 +991,15 @@ impl Plugin for RustroverAiPlugin {
 
     const VERSION: &'static str = env!("CARGO_PKG_VERSION");
 
-    const AUDIO_IO_LAYOUTS: &'static [AudioIOLayout] = &[AudioIOLayout {
-        main_input_channels: NonZeroU32::new(2),
-        main_output_channels: NonZeroU32::new(2),
-        ..AudioIOLayout::const_default()
-    }];
+    const AUDIO_IO_LAYOUTS: &'static [AudioIOLayout] = &[
+        io_layout(2, 2, "Stereo"),
+        io_layout(1, 1, "Mono"),
+        io_layout(1, 2, "Mono to Stereo"),
+        io_layout(6, 6, "5.1"),
+        io_layout(8, 8, "7.1"),
+    ];
 
-    const MIDI_INPUT: MidiConfig = MidiConfig::None;
+    const MIDI_INPUT: MidiConfig = MidiConfig::MidiCCs;
     const MIDI_OUTPUT: MidiConfig = MidiConfig::None;
 
     const SAMPLE_ACCURATE_AUTOMATION: bool = true;
@@ -173,14 +1011,66 @@ impl Plugin for RustroverAiPlugin {
         self.params.clone()
     }
 
+    fn editor(&mut self, _async_executor: AsyncExecutor<Self>) -> Option<Box<dyn Editor>> {
+        editor::create(
+            self.params.clone(),
+            self.meters.clone(),
+            self.shared_sample_rate.clone(),
+            self.edits.clone(),
+        )
+    }
+
     fn initialize(
         &mut self,
-        _audio_io_layout: &AudioIOLayout,
+        audio_io_layout: &AudioIOLayout,
         buffer_config: &BufferConfig,
-        _context: &mut impl InitContext<Self>,
+        context: &mut impl InitContext<Self>,
     ) -> bool {
         self.sample_rate = buffer_config.sample_rate;
-        self.channels = array::from_fn(|_| ChannelDsp::new(self.sample_rate as u32));
+
+        // 세션에 User 프리셋이 없으면 사용자 디렉터리의 default.toml 사용
+        let user_slot_empty = self
+            .params
+            .user_preset
+            .read()
+            .map(|slot| slot.is_none())
+            .unwrap_or(false);
+        if user_slot_empty {
+            if let Some(path) = PresetBank::user_dir().map(|dir| dir.join("default.toml")) {
+                if path.is_file() {
+                    if let Err(err) = self.params.load_user_preset(&path) {
+                        nih_log!("failed to load {}: {err}", path.display());
+                    }
+                }
+            }
+        }
+
+        // 세션의 파라미터 값이 프리셋보다 우선 (이후 프리셋 선택이 바뀔 때만 필드를 보냄)
+        let selected = self.value(&self.params.preset);
+        self.selected_preset = self
+            .params
+            .resolve_chain(selected)
+            .unwrap_or_else(|| selected.chain_preset());
+        self.active_chain = self.current_chain();
+        let preset = self.active_chain;
+        let outputs = audio_io_layout.main_output_channels.map_or(0, NonZeroU32::get) as usize;
+        self.input_channels = audio_io_layout
+            .main_input_channels
+            .map_or(0, NonZeroU32::get) as usize;
+        self.channels = (0..outputs)
+            .map(|_| ChannelDsp::new(self.sample_rate, &preset))
+            .collect();
+        self.ramps.resize(buffer_config.max_buffer_size as usize);
+        self.input_meter = LevelMeter::new(self.sample_rate);
+        self.output_meter = LevelMeter::new(self.sample_rate);
+        self.shared_sample_rate.store(self.sample_rate);
+        self.process_mode = buffer_config.process_mode;
+        self.quality = self.target_quality();
+        for channel in &mut self.channels {
+            channel.set_quality(self.quality);
+        }
+        self.reported_latency = self.latency();
+        context.set_latency_samples(self.reported_latency);
         true
     }
 
@@ -188,73 +1078,236 @@ impl Plugin for RustroverAiPlugin {
         for channel in &mut self.channels {
             channel.reset();
         }
+        self.input_meter.reset();
+        self.output_meter.reset();
+        self.held_notes = 0;
+        self.note_velocity = None;
     }
 
     fn process(
         &mut self,
         buffer: &mut Buffer,
-        _aux: &mut AuxiliaryBuffers,
-        _context: &mut impl ProcessContext<Self>,
+        aux: &mut AuxiliaryBuffers,
+        context: &mut impl ProcessContext<Self>,
     ) -> ProcessStatus {
-        // 특허 파라미터 읽기
-        let _preset = self.params.preset.value();
-        let drive = self.params.drive.smoothed.next();
-        let drywet = self.params.drywet.smoothed.next();
-        let riaa_intensity = self.params.riaa_intensity.smoothed.next();
-        let parallel_mix = self.params.parallel_mix.smoothed.next();
-        let auto_velocity = self.params.auto_velocity.value();
-
-        let drive_linear = db_to_linear(drive);
-        let saturation = ((drive - 1.0) / 9.0).clamp(0.0, 1.0);
-        let magnetic = MagneticEQ::new(saturation, 0.5);
-
-        for (channel_idx, mut channel_samples) in buffer.iter_samples().enumerate() {
-            let velocity = if auto_velocity {
-                VelocityAnalyzer::calculate_velocity(channel_samples)
-            } else {
-                0.6
-            };
+        // 호스트 FTZ 설정과 무관하게 처리 중 비정규화 수 차단 (필터 상태 flush는 dsp-core에서)
+        let _ftz = ScopedFtz::enable();
+        let events = std::iter::from_fn(|| context.next_event());
+        if let Some(latency) = self.render_events(buffer, aux, events) {
+            context.set_latency_samples(latency);
+        }
+        ProcessStatus::Normal
+    }
+}
 
-            let parallel_intensity = if velocity < 0.50 { parallel_mix } else { 0.0 };
-            self.channels[channel_idx]
-                .parallel
-                .set_intensity(parallel_intensity);
-
-            for sample in channel_samples.iter_mut() {
-                let dry = *sample;
-                let mut y = dry * drive_linear;
-                y = soft_clip(y);
-
-                if velocity < 0.60 {
-                    y = magnetic.process(y);
-                } else {
-                    let riaa = self.channels[channel_idx].riaa.process(y);
-                    y = mix_dry_wet(y, riaa, riaa_intensity);
-                }
+impl RustroverAiPlugin {
+    /// 호스트 컨텍스트 없이 한 버퍼 처리 (MIDI 이벤트 없음, 헤드리스 하네스)
+    /// 지연이 바뀌었으면 새 지연을 반환 (호출자가 호스트에 보고)
+    pub fn render(&mut self, buffer: &mut Buffer, aux: &mut AuxiliaryBuffers) -> Option<u32> {
+        self.render_events(buffer, aux, std::iter::empty())
+    }
 
-                y = self.channels[channel_idx].parallel.process(y);
-                *sample = mix_dry_wet(dry, y, drywet);
+    /// MIDI 이벤트를 타이밍 위치에서 적용하며 한 버퍼 처리 (process / 헤드리스 하네스 공용)
+    /// 이벤트 사이 구간마다 설정을 다시 읽음 (velocity 판정과 경로 크로스페이드도 구간 단위)
+    pub fn render_events(
+        &mut self,
+        buffer: &mut Buffer,
+        aux: &mut AuxiliaryBuffers,
+        events: impl IntoIterator<Item = NoteEvent<()>>,
+    ) -> Option<u32> {
+        let samples = buffer.samples();
+        let mut start = 0;
+        let mut latency = None;
+        for event in events {
+            let timing = (event.timing() as usize).clamp(start, samples);
+            if timing > start {
+                latency = self.render_range(buffer, aux, start..timing).or(latency);
+                start = timing;
             }
+            self.handle_midi(event);
         }
+        if start < samples {
+            latency = self.render_range(buffer, aux, start..samples).or(latency);
+        }
+        latency
+    }
 
-        ProcessStatus::Normal
+    /// 버퍼의 range 구간 처리
+    fn render_range(
+        &mut self,
+        buffer: &mut Buffer,
+        aux: &mut AuxiliaryBuffers,
+        range: Range<usize>,
+    ) -> Option<u32> {
+        // 프리셋 선택이 바뀌면 모든 필드를 파라미터로 보냄 (User 슬롯이 잠겨 있으면 다음 구간에서)
+        if let Some(selected) = self.params.resolve_chain(self.value(&self.params.preset)) {
+            if selected != self.selected_preset {
+                self.selected_preset = selected;
+                self.push_preset(&selected);
+            }
+        }
+        self.settle_preset_edits();
+
+        // 체인 설정(필터/리미터/하드니스/임계값)이 바뀌면 재설정
+        let chain = self.current_chain();
+        if chain != self.active_chain {
+            for dsp in &mut self.channels {
+                dsp.chain.set_preset(&chain);
+            }
+            self.active_chain = chain;
+        }
+
+        let quality = self.target_quality();
+        if quality != self.quality {
+            for dsp in &mut self.channels {
+                dsp.set_quality(quality);
+            }
+            self.quality = quality;
+        }
+
+        let params = &self.params;
+        let restoration = Restoration {
+            rumble: self.value(&params.rumble),
+            rumble_cutoff_hz: self.value(&params.rumble_cutoff),
+            dehum: self.value(&params.dehum),
+            hum_harmonics: self.value(&params.hum_harmonics) as usize,
+            declick: self.value(&params.declick),
+            declick_sensitivity: self.value(&params.declick_sensitivity),
+            declick_max_ms: self.value(&params.declick_max_ms),
+            denoise: self.value(&params.denoise),
+            denoise_amount: self.value(&params.denoise_amount),
+            denoise_floor_db: self.value(&params.denoise_floor),
+            denoise_profile: NoiseProfileMode::from(self.value(&params.denoise_profile)),
+        };
+        let auto_velocity = self.value(&params.auto_velocity);
+        let velocity_source = self.value(&params.velocity_source);
+        let midi_velocity = self.value(&params.midi_velocity);
+        for dsp in &mut self.channels {
+            dsp.set_restoration(&restoration);
+        }
+
+        // 설정 변경으로 지연이 달라지면 다시 보고
+        let latency = self.latency();
+        let latency_changed = (latency != self.reported_latency).then_some(latency);
+        self.reported_latency = latency;
+
+        // 연속 파라미터는 샘플별 램프, 보고 대기 중인 프리셋 값은 체인 내부 스무딩
+        let len = range.len().min(self.ramps.drive.len());
+        let pending: [Option<f32>; PRESET_FIELDS] =
+            std::array::from_fn(|field| self.pending(self.preset_params[field]));
+        let ramps = &mut self.ramps;
+        params.drive.smoothed.next_block(&mut ramps.drive, len);
+        params.drywet.smoothed.next_block(&mut ramps.drywet, len);
+        params.riaa_intensity.smoothed.next_block(&mut ramps.riaa_intensity, len);
+        params.parallel_mix.smoothed.next_block(&mut ramps.parallel_mix, len);
+        let fields = params.preset_fields();
+        let ramp = |field: usize, values| param_ramp(fields[field], pending[field], values, len);
+        let chain_ramps = ChainRamps {
+            drive_db: ramp(DRIVE_FIELD, &ramps.drive),
+            riaa_intensity: ramp(RIAA_FIELD, &ramps.riaa_intensity),
+            parallel_mix: ramp(PARALLEL_FIELD, &ramps.parallel_mix),
+            dry_wet: ramp(DRYWET_FIELD, &ramps.drywet),
+        };
+
+        // 사이드체인: 메인 경로와 같은 지연을 거친 뒤 채널별 velocity 분석
+        // (채널이 적은 사이드체인은 마지막 채널을 공유, 무음 채널은 메인 입력 분석)
+        for dsp in &mut self.channels {
+            dsp.key_velocity = None;
+        }
+        if let Some(sidechain) = aux.inputs.first_mut() {
+            let sidechain = sidechain.as_slice();
+            for (samples, dsp) in sidechain.iter_mut().zip(self.channels.iter_mut()) {
+                for sample in &mut samples[range.clone()] {
+                    *sample = dsp.sidechain_delay.process(*sample);
+                }
+            }
+            if velocity_source == VelocitySource::Sidechain {
+                for (index, dsp) in self.channels.iter_mut().enumerate() {
+                    dsp.key_velocity = sidechain
+                        .get(index)
+                        .or(sidechain.last())
+                        .map(|samples| &samples[range.clone()])
+                        .filter(|samples| samples.iter().any(|&sample| sample != 0.0))
+                        .map(VelocityAnalyzer::calculate_velocity);
+                }
+            }
+        }
+        if midi_velocity && self.note_velocity.is_some() {
+            for dsp in &mut self.channels {
+                dsp.key_velocity = self.note_velocity;
+            }
+        }
+
+        let channels = buffer.as_slice();
+        upmix(channels, self.input_channels, &range);
+        self.input_meter.measure(channels.iter().map(|samples| &samples[range.clone()]));
+
+        // 채널 평균 (게인 리덕션은 최대값)
+        let mut velocity_sum = 0.0;
+        let mut magnetic_sum = 0.0;
+        let mut riaa_sum = 0.0;
+        let mut parallel_sum = 0.0;
+        let mut gain_reduction_db = 0.0f32;
+
+        for (channel_samples, dsp) in channels.iter_mut().zip(self.channels.iter_mut()) {
+            let key_velocity = if auto_velocity {
+                dsp.key_velocity
+            } else {
+                Some(FIXED_VELOCITY)
+            };
+            let samples = &mut channel_samples[range.clone()];
+            let velocity = dsp.chain.process_block(samples, &chain_ramps, key_velocity);
+
+            velocity_sum += velocity;
+            magnetic_sum += dsp.chain.magnetic_blend();
+            riaa_sum += dsp.chain.riaa_blend();
+            parallel_sum += dsp.chain.parallel_blend();
+            gain_reduction_db = gain_reduction_db.max(dsp.chain.gain_reduction_db());
+        }
+
+        self.output_meter.measure(channels.iter().map(|samples| &samples[range.clone()]));
+        let count = self.channels.len().max(1) as f32;
+        self.meters.publish(&MeterSnapshot {
+            input_peak: self.input_meter.peak(),
+            input_rms: self.input_meter.rms(),
+            output_peak: self.output_meter.peak(),
+            output_rms: self.output_meter.rms(),
+            velocity: velocity_sum / count,
+            path: if magnetic_sum / count >= 0.5 {
+                ProcessingPath::Magnetic
+            } else {
+                ProcessingPath::Riaa
+            },
+            gain_reduction_db,
+            riaa_blend: riaa_sum / count,
+            parallel_blend: parallel_sum / count,
+        });
+
+        latency_changed
     }
 }
 
-#[inline]
-fn db_to_linear(db: f32) -> f32 {
-    10.0_f32.powf(db / 20.0)
+/// 보고 대기 중인 값이 있으면 고정 목표값(체인 내부 스무딩), 없으면 파라미터 스무더 램프
+fn param_ramp<'a>(
+    param: &FloatParam,
+    pending: Option<f32>,
+    values: &'a [f32],
+    len: usize,
+) -> ParamRamp<'a> {
+    match pending {
+        Some(normalized) => ParamRamp::Constant(param.preview_plain(normalized)),
+        None => ParamRamp::PerSample(&values[..len]),
+    }
 }
 
-#[inline]
-fn soft_clip(x: f32) -> f32 {
-    const THRESHOLD: f32 = 1.0;
-    if x > THRESHOLD {
-        THRESHOLD + (x - THRESHOLD) / (1.0 + (x - THRESHOLD).abs())
-    } else if x < -THRESHOLD {
-        -THRESHOLD + (x + THRESHOLD) / (1.0 + (x + THRESHOLD).abs())
-    } else {
-        x
+/// 입력보다 많은 출력 채널(모노 -> 스테레오)은 range 구간에 입력 채널을 복사해 채움
+fn upmix(channels: &mut [&mut [f32]], input_channels: usize, range: &Range<usize>) {
+    if input_channels == 0 {
+        return;
+    }
+    for index in input_channels..channels.len() {
+        let (sources, targets) = channels.split_at_mut(index);
+        targets[0][range.clone()].copy_from_slice(&sources[index % input_channels][range.clone()]);
     }
 }
 
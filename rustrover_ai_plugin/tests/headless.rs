@@ -0,0 +1,430 @@
+//! 호스트 없이 RustroverAiPlugin 실행: 안정성 / 결정성 / 리셋 / 버퍼 크기 독립성
+
+use nih_plug::prelude::*;
+use plugin_harness::{assert_finite_normal, max_difference, noise, sine, Harness};
+use dsp_core::{ChainPreset, PresetFile};
+use rustrover_ai_plugin::{PluginParams, RustroverAiPlugin};
+
+const SR: f32 = 48000.0;
+
+fn harness(layout: usize, sample_rate: f32, max_buffer: usize) -> Harness<RustroverAiPlugin> {
+    let mut harness = Harness::new(RustroverAiPlugin::default(), RustroverAiPlugin::render);
+    let layout = &RustroverAiPlugin::AUDIO_IO_LAYOUTS[layout];
+    assert!(harness.initialize(layout, sample_rate, max_buffer, ProcessMode::Realtime));
+    harness
+}
+
+/// 음악 대용 신호: 정현파 + 잡음, 채널마다 다른 시드
+fn program(channels: usize, sample_rate: f32, len: usize) -> Vec<Vec<f32>> {
+    (0..channels)
+        .map(|channel| {
+            let tone = sine(220.0 * (channel + 1) as f32, sample_rate, len, 0.5);
+            let hiss = noise(channel as u32 + 1, len, 0.05);
+            tone.iter().zip(&hiss).map(|(a, b)| a + b).collect()
+        })
+        .collect()
+}
+
+/// 복원 단계까지 모두 켜고, velocity 분석은 버퍼 단위이므로 끔
+fn enable_restoration(harness: &mut Harness<RustroverAiPlugin>) {
+    for id in ["rumble", "dehum", "declick", "denoise"] {
+        harness.set_parameter(id, 1.0);
+    }
+    harness.set_parameter("auto_velocity", 0.0);
+}
+
+#[test]
+fn test_stable_across_configs() {
+    for (layout, plugin_layout) in RustroverAiPlugin::AUDIO_IO_LAYOUTS.iter().enumerate() {
+        let inputs = plugin_layout.main_input_channels.map_or(0, NonZeroU32::get) as usize;
+        for sample_rate in [44100.0, 48000.0, 96000.0] {
+            for max_buffer in [32, 512] {
+                let mut harness = harness(layout, sample_rate, max_buffer);
+                let input = program(inputs, sample_rate, sample_rate as usize / 2);
+                let first = harness.process(&input, &[max_buffer, 7, 1]);
+
+                // 자동화: 드라이브 최대, Dry/Wet 절반, 복원 단계 켬
+                harness.set_parameter("drive", 1.0);
+                harness.set_parameter("drywet", 0.5);
+                enable_restoration(&mut harness);
+                let second = harness.process(&input, &[max_buffer, 13]);
+
+                // 입력 종료 후 꼬리 (필터 감쇠 구간)
+                let silence = vec![vec![0.0; sample_rate as usize / 2]; inputs];
+                let tail = harness.process(&silence, &[max_buffer]);
+
+                for output in [&first, &second, &tail] {
+                    assert_finite_normal(output);
+                    assert!(output.iter().flatten().all(|x| x.abs() <= 1.0));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_deterministic() {
+    let input = program(2, SR, 24000);
+    let render = || {
+        let mut harness = harness(0, SR, 256);
+        harness.set_parameter("drive", 0.8);
+        harness.process(&input, &[256, 100])
+    };
+    assert_eq!(max_difference(&render(), &render()), 0.0);
+}
+
+#[test]
+fn test_reset_restores_initial_state() {
+    let input = program(2, SR, 24000);
+    let mut harness = harness(0, SR, 256);
+    enable_restoration(&mut harness);
+    // 험 검출 결과는 의도적으로 리셋 후에도 유지되므로 제외
+    harness.set_parameter("dehum", 0.0);
+    // 스무더가 목표값에 도달하도록 먼저 처리
+    harness.process(&input, &[256]);
+    harness.reset();
+    let first = harness.process(&input, &[256]);
+    harness.reset();
+    let second = harness.process(&input, &[256]);
+    assert_eq!(max_difference(&first, &second), 0.0);
+}
+
+#[test]
+fn test_buffer_size_does_not_change_output() {
+    let input = program(2, SR, 48000);
+    let render = |blocks: &[usize]| {
+        let mut harness = harness(0, SR, 1024);
+        enable_restoration(&mut harness);
+        harness.set_parameter("drive", 0.7);
+        harness.process(&input, blocks)
+    };
+    let reference = render(&[1024]);
+    for blocks in [&[64][..], &[1, 2, 3, 500], &[1000, 17]] {
+        let difference = max_difference(&reference, &render(blocks));
+        assert!(difference < 1e-6, "{blocks:?}: {difference}");
+    }
+}
+
+#[test]
+fn test_reported_latency_matches_output() {
+    // 드라이/웻 0 (Dry 경로만): 출력은 보고된 지연만큼 밀린 입력
+    let mut harness = harness(1, SR, 512);
+    harness.set_parameter("drywet", 0.0);
+    harness.process(&[vec![0.0; 4800]], &[512]);
+    let latency = harness.latency() as usize;
+    assert!(latency > 0);
+
+    let input = sine(440.0, SR, 9600, 0.25);
+    let output = harness.process(&[input.clone()], &[512]);
+    for n in latency + 100..input.len() {
+        assert!(
+            (output[0][n] - input[n - latency]).abs() < 1e-4,
+            "sample {n}"
+        );
+    }
+}
+
+#[test]
+fn test_silent_sidechain_falls_back_to_main() {
+    let input = program(2, SR, 4800);
+    let render = |source: f32, sidechain: &[Vec<f32>]| {
+        let mut harness = harness(0, SR, 480);
+        harness.set_parameter("velocity_source", source);
+        let output = harness.process_with_sidechain(&input, sidechain, &[480]);
+        (output, harness.plugin().meters().snapshot().velocity)
+    };
+    let (main, main_velocity) = render(0.0, &[]);
+
+    // 연결되지 않은 사이드체인(0)은 메인 입력 분석과 같음
+    let (unconnected, velocity) = render(1.0, &[]);
+    assert_eq!(max_difference(&main, &unconnected), 0.0);
+    assert_eq!(velocity, main_velocity);
+
+    // 신호가 있으면 사이드체인 분석 (메인보다 훨씬 작은 레벨)
+    let key = vec![sine(100.0, SR, 4800, 0.01); 2];
+    let (_, velocity) = render(1.0, &key);
+    assert!((velocity - main_velocity).abs() > 1e-3);
+}
+
+#[test]
+fn test_program_change_selects_preset() {
+    let mut harness = harness(0, SR, 256);
+    let program_change = |program| NoteEvent::MidiProgramChange {
+        timing: 0,
+        channel: 0,
+        program,
+    };
+    // 파라미터는 직접 바뀌지 않고 호스트 보고 경로(ParamEdits)로 전달
+    let edits = harness.plugin().param_edits();
+    for (program, normalized) in [(0, 0.0), (2, 2.0 / 3.0), (3, 1.0)] {
+        harness.plugin_mut().handle_midi(program_change(program));
+        assert!((harness.parameter("preset") - normalized).abs() > 0.1);
+        harness.report_edits(&edits);
+        assert!((harness.parameter("preset") - normalized).abs() < 1e-6);
+    }
+    // 프리셋 수 밖의 프로그램은 무시
+    harness.plugin_mut().handle_midi(program_change(10));
+    harness.report_edits(&edits);
+    assert_eq!(harness.parameter("preset"), 1.0);
+}
+
+#[test]
+fn test_cc_learn_controls_parameter() {
+    let mut harness = harness(0, SR, 256);
+    let cc = |cc, value| NoteEvent::MidiCC {
+        timing: 0,
+        channel: 0,
+        cc,
+        value,
+    };
+    let edits = harness.plugin().param_edits();
+    let drive = harness.parameter("drive");
+    harness.plugin_mut().handle_midi(cc(20, 1.0));
+    harness.report_edits(&edits);
+    assert_eq!(harness.parameter("drive"), drive);
+
+    let midi_map = harness.plugin().midi_map();
+    midi_map.write().unwrap().start_learn("drive");
+    harness.plugin_mut().handle_midi(cc(20, 1.0));
+    harness.report_edits(&edits);
+    assert_eq!(harness.parameter("drive"), 1.0);
+    harness.plugin_mut().handle_midi(cc(20, 0.25));
+    harness.report_edits(&edits);
+    assert_eq!(harness.parameter("drive"), 0.25);
+    harness.plugin_mut().handle_midi(cc(21, 0.75));
+    harness.report_edits(&edits);
+    assert_eq!(harness.parameter("drive"), 0.25);
+
+    // 에디터에서 매핑 해제: 다음 CC부터 무시
+    midi_map.write().unwrap().clear("drive");
+    harness.plugin_mut().handle_midi(cc(20, 1.0));
+    harness.report_edits(&edits);
+    assert_eq!(harness.parameter("drive"), 0.25);
+}
+
+#[test]
+fn test_automation_overrides_unreported_midi() {
+    // 에디터를 열지 않은 세션: CC / 프로그램 체인지 값은 보고되지 않은 채 남아 있음
+    let cc = |value| NoteEvent::MidiCC {
+        timing: 0,
+        channel: 0,
+        cc: 20,
+        value,
+    };
+    let input = program(2, SR, 256);
+    let mut learned = harness(0, SR, 256);
+    let midi_map = learned.plugin().midi_map();
+    midi_map.write().unwrap().start_learn("drive");
+    learned.plugin_mut().handle_midi(cc(1.0));
+    learned.set_parameter("drive", 0.6);
+    let output = learned.process(&input, &[256]);
+
+    let mut reference = harness(0, SR, 256);
+    reference.set_parameter("drive", 0.6);
+    assert_eq!(
+        max_difference(&output, &reference.process(&input, &[256])),
+        0.0
+    );
+    let edits = learned.plugin().param_edits();
+    edits.drain(|_, _| panic!("CC value should be dropped by automation"));
+
+    // 다음 CC는 다시 적용
+    learned.plugin_mut().handle_midi(cc(0.9));
+    learned.process(&input, &[256]);
+    learned.report_edits(&edits);
+    assert_eq!(learned.parameter("drive"), 0.9);
+
+    // 프로그램 체인지 뒤의 프리셋 자동화
+    let program_change = NoteEvent::MidiProgramChange {
+        timing: 0,
+        channel: 0,
+        program: 0,
+    };
+    learned.plugin_mut().handle_midi(program_change);
+    learned.set_parameter("preset", 2.0 / 3.0);
+    learned.process(&input, &[256]);
+    learned.report_edits(&edits);
+    assert!((learned.parameter("preset") - 2.0 / 3.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_midi_event_applies_at_timing() {
+    let input = program(2, SR, 256);
+    // 같은 위치에서 나뉜 블록과 비교: 이벤트 전 구간은 그대로, 이후 구간부터 Vinyl
+    let reference = harness(0, SR, 256).process(&input, &[128]);
+    let mut timed = Harness::new(RustroverAiPlugin::default(), |plugin, buffer, aux| {
+        let event = NoteEvent::MidiProgramChange {
+            timing: 128,
+            channel: 0,
+            program: 0,
+        };
+        plugin.render_events(buffer, aux, [event])
+    });
+    let layout = &RustroverAiPlugin::AUDIO_IO_LAYOUTS[0];
+    assert!(timed.initialize(layout, SR, 256, ProcessMode::Realtime));
+    let output = timed.process(&input, &[256]);
+
+    let split = |output: &[Vec<f32>], range: std::ops::Range<usize>| -> Vec<Vec<f32>> {
+        output
+            .iter()
+            .map(|channel| channel[range.clone()].to_vec())
+            .collect()
+    };
+    assert_eq!(
+        max_difference(&split(&reference, 0..128), &split(&output, 0..128)),
+        0.0
+    );
+    assert!(max_difference(&split(&reference, 128..256), &split(&output, 128..256)) > 1e-4);
+}
+
+#[test]
+fn test_note_velocity_overrides_analyzer() {
+    let mut harness = harness(0, SR, 512);
+    harness.set_parameter("midi_velocity", 1.0);
+    let input = program(2, SR, 512);
+    let note = |on: bool| {
+        let (timing, voice_id, channel, note, velocity) = (0, None, 0, 60, 0.1);
+        if on {
+            NoteEvent::NoteOn {
+                timing,
+                voice_id,
+                channel,
+                note,
+                velocity,
+            }
+        } else {
+            NoteEvent::NoteOff {
+                timing,
+                voice_id,
+                channel,
+                note,
+                velocity,
+            }
+        }
+    };
+
+    harness.plugin_mut().handle_midi(note(true));
+    harness.process(&input, &[512]);
+    let meters = harness.plugin().meters();
+    assert!((meters.snapshot().velocity - 0.1).abs() < 1e-6);
+
+    // 노트를 놓으면 다시 분석값
+    harness.plugin_mut().handle_midi(note(false));
+    harness.process(&input, &[512]);
+    assert!((meters.snapshot().velocity - 0.1).abs() > 1e-3);
+}
+
+#[test]
+fn test_preset_selection_pushes_all_fields() {
+    let mut harness = harness(0, SR, 256);
+    let input = program(2, SR, 256);
+    harness.set_parameter("drive", 0.0);
+    harness.set_parameter("lowpass_hz", 1.0);
+
+    // Vinyl 선택: 다음 버퍼부터 프리셋 값으로 처리되고, 보고 후 파라미터에 반영
+    harness.set_parameter("preset", 0.0);
+    harness.process(&input, &[256]);
+    let edits = harness.plugin().param_edits();
+    harness.report_edits(&edits);
+    // ChainPreset::fields() 순서의 파라미터 ID
+    let ids = [
+        "drive",
+        "hardness",
+        "riaa_intensity",
+        "parallel_mix",
+        "drywet",
+        "magnetic_threshold",
+        "parallel_threshold",
+        "limiter_ceiling",
+        "limiter_release",
+        "lowpass_hz",
+        "lowpass_q",
+    ];
+    let params = PluginParams::default();
+    let fields = params.preset_fields().into_iter().zip(ChainPreset::vinyl().fields());
+    for (id, (param, (name, value))) in ids.into_iter().zip(fields) {
+        let expected = param.preview_normalized(value);
+        assert!((harness.parameter(id) - expected).abs() < 1e-4, "{name}");
+    }
+
+    // 세션 값은 유지: 같은 프리셋을 다시 처리해도 보내지 않음
+    harness.set_parameter("drive", 0.0);
+    harness.process(&input, &[256]);
+    edits.drain(|_, _| panic!("preset was not changed"));
+    assert_eq!(harness.parameter("drive"), 0.0);
+}
+
+#[test]
+fn test_automation_overrides_unreported_preset() {
+    // 에디터를 열지 않은 세션: 프리셋 값이 보고되지 않은 채 남아 있음
+    let input = program(2, SR, 256);
+    let render = |drive: Option<f32>| {
+        let mut harness = harness(0, SR, 256);
+        harness.set_parameter("preset", 0.0);
+        harness.process(&input, &[256]);
+        if let Some(drive) = drive {
+            harness.set_parameter("drive", drive);
+        }
+        let output = harness.process(&input, &[256]);
+        (harness, output)
+    };
+    let (mut automated, output) = render(Some(1.0));
+    let (_, held) = render(None);
+    assert!(max_difference(&output, &held) > 1e-3);
+
+    // 자동화된 필드만 버려지고 나머지 프리셋 필드는 그대로 보고
+    let edits = automated.plugin().param_edits();
+    automated.report_edits(&edits);
+    assert_eq!(automated.parameter("drive"), 1.0);
+    let vinyl = ChainPreset::vinyl();
+    let lowpass = PluginParams::default()
+        .lowpass_hz
+        .preview_normalized(vinyl.lowpass_hz);
+    assert!((automated.parameter("lowpass_hz") - lowpass).abs() < 1e-4);
+}
+
+#[test]
+fn test_unreported_preset_survives_session_reload() {
+    let input = program(2, SR, 256);
+    let mut session = harness(0, SR, 256);
+    session.set_parameter("preset", 0.0);
+    session.process(&input, &[256]);
+    let state = session.plugin().params().serialize_fields();
+
+    // 호스트 복원 순서: 파라미터 값 -> 저장 필드 -> initialize
+    let mut restored = Harness::new(RustroverAiPlugin::default(), RustroverAiPlugin::render);
+    restored.set_parameter("preset", 0.0);
+    restored.plugin().params().deserialize_fields(&state);
+    let layout = &RustroverAiPlugin::AUDIO_IO_LAYOUTS[0];
+    assert!(restored.initialize(layout, SR, 256, ProcessMode::Realtime));
+    restored.process(&input, &[256]);
+
+    let edits = restored.plugin().param_edits();
+    restored.report_edits(&edits);
+    let vinyl = ChainPreset::vinyl();
+    let drive = PluginParams::default()
+        .drive
+        .preview_normalized(vinyl.drive_db);
+    assert!((restored.parameter("drive") - drive).abs() < 1e-4);
+}
+
+#[test]
+fn test_user_preset_slot_change_pushes_fields() {
+    let mut harness = harness(0, SR, 256);
+    let input = program(2, SR, 256);
+    harness.set_parameter("preset", 1.0);
+    harness.process(&input, &[256]);
+
+    // 에디터에서 사용자 프리셋 파일 선택 (User 선택 중 슬롯이 바뀌면 필드를 보냄)
+    let chain = ChainPreset {
+        drive_db: 7.5,
+        ..ChainPreset::vinyl()
+    };
+    let slot = harness.plugin().user_preset();
+    *slot.write().unwrap() = Some(PresetFile::new("Dusty", chain));
+    harness.process(&input, &[256]);
+    let edits = harness.plugin().param_edits();
+    harness.report_edits(&edits);
+    let expected = PluginParams::default().drive.preview_normalized(chain.drive_db);
+    assert!((harness.parameter("drive") - expected).abs() < 1e-4);
+}
@@ -0,0 +1,260 @@
+//! Warm Tape 에디터 (nih_plug_egui)
+//! 프리셋 선택 / 파라미터 슬라이더 / 출력 레벨과 게인 리덕션
+//! 슬라이더 우클릭 메뉴로 MIDI CC Learn / 해제
+//! 프리셋 목록은 내장 프리셋 + 사용자 프리셋 디렉터리 (`cli preset import` 로 설치한 파일)
+//! 오디오 스레드의 파라미터 변경(프리셋 적용)은 프레임마다 호스트에 보고 (창이 닫혀 있으면 대기)
+
+use crate::{OfflineQuality, PresetType, RealtimeQuality, WarmTapeParams};
+use dsp_core::preset_file::{PresetEntry, PresetSource};
+use dsp_core::{Meters, ParamEdits, PresetBank};
+use nih_plug::prelude::*;
+use nih_plug_egui::egui::{self, Ui};
+use nih_plug_egui::{create_egui_editor, widgets, EguiState};
+use std::sync::Arc;
+
+const WIDTH: u32 = 360;
+const HEIGHT: u32 = 420;
+
+pub(crate) fn default_state() -> Arc<EguiState> {
+    EguiState::from_size(WIDTH, HEIGHT)
+}
+
+pub(crate) fn create(
+    params: Arc<WarmTapeParams>,
+    meters: Arc<Meters>,
+    edits: Arc<ParamEdits>,
+) -> Option<Box<dyn Editor>> {
+    let editor_state = params.editor_state.clone();
+    let ids: Vec<(ParamPtr, String)> = params
+        .param_map()
+        .into_iter()
+        .map(|(id, param, _)| (param, id))
+        .collect();
+    create_egui_editor(
+        editor_state,
+        scan_presets(),
+        |_, _| {},
+        move |egui_ctx, setter, bank| {
+            report_edits(setter, &edits, &ids);
+            egui::CentralPanel::default().show(egui_ctx, |ui| {
+                let midi = MidiLearn {
+                    params: &params,
+                    ids: &ids,
+                };
+                draw(ui, setter, &params, &midi, bank, &meters);
+            });
+            // 미터는 오디오 스레드에서 계속 갱신됨
+            egui_ctx.request_repaint();
+        },
+    )
+}
+
+/// 오디오 스레드에서 바꾼 값을 호스트 파라미터 변경 경로로 보고 (호스트 자동화 / UI 갱신)
+fn report_edits(setter: &ParamSetter, edits: &ParamEdits, ids: &[(ParamPtr, String)]) {
+    edits.drain(|index, normalized| {
+        if let Some(&(param, _)) = ids.get(index) {
+            // SAFETY: 포인터는 params가 소유한 파라미터를 가리키고, 에디터 스레드에서 호출
+            unsafe {
+                setter.raw_context.raw_begin_set_parameter(param);
+                setter
+                    .raw_context
+                    .raw_set_parameter_normalized(param, normalized);
+                setter.raw_context.raw_end_set_parameter(param);
+            }
+        }
+    });
+}
+
+/// 내장 + 사용자 프리셋 목록 (읽을 수 없는 파일은 로그만 남기고 건너뜀)
+fn scan_presets() -> PresetBank {
+    let (bank, errors) = PresetBank::with_user_presets();
+    for (path, err) in errors {
+        nih_log!("skipped preset {}: {err}", path.display());
+    }
+    bank
+}
+
+/// 프리셋 목록 선택: 내장 프리셋은 Preset 파라미터, 파일은 User 슬롯에 로드 후 User 선택
+fn preset_selector(
+    ui: &mut Ui,
+    setter: &ParamSetter,
+    params: &WarmTapeParams,
+    bank: &mut PresetBank,
+) {
+    let current = params.preset.value();
+    let user_name = params
+        .user_preset
+        .read()
+        .ok()
+        .and_then(|slot| slot.as_ref().map(|file| file.name.clone()));
+    let selected_text = match (current, &user_name) {
+        (PresetType::User, Some(name)) => name.clone(),
+        _ => PresetType::variants()[current.to_index()].to_string(),
+    };
+
+    let mut chosen: Option<PresetEntry> = None;
+    egui::ComboBox::from_label(params.preset.name())
+        .selected_text(selected_text)
+        .show_ui(ui, |ui| {
+            for entry in bank.entries() {
+                let selected = match &entry.source {
+                    PresetSource::Builtin => builtin_type(&entry.name) == Some(current),
+                    PresetSource::File(_) => {
+                        current == PresetType::User && user_name.as_deref() == Some(&entry.name)
+                    }
+                };
+                if ui.selectable_label(selected, entry.name.as_str()).clicked() {
+                    chosen = Some(entry.clone());
+                }
+            }
+        });
+    if ui.button("Rescan").clicked() {
+        *bank = scan_presets();
+    }
+
+    let Some(entry) = chosen else {
+        return;
+    };
+    let preset = match entry.source {
+        PresetSource::Builtin => builtin_type(&entry.name).unwrap_or(PresetType::User),
+        PresetSource::File(_) => {
+            params.load_user_entry(&entry);
+            PresetType::User
+        }
+    };
+    setter.begin_set_parameter(&params.preset);
+    setter.set_parameter(&params.preset, preset);
+    setter.end_set_parameter(&params.preset);
+}
+
+/// 내장 프리셋 이름 ("vinyl") -> PresetType
+fn builtin_type(name: &str) -> Option<PresetType> {
+    PresetType::variants()
+        .iter()
+        .position(|variant| variant.eq_ignore_ascii_case(name))
+        .map(PresetType::from_index)
+}
+
+fn draw(
+    ui: &mut Ui,
+    setter: &ParamSetter,
+    params: &WarmTapeParams,
+    midi: &MidiLearn,
+    bank: &mut PresetBank,
+    meters: &Meters,
+) {
+    ui.horizontal(|ui| {
+        preset_selector(ui, setter, params, bank);
+    });
+    ui.horizontal(|ui| {
+        enum_selector::<RealtimeQuality>(ui, setter, &params.realtime_quality);
+        enum_selector::<OfflineQuality>(ui, setter, &params.offline_quality);
+    });
+    ui.separator();
+
+    for param in [
+        &params.drive,
+        &params.warmth,
+        &params.tone,
+        &params.output,
+        &params.drywet,
+        &params.lowpass_hz,
+        &params.lowpass_q,
+        &params.limiter_ceiling,
+        &params.limiter_release,
+    ] {
+        ui.horizontal(|ui| {
+            let response = ui.add(widgets::ParamSlider::for_param(param, setter));
+            ui.label(param.name());
+            midi.attach(ui, &response, param);
+        });
+    }
+
+    ui.separator();
+    let snapshot = meters.snapshot();
+    ui.label(format!(
+        "Output {:.1} dBFS peak, gain reduction {:.1} dB",
+        util::gain_to_db(snapshot.output_peak),
+        snapshot.gain_reduction_db
+    ));
+}
+
+/// 위젯의 파라미터 ID 조회 + CC Learn 메뉴
+struct MidiLearn<'a> {
+    params: &'a WarmTapeParams,
+    ids: &'a [(ParamPtr, String)],
+}
+
+impl MidiLearn<'_> {
+    fn id(&self, param: &impl Param) -> Option<&str> {
+        let ptr = param.as_ptr();
+        self.ids
+            .iter()
+            .find(|(param, _)| *param == ptr)
+            .map(|(_, id)| id.as_str())
+    }
+
+    /// 연결된 CC 표시 + 우클릭 메뉴 (Learn / 해제)
+    fn attach(&self, ui: &mut Ui, response: &egui::Response, param: &impl Param) {
+        let Some(id) = self.id(param) else {
+            return;
+        };
+        let (cc, learning) = {
+            let map = self
+                .params
+                .midi_map
+                .read()
+                .unwrap_or_else(|e| e.into_inner());
+            (map.cc_for(id), map.learning() == Some(id))
+        };
+        if learning {
+            ui.small("Learning...");
+        } else if let Some(cc) = cc {
+            ui.small(format!("CC {cc}"));
+        }
+
+        response.context_menu(|ui| {
+            if learning {
+                if ui.button("Cancel MIDI Learn").clicked() {
+                    self.params
+                        .midi_map
+                        .write()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .cancel_learn();
+                    ui.close_menu();
+                }
+            } else if ui.button("MIDI Learn").clicked() {
+                self.params.learn_cc(id);
+                ui.close_menu();
+            }
+            if cc.is_some() && ui.button("Clear MIDI CC").clicked() {
+                self.params
+                    .midi_map
+                    .write()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .clear(id);
+                ui.close_menu();
+            }
+        });
+    }
+}
+
+fn enum_selector<E: Enum + PartialEq + 'static>(
+    ui: &mut Ui,
+    setter: &ParamSetter,
+    param: &EnumParam<E>,
+) {
+    let current = param.value();
+    egui::ComboBox::from_label(param.name())
+        .selected_text(E::variants()[current.to_index()])
+        .show_ui(ui, |ui| {
+            for (index, name) in E::variants().iter().enumerate() {
+                let variant = E::from_index(index);
+                if ui.selectable_label(variant == current, *name).clicked() {
+                    setter.begin_set_parameter(param);
+                    setter.set_parameter(param, variant);
+                    setter.end_set_parameter(param);
+                }
+            }
+        });
+}
@@ -1,51 +1,726 @@
+use nih_plug::params::persist::PersistentField;
 use nih_plug::prelude::*;
-use dsp_core::{process_block, DspParams};
-use std::sync::Arc;
+use nih_plug_egui::EguiState;
+use dsp_core::{
+    ChainPreset, FieldRange, LevelMeter, MeterSnapshot, Meters, MidiMap, ParamEdits, ParamRamp,
+    PresetFile, Processor, Quality, ScopedFtz, TapeChain, TapeRamps,
+};
+use dsp_core::preset_file::PresetEntry;
+use std::ops::Range;
+use std::sync::{Arc, OnceLock, RwLock};
+
+mod editor;
 
 pub struct WarmTapePlugin {
     params: Arc<WarmTapeParams>,
     sample_rate: f32,
+    /// 채널별 테이프 체인 상태 (협상된 출력 채널 수만큼 initialize에서 할당)
+    channels: Vec<TapeChain>,
+    /// 메인 입력 채널 수 (출력보다 적으면 업믹스)
+    input_channels: usize,
+    ramps: RampBuffers,
+    /// 에디터/호스트와 공유하는 입출력 레벨 미터
+    meters: Arc<Meters>,
+    input_meter: LevelMeter,
+    output_meter: LevelMeter,
+    /// 실시간 / 오프라인 렌더링 구분 (initialize에서 갱신)
+    process_mode: ProcessMode,
+    /// 현재 체인에 적용된 품질
+    quality: Quality,
+    /// 호스트에 마지막으로 보고한 지연 (샘플)
+    reported_latency: u32,
+    /// CC 번호 -> 파라미터 인덱스 (매핑이 바뀐 뒤 첫 CC에서 다시 계산)
+    cc_params: [Option<usize>; 128],
+    /// 마지막으로 선택된 프리셋 (바뀌면 프리셋 필드를 파라미터로 보냄)
+    selected_preset: ChainPreset,
+    /// 현재 체인에 적용된 출력 단계 설정
+    active_output: ChainPreset,
+    /// param_map() 순서의 파라미터 ID / 포인터 (ParamEdits 인덱스)
+    param_ptrs: Vec<(String, ParamPtr)>,
+    /// 오디오 스레드에서 바꾼 파라미터 값 (에디터가 호스트에 보고, 인덱스 = param_ptrs 순서)
+    edits: Arc<ParamEdits>,
+    /// WarmTapeParams::edited_fields() 순서의 파라미터 인덱스
+    field_params: [usize; EDITED_FIELDS],
+    /// 지난 구간에서 보고 대기 중이던 필드 값 (정규화)
+    field_pending: [Option<f32>; EDITED_FIELDS],
+}
+
+/// 프리셋과 연결된 파라미터 수 (WarmTapeParams::preset_fields)
+const PRESET_FIELDS: usize = 7;
+/// 오디오 스레드가 바꾸는 파라미터 수 (WarmTapeParams::edited_fields)
+const EDITED_FIELDS: usize = PRESET_FIELDS + 2;
+/// edited_fields() 안의 필드 위치
+const DRIVE_FIELD: usize = 0;
+const WARMTH_FIELD: usize = 1;
+const DRYWET_FIELD: usize = 2;
+const TONE_FIELD: usize = 7;
+const OUTPUT_FIELD: usize = 8;
+
+/// 기본 CC 매핑 (세션에 매핑이 없을 때): 모듈레이션 휠, 사운드 컨트롤(Timbre / Brightness),
+/// 볼륨, Effects Depth
+const DEFAULT_CC_MAP: &[(u8, &str)] = &[
+    (1, "drive"),
+    (71, "warmth"),
+    (74, "tone"),
+    (7, "output"),
+    (91, "drywet"),
+];
+
+/// 버퍼 단위 샘플별 파라미터 램프 (initialize에서 할당)
+#[derive(Default)]
+struct RampBuffers {
+    drive: Vec<f32>,
+    warmth: Vec<f32>,
+    tone: Vec<f32>,
+    output: Vec<f32>,
+    drywet: Vec<f32>,
+}
+
+impl RampBuffers {
+    fn resize(&mut self, len: usize) {
+        for ramp in [
+            &mut self.drive,
+            &mut self.warmth,
+            &mut self.tone,
+            &mut self.output,
+            &mut self.drywet,
+        ] {
+            ramp.resize(len, 0.0);
+        }
+    }
 }
 
 #[derive(Params)]
 pub struct WarmTapeParams {
+    /// 프리셋 (Vinyl / Warm / Clean / User)
+    #[id = "preset"]
+    pub preset: EnumParam<PresetType>,
+
     #[id = "drive"]
     pub drive: FloatParam,
     
+    /// 포화 하드니스 / 바이어스 / 헤드 범프
     #[id = "warmth"]
     pub warmth: FloatParam,
+
+    /// 톤 (고역 쉘프, -1 어둡게 ~ +1 밝게)
+    #[id = "tone"]
+    pub tone: FloatParam,
+
+    /// 출력 트림
+    #[id = "output"]
+    pub output: FloatParam,
+
+    #[id = "drywet"]
+    pub drywet: FloatParam,
+
+    /// 출력 Lowpass 컷오프
+    #[id = "lowpass_hz"]
+    pub lowpass_hz: FloatParam,
+
+    /// 출력 Lowpass Q
+    #[id = "lowpass_q"]
+    pub lowpass_q: FloatParam,
+
+    /// 리미터 천장 (linear)
+    #[id = "limiter_ceiling"]
+    pub limiter_ceiling: FloatParam,
+
+    /// 리미터 릴리즈
+    #[id = "limiter_release"]
+    pub limiter_release: FloatParam,
+
+    /// 실시간 재생 품질 (포화 단계 오버샘플링)
+    #[id = "realtime_quality"]
+    pub realtime_quality: EnumParam<RealtimeQuality>,
+
+    /// 오프라인 렌더링 품질
+    #[id = "offline_quality"]
+    pub offline_quality: EnumParam<OfflineQuality>,
+
+    /// 사용자 프리셋 (PresetType::User) - 세션에 함께 저장
+    #[persist = "user_preset"]
+    pub user_preset: Arc<RwLock<Option<PresetFile>>>,
+    /// MIDI CC Learn 매핑 - 세션에 함께 저장
+    #[persist = "midi-map"]
+    pub midi_map: Arc<RwLock<MidiMap>>,
+    /// 에디터가 아직 호스트에 보고하지 않은 변경 - 에디터를 열지 않은 세션도 저장 후 복원
+    #[persist = "unreported-edits"]
+    pub unreported_edits: UnreportedEdits,
+    /// 에디터 창 크기 / 열림 상태
+    #[persist = "editor-state"]
+    pub editor_state: Arc<EguiState>,
+}
+
+impl WarmTapeParams {
+    /// 프리셋 뱅크 항목을 User 슬롯에 넣음 (에디터 프리셋 목록에서 선택)
+    pub fn load_user_entry(&self, entry: &PresetEntry) {
+        let file = PresetFile::new(entry.name.clone(), entry.chain);
+        *self.user_preset.write().unwrap_or_else(|e| e.into_inner()) = Some(file);
+    }
+
+    /// 프리셋 필드와 연결된 파라미터 (preset_values() 순서)
+    pub fn preset_fields(&self) -> [&FloatParam; PRESET_FIELDS] {
+        [
+            &self.drive,
+            &self.warmth,
+            &self.drywet,
+            &self.lowpass_hz,
+            &self.lowpass_q,
+            &self.limiter_ceiling,
+            &self.limiter_release,
+        ]
+    }
+
+    /// 오디오 스레드가 ParamEdits로 바꾸는 파라미터: preset_fields() 뒤에 CC 전용 Tone / Output
+    fn edited_fields(&self) -> [&FloatParam; EDITED_FIELDS] {
+        let [drive, warmth, drywet, lowpass_hz, lowpass_q, ceiling, release] = self.preset_fields();
+        [
+            drive,
+            warmth,
+            drywet,
+            lowpass_hz,
+            lowpass_q,
+            ceiling,
+            release,
+            &self.tone,
+            &self.output,
+        ]
+    }
+
+    /// 파라미터의 다음 CC를 Learn (에디터에서 호출)
+    pub fn learn_cc(&self, param_id: &str) {
+        self.midi_map
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .start_learn(param_id);
+    }
+
+    /// 선택된 프리셋의 체인 설정
+    /// User 슬롯이 잠겨 있으면 None (호출자가 이전 값 유지)
+    fn resolve_chain(&self, preset: PresetType) -> Option<ChainPreset> {
+        match preset {
+            PresetType::User => match self.user_preset.try_read() {
+                Ok(slot) => Some(slot.as_ref().map(|file| file.chain).unwrap_or_default()),
+                Err(_) => None,
+            },
+            builtin => Some(builtin.chain_preset()),
+        }
+    }
+}
+
+/// preset_fields() 순서의 프리셋 값
+/// 드라이브 -> drive, 하드니스 -> warmth (포화 하드니스), Dry/Wet, 출력 단계
+/// RIAA / Parallel HF / velocity 임계값은 테이프 체인에 해당 단계가 없으므로 사용하지 않음
+pub fn preset_values(preset: &ChainPreset) -> [f32; PRESET_FIELDS] {
+    [
+        preset.drive_db,
+        preset.hardness,
+        preset.dry_wet,
+        preset.lowpass_hz,
+        preset.lowpass_q,
+        preset.limiter_ceiling,
+        preset.limiter_release_ms,
+    ]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresetType {
+    Vinyl,
+    Warm,
+    Clean,
+    User,
+}
+
+impl Enum for PresetType {
+    fn variants() -> &'static [&'static str] {
+        &["Vinyl", "Warm", "Clean", "User"]
+    }
+
+    fn ids() -> Option<&'static [&'static str]> {
+        Some(&["vinyl", "warm", "clean", "user"])
+    }
+
+    fn to_index(self) -> usize {
+        match self {
+            PresetType::Vinyl => 0,
+            PresetType::Warm => 1,
+            PresetType::Clean => 2,
+            PresetType::User => 3,
+        }
+    }
+
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => PresetType::Vinyl,
+            1 => PresetType::Warm,
+            2 => PresetType::Clean,
+            _ => PresetType::User,
+        }
+    }
+}
+
+impl PresetType {
+    /// dsp-core 공유 프리셋 정의 (User는 슬롯이 비었을 때의 기본값)
+    pub fn chain_preset(self) -> ChainPreset {
+        match self {
+            PresetType::Vinyl => ChainPreset::vinyl(),
+            PresetType::Warm => ChainPreset::warm(),
+            PresetType::Clean => ChainPreset::clean(),
+            PresetType::User => ChainPreset::default(),
+        }
+    }
+}
+
+/// 실시간 처리 품질
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RealtimeQuality {
+    Eco,
+    Normal,
+    High,
+}
+
+impl Enum for RealtimeQuality {
+    fn variants() -> &'static [&'static str] {
+        &["Eco", "Normal", "High"]
+    }
+
+    fn ids() -> Option<&'static [&'static str]> {
+        Some(&["eco", "normal", "high"])
+    }
+
+    fn to_index(self) -> usize {
+        match self {
+            RealtimeQuality::Eco => 0,
+            RealtimeQuality::Normal => 1,
+            RealtimeQuality::High => 2,
+        }
+    }
+
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => RealtimeQuality::Eco,
+            1 => RealtimeQuality::Normal,
+            _ => RealtimeQuality::High,
+        }
+    }
+}
+
+impl From<RealtimeQuality> for Quality {
+    fn from(quality: RealtimeQuality) -> Self {
+        match quality {
+            RealtimeQuality::Eco => Quality::Eco,
+            RealtimeQuality::Normal => Quality::Normal,
+            RealtimeQuality::High => Quality::High,
+        }
+    }
+}
+
+/// 오프라인 렌더링 품질
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OfflineQuality {
+    High,
+    Ultra,
+}
+
+impl Enum for OfflineQuality {
+    fn variants() -> &'static [&'static str] {
+        &["High", "Ultra"]
+    }
+
+    fn ids() -> Option<&'static [&'static str]> {
+        Some(&["high", "ultra"])
+    }
+
+    fn to_index(self) -> usize {
+        match self {
+            OfflineQuality::High => 0,
+            OfflineQuality::Ultra => 1,
+        }
+    }
+
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => OfflineQuality::High,
+            _ => OfflineQuality::Ultra,
+        }
+    }
+}
+
+impl From<OfflineQuality> for Quality {
+    fn from(quality: OfflineQuality) -> Self {
+        match quality {
+            OfflineQuality::High => Quality::High,
+            OfflineQuality::Ultra => Quality::Ultra,
+        }
+    }
 }
 
 impl Default for WarmTapePlugin {
     fn default() -> Self {
+        let sample_rate = 48000.0;
+        let params = Arc::new(WarmTapeParams::default());
+        let param_ptrs: Vec<(String, ParamPtr)> = params
+            .param_map()
+            .into_iter()
+            .map(|(id, param, _)| (id, param))
+            .collect();
+        let field_params = params.edited_fields().map(|param| {
+            param_ptrs
+                .iter()
+                .position(|(_, ptr)| *ptr == param.as_ptr())
+                .unwrap_or(usize::MAX)
+        });
+        let selected_preset = params.preset.value().chain_preset();
+        let edits = Arc::new(ParamEdits::new(param_ptrs.len()));
+        let ids = param_ptrs.iter().map(|(id, _)| id.clone()).collect();
+        params.unreported_edits.attach(edits.clone(), ids);
         Self {
-            params: Arc::new(WarmTapeParams::default()),
-            sample_rate: 48000.0,
+            param_ptrs,
+            edits,
+            field_params,
+            field_pending: [None; EDITED_FIELDS],
+            cc_params: [None; 128],
+            selected_preset,
+            active_output: selected_preset,
+            params,
+            sample_rate,
+            channels: (0..2).map(|_| TapeChain::new(sample_rate)).collect(),
+            input_channels: 2,
+            ramps: RampBuffers::default(),
+            meters: Arc::new(Meters::default()),
+            input_meter: LevelMeter::new(sample_rate),
+            output_meter: LevelMeter::new(sample_rate),
+            process_mode: ProcessMode::Realtime,
+            quality: Quality::default(),
+            reported_latency: 0,
         }
     }
 }
 
+impl WarmTapePlugin {
+    /// 미터 값 읽기용 핸들
+    pub fn meters(&self) -> Arc<Meters> {
+        self.meters.clone()
+    }
+
+    /// 오디오 스레드에서 바꾼 파라미터 값 (에디터 / 테스트 하네스가 호스트에 보고)
+    pub fn param_edits(&self) -> Arc<ParamEdits> {
+        self.edits.clone()
+    }
+
+    /// User 프리셋 슬롯 핸들 (테스트 하네스)
+    pub fn user_preset(&self) -> Arc<RwLock<Option<PresetFile>>> {
+        self.params.user_preset.clone()
+    }
+
+    /// CC Learn 매핑 핸들 (에디터 외부 / 테스트 하네스)
+    pub fn midi_map(&self) -> Arc<RwLock<MidiMap>> {
+        self.params.midi_map.clone()
+    }
+
+    /// param_map() 순서의 인덱스 (ParamEdits 슬롯)
+    fn param_index(&self, param: &impl Param) -> Option<usize> {
+        let ptr = param.as_ptr();
+        self.param_ptrs.iter().position(|(_, other)| *other == ptr)
+    }
+
+    /// 호스트가 가진 정규화 값 (ParamEdits 기록 이후 자동화로 바뀌었는지 비교)
+    fn host_normalized(&self, index: usize) -> f32 {
+        self.param_ptrs.get(index).map_or(0.0, |(_, ptr)| {
+            // SAFETY: 포인터는 self.params가 소유한 파라미터를 가리킴
+            unsafe { ptr.unmodulated_normalized_value() }
+        })
+    }
+
+    /// 보고 대기 중인 값 (기록 이후 호스트 값이 바뀌었으면 None, 호스트 값을 따름)
+    fn pending(&self, index: usize) -> Option<f32> {
+        self.edits.pending(index, self.host_normalized(index))
+    }
+
+    /// 오디오 스레드의 파라미터 변경 기록 (에디터가 호스트에 보고)
+    fn push_edit(&self, index: usize, normalized: f32) {
+        self.edits
+            .push(index, normalized, self.host_normalized(index));
+    }
+
+    /// 보고 대기 중인 값(MIDI CC, 프로그램 체인지)을 반영한 파라미터 값
+    fn value<P: Param>(&self, param: &P) -> P::Plain {
+        match self
+            .param_index(param)
+            .and_then(|index| self.pending(index))
+        {
+            Some(normalized) => param.preview_plain(normalized),
+            None => param.value(),
+        }
+    }
+
+    /// 프리셋 값을 프리셋 파라미터에 기록 (호스트 보고 전에도 체인이 새 값을 사용)
+    fn push_preset(&self, preset: &ChainPreset) {
+        let fields = self.params.preset_fields();
+        for ((param, index), value) in fields
+            .iter()
+            .zip(self.field_params)
+            .zip(preset_values(preset))
+        {
+            self.push_edit(index, param.preview_normalized(value));
+        }
+    }
+
+    /// 보고 대기 중인 값을 반영한 필드 값
+    fn field_value(&self, field: usize) -> f32 {
+        let param = self.params.edited_fields()[field];
+        match self.pending(self.field_params[field]) {
+            Some(normalized) => param.preview_plain(normalized),
+            None => param.value(),
+        }
+    }
+
+    /// 현재 출력 단계 설정 (Lowpass / 리미터 필드만 사용)
+    fn current_output(&self) -> ChainPreset {
+        ChainPreset {
+            lowpass_hz: self.field_value(3),
+            lowpass_q: self.field_value(4),
+            limiter_ceiling: self.field_value(5),
+            limiter_release_ms: self.field_value(6),
+            ..ChainPreset::default()
+        }
+    }
+
+    /// 보고가 끝나거나 자동화로 버려진 필드는 스무더를 마지막 기록 값에서 시작
+    /// (보고된 경우 램프 없음, 자동화가 이긴 경우 기록 값 -> 호스트 값으로 램프)
+    fn settle_edits(&mut self) {
+        let fields = self.params.edited_fields();
+        for (field, param) in fields.iter().enumerate() {
+            let pending = self.pending(self.field_params[field]);
+            if let (Some(last), None) = (self.field_pending[field], pending) {
+                param.smoothed.reset(param.preview_plain(last));
+                param.smoothed.set_target(self.sample_rate, param.value());
+            }
+            self.field_pending[field] = pending;
+        }
+    }
+
+    /// 호스트 처리 모드에 맞는 품질 설정
+    fn target_quality(&self) -> Quality {
+        match self.process_mode {
+            ProcessMode::Offline => self.params.offline_quality.value().into(),
+            ProcessMode::Realtime | ProcessMode::Buffered => {
+                self.params.realtime_quality.value().into()
+            }
+        }
+    }
+
+    /// 품질 적용 (오버샘플링 배율이 바뀌면 지연도 바뀜)
+    fn apply_quality(&mut self, quality: Quality) {
+        for chain in &mut self.channels {
+            chain.set_oversampling(quality.oversampling());
+        }
+        self.quality = quality;
+    }
+
+    fn latency(&self) -> u32 {
+        self.channels.first().map_or(0, |chain| chain.latency_samples()) as u32
+    }
+
+    /// MIDI 이벤트 처리 (render_events가 이벤트 타이밍에서 호출)
+    /// CC / 프로그램 체인지는 ParamEdits에 기록 (에디터가 호스트에 보고, 그 전에는 pending 값 사용)
+    pub fn handle_midi(&mut self, event: NoteEvent<()>) {
+        match event {
+            NoteEvent::MidiCC { cc, value, .. } => {
+                // 에디터가 매핑을 수정 중이면 이전 매핑 표 사용 (Learn은 다음 CC에서)
+                if let Ok(mut map) = self.params.midi_map.try_write() {
+                    map.handle_cc(cc);
+                    if map.take_changed() {
+                        self.cc_params = resolve_cc(&map, &self.param_ptrs);
+                    }
+                }
+                if let Some(index) = self.cc_params.get(cc as usize).copied().flatten() {
+                    self.push_edit(index, value);
+                }
+            }
+            NoteEvent::MidiProgramChange { program, .. } => {
+                // 프로그램 번호 = 프리셋 인덱스 (Vinyl, Warm, Clean, User)
+                if (program as usize) < PresetType::variants().len() {
+                    let preset = PresetType::from_index(program as usize);
+                    let normalized = self.params.preset.preview_normalized(preset);
+                    if let Some(index) = self.param_index(&self.params.preset) {
+                        self.push_edit(index, normalized);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// 매핑의 파라미터 ID를 param_map() 인덱스로 변환 (오디오 스레드, 할당 없음)
+fn resolve_cc(map: &MidiMap, param_ptrs: &[(String, ParamPtr)]) -> [Option<usize>; 128] {
+    let mut cc_params = [None; 128];
+    for mapping in map.mappings() {
+        if let Some(slot) = cc_params.get_mut(mapping.cc as usize) {
+            *slot = param_ptrs.iter().position(|(id, _)| *id == mapping.param_id);
+        }
+    }
+    cc_params
+}
+
+/// 세션에 저장하는 미보고 변경: (파라미터 ID, 정규화 값, 기록 시점의 호스트 값)
+type SavedEdit = (String, f32, f32);
+
+/// ParamEdits의 미보고 변경을 세션 상태로 저장 / 복원
+/// 복원 시 호스트 값이 저장 당시와 다르면(자동화) ParamEdits가 기록을 버림
+#[derive(Default)]
+pub struct UnreportedEdits {
+    /// 플러그인의 ParamEdits와 param_map() 순서의 파라미터 ID (플러그인 생성 시 연결)
+    target: OnceLock<(Arc<ParamEdits>, Vec<String>)>,
+}
+
+impl UnreportedEdits {
+    fn attach(&self, edits: Arc<ParamEdits>, ids: Vec<String>) {
+        let _ = self.target.set((edits, ids));
+    }
+}
+
+impl<'a> PersistentField<'a, Vec<SavedEdit>> for UnreportedEdits {
+    fn set(&self, saved: Vec<SavedEdit>) {
+        let Some((edits, ids)) = self.target.get() else {
+            return;
+        };
+        for (id, normalized, host) in saved {
+            if let Some(index) = ids.iter().position(|other| *other == id) {
+                edits.push(index, normalized, host);
+            }
+        }
+    }
+
+    fn map<F, R>(&self, f: F) -> R
+    where
+        F: Fn(&Vec<SavedEdit>) -> R,
+    {
+        let saved = self.target.get().map_or_else(Vec::new, |(edits, ids)| {
+            edits
+                .unreported()
+                .map(|(index, normalized, host)| (ids[index].clone(), normalized, host))
+                .collect()
+        });
+        f(&saved)
+    }
+}
+
+/// dsp-core 프리셋 필드 범위 -> 호스트 파라미터 범위
+fn linear_range(range: FieldRange) -> FloatRange {
+    FloatRange::Linear {
+        min: range.min,
+        max: range.max,
+    }
+}
+
+fn skewed_range(range: FieldRange, skew: f32) -> FloatRange {
+    FloatRange::Skewed {
+        min: range.min,
+        max: range.max,
+        factor: FloatRange::skew_factor(skew),
+    }
+}
+
 impl Default for WarmTapeParams {
     fn default() -> Self {
+        // 프리셋 파라미터 기본값 = 기본 프리셋(Warm)
+        let warm = ChainPreset::warm();
         Self {
+            preset: EnumParam::new("Preset", PresetType::Warm),
             drive: FloatParam::new(
                 "Drive",
-                0.0,
-                FloatRange::Linear { min: 0.0, max: 20.0 },
+                warm.drive_db,
+                linear_range(ChainPreset::DRIVE_DB),
             )
             .with_unit(" dB")
             .with_smoother(SmoothingStyle::Linear(50.0)),
             
             warmth: FloatParam::new(
                 "Warmth",
-                0.5,
-                FloatRange::Linear { min: 0.0, max: 1.0 },
+                warm.hardness,
+                linear_range(ChainPreset::HARDNESS),
+            )
+            .with_smoother(SmoothingStyle::Linear(50.0)),
+
+            tone: FloatParam::new(
+                "Tone",
+                0.0,
+                FloatRange::Linear { min: -1.0, max: 1.0 },
             )
             .with_smoother(SmoothingStyle::Linear(50.0)),
+
+            output: FloatParam::new(
+                "Output",
+                0.0,
+                FloatRange::Linear { min: -12.0, max: 12.0 },
+            )
+            .with_unit(" dB")
+            .with_smoother(SmoothingStyle::Linear(50.0)),
+
+            drywet: FloatParam::new(
+                "Dry/Wet",
+                warm.dry_wet,
+                linear_range(ChainPreset::DRY_WET),
+            )
+            .with_unit("%")
+            .with_value_to_string(formatters::v2s_f32_percentage(0))
+            .with_smoother(SmoothingStyle::Linear(50.0)),
+
+            lowpass_hz: FloatParam::new(
+                "Lowpass",
+                warm.lowpass_hz,
+                skewed_range(ChainPreset::LOWPASS_HZ, -2.0),
+            )
+            .with_unit(" Hz")
+            .with_step_size(10.0),
+
+            lowpass_q: FloatParam::new(
+                "Lowpass Q",
+                warm.lowpass_q,
+                skewed_range(ChainPreset::LOWPASS_Q, -1.0),
+            )
+            .with_step_size(0.001),
+
+            limiter_ceiling: FloatParam::new(
+                "Limiter Ceiling",
+                warm.limiter_ceiling,
+                linear_range(ChainPreset::LIMITER_CEILING),
+            )
+            .with_step_size(0.01),
+
+            limiter_release: FloatParam::new(
+                "Limiter Release",
+                warm.limiter_release_ms,
+                skewed_range(ChainPreset::LIMITER_RELEASE_MS, -2.0),
+            )
+            .with_unit(" ms")
+            .with_step_size(1.0),
+
+            realtime_quality: EnumParam::new("Realtime Quality", RealtimeQuality::Normal),
+            offline_quality: EnumParam::new("Offline Quality", OfflineQuality::High),
+            user_preset: Arc::new(RwLock::new(None)),
+            midi_map: Arc::new(RwLock::new(MidiMap::with_mappings(DEFAULT_CC_MAP))),
+            unreported_edits: UnreportedEdits::default(),
+            editor_state: editor::default_state(),
         }
     }
 }
 
+const fn io_layout(inputs: u32, outputs: u32, name: &'static str) -> AudioIOLayout {
+    AudioIOLayout {
+        main_input_channels: NonZeroU32::new(inputs),
+        main_output_channels: NonZeroU32::new(outputs),
+        names: PortNames {
+            layout: Some(name),
+            ..PortNames::const_default()
+        },
+        ..AudioIOLayout::const_default()
+    }
+}
+
 impl Plugin for WarmTapePlugin {
     const NAME: &'static str = "Warm Tape";
     const VENDOR: &'static str = "Rustrover Audio";
@@ -53,13 +728,15 @@ impl Plugin for WarmTapePlugin {
     const EMAIL: &'static str = "info@rustrover.audio";
     const VERSION: &'static str = env!("CARGO_PKG_VERSION");
 
-    const AUDIO_IO_LAYOUTS: &'static [AudioIOLayout] = &[AudioIOLayout {
-        main_input_channels: NonZeroU32::new(2),
-        main_output_channels: NonZeroU32::new(2),
-        ..AudioIOLayout::const_default()
-    }];
+    const AUDIO_IO_LAYOUTS: &'static [AudioIOLayout] = &[
+        io_layout(2, 2, "Stereo"),
+        io_layout(1, 1, "Mono"),
+        io_layout(1, 2, "Mono to Stereo"),
+        io_layout(6, 6, "5.1"),
+        io_layout(8, 8, "7.1"),
+    ];
 
-    const MIDI_INPUT: MidiConfig = MidiConfig::None;
+    const MIDI_INPUT: MidiConfig = MidiConfig::MidiCCs;
     const MIDI_OUTPUT: MidiConfig = MidiConfig::None;
     const SAMPLE_ACCURATE_AUTOMATION: bool = true;
 
@@ -70,57 +747,196 @@ impl Plugin for WarmTapePlugin {
         self.params.clone()
     }
 
+    fn editor(&mut self, _async_executor: AsyncExecutor<Self>) -> Option<Box<dyn Editor>> {
+        editor::create(self.params.clone(), self.meters.clone(), self.edits.clone())
+    }
+
     fn initialize(
         &mut self,
-        _audio_io_layout: &AudioIOLayout,
+        audio_io_layout: &AudioIOLayout,
         buffer_config: &BufferConfig,
-        _context: &mut impl InitContext<Self>,
+        context: &mut impl InitContext<Self>,
     ) -> bool {
         self.sample_rate = buffer_config.sample_rate;
+        let outputs = audio_io_layout.main_output_channels.map_or(0, NonZeroU32::get) as usize;
+        self.input_channels = audio_io_layout
+            .main_input_channels
+            .map_or(0, NonZeroU32::get) as usize;
+        // 세션의 파라미터 값이 프리셋보다 우선 (이후 프리셋 선택이 바뀔 때만 필드를 보냄)
+        let selected = self.value(&self.params.preset);
+        self.selected_preset = self
+            .params
+            .resolve_chain(selected)
+            .unwrap_or_else(|| selected.chain_preset());
+        self.active_output = self.current_output();
+        self.channels = (0..outputs)
+            .map(|_| {
+                let mut chain = TapeChain::new(self.sample_rate);
+                chain.set_output_stage(Some(&self.active_output));
+                chain
+            })
+            .collect();
+        self.ramps.resize(buffer_config.max_buffer_size as usize);
+        self.input_meter = LevelMeter::new(self.sample_rate);
+        self.output_meter = LevelMeter::new(self.sample_rate);
+        self.process_mode = buffer_config.process_mode;
+        self.apply_quality(self.target_quality());
+        self.reported_latency = self.latency();
+        context.set_latency_samples(self.reported_latency);
         true
     }
 
-    fn reset(&mut self) {}
+    fn reset(&mut self) {
+        for chain in &mut self.channels {
+            chain.reset();
+        }
+        self.input_meter.reset();
+        self.output_meter.reset();
+    }
 
     fn process(
         &mut self,
         buffer: &mut Buffer,
         _aux: &mut AuxiliaryBuffers,
-        _context: &mut impl ProcessContext<Self>,
+        context: &mut impl ProcessContext<Self>,
     ) -> ProcessStatus {
-        let drive = self.params.drive.smoothed.next();
-        let sample_rate = self.sample_rate;
-        
-        let params = DspParams { drive_db: drive };
+        // 호스트 FTZ 설정과 무관하게 처리 중 비정규화 수 차단
+        let _ftz = ScopedFtz::enable();
+        let events = std::iter::from_fn(|| context.next_event());
+        if let Some(latency) = self.render_events(buffer, events) {
+            context.set_latency_samples(latency);
+        }
+        ProcessStatus::Normal
+    }
+}
 
-        for mut channel_samples in buffer.iter_samples() {
-            let params = DspParams { drive_db: drive };
-            
-            for sample in channel_samples.iter_mut() {
-                let x = *sample * db_to_linear(drive);
-                let y = soft_clip(x);
-                *sample = y;
+impl WarmTapePlugin {
+    /// 호스트 컨텍스트 없이 한 버퍼 처리 (MIDI 이벤트 없음, 헤드리스 하네스)
+    /// 지연이 바뀌었으면 새 지연을 반환 (호출자가 호스트에 보고)
+    pub fn render(&mut self, buffer: &mut Buffer) -> Option<u32> {
+        self.render_events(buffer, std::iter::empty())
+    }
+
+    /// MIDI 이벤트(CC, 프로그램 체인지)를 타이밍 위치에서 적용하며 한 버퍼 처리
+    /// (process / 헤드리스 하네스 공용)
+    pub fn render_events(
+        &mut self,
+        buffer: &mut Buffer,
+        events: impl IntoIterator<Item = NoteEvent<()>>,
+    ) -> Option<u32> {
+        let samples = buffer.samples();
+        let mut start = 0;
+        let mut latency = None;
+        for event in events {
+            let timing = (event.timing() as usize).clamp(start, samples);
+            if timing > start {
+                latency = self.render_range(buffer, start..timing).or(latency);
+                start = timing;
             }
+            self.handle_midi(event);
+        }
+        if start < samples {
+            latency = self.render_range(buffer, start..samples).or(latency);
         }
+        latency
+    }
 
-        ProcessStatus::Normal
+    /// 버퍼의 range 구간 처리
+    fn render_range(&mut self, buffer: &mut Buffer, range: Range<usize>) -> Option<u32> {
+        // 프리셋 선택이 바뀌면 프리셋 필드를 파라미터로 보냄 (User 슬롯이 잠겨 있으면 다음 구간에서)
+        if let Some(selected) = self.params.resolve_chain(self.value(&self.params.preset)) {
+            if selected != self.selected_preset {
+                self.selected_preset = selected;
+                self.push_preset(&selected);
+            }
+        }
+        self.settle_edits();
+
+        let output = self.current_output();
+        if output != self.active_output {
+            for chain in &mut self.channels {
+                chain.set_output_stage(Some(&output));
+            }
+            self.active_output = output;
+        }
+
+        let quality = self.target_quality();
+        if quality != self.quality {
+            self.apply_quality(quality);
+        }
+        let latency = self.latency();
+        let latency_changed = (latency != self.reported_latency).then_some(latency);
+        self.reported_latency = latency;
+
+        // 샘플 단위 자동화: 스무더 값을 구간 길이만큼 램프로 받아 체인에 전달
+        let len = range.len().min(self.ramps.drive.len());
+        let pending: [Option<f32>; EDITED_FIELDS] =
+            std::array::from_fn(|field| self.pending(self.field_params[field]));
+        let ramps = &mut self.ramps;
+        self.params.drive.smoothed.next_block(&mut ramps.drive, len);
+        self.params.warmth.smoothed.next_block(&mut ramps.warmth, len);
+        self.params.tone.smoothed.next_block(&mut ramps.tone, len);
+        self.params.output.smoothed.next_block(&mut ramps.output, len);
+        self.params.drywet.smoothed.next_block(&mut ramps.drywet, len);
+        // 보고 대기 중인 값(프리셋, MIDI CC)은 고정 목표값 (체인 내부 스무딩)
+        let fields = self.params.edited_fields();
+        let ramp = |field: usize, values| param_ramp(fields[field], pending[field], values, len);
+        let tape_ramps = TapeRamps {
+            drive_db: ramp(DRIVE_FIELD, &ramps.drive),
+            warmth: ramp(WARMTH_FIELD, &ramps.warmth),
+            tone: ramp(TONE_FIELD, &ramps.tone),
+            output_db: ramp(OUTPUT_FIELD, &ramps.output),
+            dry_wet: ramp(DRYWET_FIELD, &ramps.drywet),
+        };
+
+        let channels = buffer.as_slice();
+        upmix(channels, self.input_channels, &range);
+        self.input_meter.measure(channels.iter().map(|samples| &samples[range.clone()]));
+        for (channel_samples, chain) in channels.iter_mut().zip(self.channels.iter_mut()) {
+            chain.process_block(&mut channel_samples[range.clone()], &tape_ramps);
+        }
+        self.output_meter.measure(channels.iter().map(|samples| &samples[range.clone()]));
+
+        // 테이프 체인은 적응형 경로가 없으므로 레벨과 리미터만 게시
+        let gain_reduction_db = self
+            .channels
+            .iter()
+            .map(TapeChain::gain_reduction_db)
+            .fold(0.0, f32::max);
+        self.meters.publish(&MeterSnapshot {
+            input_peak: self.input_meter.peak(),
+            input_rms: self.input_meter.rms(),
+            output_peak: self.output_meter.peak(),
+            output_rms: self.output_meter.rms(),
+            gain_reduction_db,
+            ..MeterSnapshot::default()
+        });
+
+        latency_changed
     }
 }
 
-#[inline]
-fn db_to_linear(db: f32) -> f32 {
-    10.0_f32.powf(db / 20.0)
+/// 보고 대기 중인 값이 있으면 고정 목표값(체인 내부 스무딩), 없으면 파라미터 스무더 램프
+fn param_ramp<'a>(
+    param: &FloatParam,
+    pending: Option<f32>,
+    values: &'a [f32],
+    len: usize,
+) -> ParamRamp<'a> {
+    match pending {
+        Some(normalized) => ParamRamp::Constant(param.preview_plain(normalized)),
+        None => ParamRamp::PerSample(&values[..len]),
+    }
 }
 
-#[inline]
-fn soft_clip(x: f32) -> f32 {
-    const THRESHOLD: f32 = 1.0;
-    if x > THRESHOLD {
-        THRESHOLD + (x - THRESHOLD) / (1.0 + (x - THRESHOLD).abs())
-    } else if x < -THRESHOLD {
-        -THRESHOLD + (x + THRESHOLD) / (1.0 + (x + THRESHOLD).abs())
-    } else {
-        x
+/// 입력보다 많은 출력 채널(모노 -> 스테레오)은 range 구간에 입력 채널을 복사해 채움
+fn upmix(channels: &mut [&mut [f32]], input_channels: usize, range: &Range<usize>) {
+    if input_channels == 0 {
+        return;
+    }
+    for index in input_channels..channels.len() {
+        let (sources, targets) = channels.split_at_mut(index);
+        targets[0][range.clone()].copy_from_slice(&sources[index % input_channels][range.clone()]);
     }
 }
 
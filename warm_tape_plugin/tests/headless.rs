@@ -0,0 +1,341 @@
+//! 호스트 없이 WarmTapePlugin 실행: 안정성 / 결정성 / 리셋 / 버퍼 크기 독립성
+
+use nih_plug::prelude::*;
+use plugin_harness::{assert_finite_normal, max_difference, noise, sine, Harness};
+use dsp_core::{ChainPreset, PresetFile};
+use warm_tape_plugin::{preset_values, WarmTapeParams, WarmTapePlugin};
+
+const SR: f32 = 48000.0;
+
+fn harness(layout: usize, sample_rate: f32, max_buffer: usize) -> Harness<WarmTapePlugin> {
+    let mut harness = Harness::new(WarmTapePlugin::default(), |plugin, buffer, _| {
+        plugin.render(buffer)
+    });
+    let layout = &WarmTapePlugin::AUDIO_IO_LAYOUTS[layout];
+    assert!(harness.initialize(layout, sample_rate, max_buffer, ProcessMode::Realtime));
+    harness
+}
+
+fn program(channels: usize, sample_rate: f32, len: usize) -> Vec<Vec<f32>> {
+    (0..channels)
+        .map(|channel| {
+            let tone = sine(110.0 * (channel + 1) as f32, sample_rate, len, 0.6);
+            let hiss = noise(channel as u32 + 7, len, 0.05);
+            tone.iter().zip(&hiss).map(|(a, b)| a + b).collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_stable_across_configs() {
+    for (layout, plugin_layout) in WarmTapePlugin::AUDIO_IO_LAYOUTS.iter().enumerate() {
+        let inputs = plugin_layout.main_input_channels.map_or(0, NonZeroU32::get) as usize;
+        for sample_rate in [44100.0, 96000.0] {
+            let mut harness = harness(layout, sample_rate, 256);
+            let input = program(inputs, sample_rate, sample_rate as usize / 2);
+            let first = harness.process(&input, &[256, 5]);
+
+            // 자동화: 최대 드라이브 / 워밍 / 밝은 톤
+            harness.set_parameter("drive", 1.0);
+            harness.set_parameter("warmth", 1.0);
+            harness.set_parameter("tone", 1.0);
+            let second = harness.process(&input, &[31, 256]);
+
+            let silence = vec![vec![0.0; sample_rate as usize / 2]; inputs];
+            let tail = harness.process(&silence, &[256]);
+            for output in [&first, &second, &tail] {
+                assert_finite_normal(output);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_deterministic_and_reset() {
+    let input = program(2, SR, 24000);
+    let mut first = harness(0, SR, 512);
+    first.set_parameter("warmth", 0.9);
+    let reference = first.process(&input, &[512]);
+
+    let mut second = harness(0, SR, 512);
+    second.set_parameter("warmth", 0.9);
+    assert_eq!(
+        max_difference(&reference, &second.process(&input, &[512])),
+        0.0
+    );
+
+    // 스무딩이 끝난 뒤 리셋하면 같은 입력에 같은 출력
+    second.reset();
+    let after_reset = second.process(&input, &[512]);
+    second.reset();
+    assert_eq!(
+        max_difference(&after_reset, &second.process(&input, &[512])),
+        0.0
+    );
+}
+
+#[test]
+fn test_buffer_size_does_not_change_output() {
+    let input = program(2, SR, 48000);
+    let render = |blocks: &[usize]| {
+        let mut harness = harness(0, SR, 1024);
+        harness.set_parameter("drive", 0.6);
+        harness.set_parameter("tone", 0.2);
+        harness.process(&input, blocks)
+    };
+    let reference = render(&[1024]);
+    for blocks in [&[64][..], &[1, 9, 333]] {
+        let difference = max_difference(&reference, &render(blocks));
+        assert!(difference < 1e-6, "{blocks:?}: {difference}");
+    }
+}
+
+#[test]
+fn test_cc_applies_at_timing() {
+    let input = program(2, SR, 256);
+    // 같은 위치에서 나뉜 블록과 비교: CC 전 구간은 그대로, 이후 구간부터 Output 최대
+    let reference = harness(0, SR, 256).process(&input, &[128]);
+    let mut timed = Harness::new(WarmTapePlugin::default(), |plugin, buffer, _| {
+        let event = NoteEvent::MidiCC {
+            timing: 128,
+            channel: 0,
+            cc: 7,
+            value: 1.0,
+        };
+        plugin.render_events(buffer, [event])
+    });
+    let layout = &WarmTapePlugin::AUDIO_IO_LAYOUTS[0];
+    assert!(timed.initialize(layout, SR, 256, ProcessMode::Realtime));
+    let output = timed.process(&input, &[256]);
+
+    let split = |output: &[Vec<f32>], range: std::ops::Range<usize>| -> Vec<Vec<f32>> {
+        output
+            .iter()
+            .map(|channel| channel[range.clone()].to_vec())
+            .collect()
+    };
+    assert_eq!(
+        max_difference(&split(&reference, 0..128), &split(&output, 0..128)),
+        0.0
+    );
+    assert!(max_difference(&split(&reference, 128..256), &split(&output, 128..256)) > 1e-4);
+
+    // 파라미터는 직접 바뀌지 않고 호스트 보고 후에 반영
+    assert_eq!(timed.parameter("output"), 0.5);
+    let edits = timed.plugin().param_edits();
+    timed.report_edits(&edits);
+    assert_eq!(timed.parameter("output"), 1.0);
+}
+
+#[test]
+fn test_cc_learn_controls_parameter() {
+    let mut harness = harness(0, SR, 256);
+    let cc = |cc, value| NoteEvent::MidiCC {
+        timing: 0,
+        channel: 0,
+        cc,
+        value,
+    };
+    let edits = harness.plugin().param_edits();
+    let tone = harness.parameter("tone");
+    harness.plugin_mut().handle_midi(cc(20, 1.0));
+    harness.report_edits(&edits);
+    assert_eq!(harness.parameter("tone"), tone);
+
+    // Learn은 기본 매핑(CC 74 -> tone)을 새 CC로 교체
+    let midi_map = harness.plugin().midi_map();
+    midi_map.write().unwrap().start_learn("tone");
+    harness.plugin_mut().handle_midi(cc(20, 1.0));
+    harness.report_edits(&edits);
+    assert_eq!(harness.parameter("tone"), 1.0);
+    harness.plugin_mut().handle_midi(cc(74, 0.25));
+    harness.report_edits(&edits);
+    assert_eq!(harness.parameter("tone"), 1.0);
+    harness.plugin_mut().handle_midi(cc(20, 0.25));
+    harness.report_edits(&edits);
+    assert_eq!(harness.parameter("tone"), 0.25);
+
+    // 에디터에서 매핑 해제: 다음 CC부터 무시
+    midi_map.write().unwrap().clear("tone");
+    harness.plugin_mut().handle_midi(cc(20, 1.0));
+    harness.report_edits(&edits);
+    assert_eq!(harness.parameter("tone"), 0.25);
+}
+
+#[test]
+fn test_program_change_selects_preset() {
+    let mut harness = harness(0, SR, 256);
+    let input = program(2, SR, 256);
+    let program_change = |program| NoteEvent::MidiProgramChange {
+        timing: 0,
+        channel: 0,
+        program,
+    };
+    // 파라미터는 직접 바뀌지 않고 호스트 보고 경로(ParamEdits)로 전달
+    let edits = harness.plugin().param_edits();
+    for (program, normalized) in [(0, 0.0), (2, 2.0 / 3.0), (3, 1.0)] {
+        harness.plugin_mut().handle_midi(program_change(program));
+        assert!((harness.parameter("preset") - normalized).abs() > 0.1);
+        harness.report_edits(&edits);
+        assert!((harness.parameter("preset") - normalized).abs() < 1e-6);
+    }
+    // 프리셋 수 밖의 프로그램은 무시
+    harness.plugin_mut().handle_midi(program_change(10));
+    harness.report_edits(&edits);
+    assert_eq!(harness.parameter("preset"), 1.0);
+
+    // 보고 전에도 선택한 프리셋의 필드를 보냄
+    harness.plugin_mut().handle_midi(program_change(0));
+    harness.process(&input, &[256]);
+    harness.report_edits(&edits);
+    let vinyl = ChainPreset::vinyl();
+    let drive = WarmTapeParams::default()
+        .drive
+        .preview_normalized(vinyl.drive_db);
+    assert!((harness.parameter("drive") - drive).abs() < 1e-4);
+}
+
+#[test]
+fn test_automation_overrides_unreported_cc() {
+    // 에디터를 열지 않은 세션: CC 값은 보고되지 않은 채 남아 있음 (CC 1 -> drive)
+    let cc = |value| NoteEvent::MidiCC {
+        timing: 0,
+        channel: 0,
+        cc: 1,
+        value,
+    };
+    let input = program(2, SR, 256);
+    let mut controlled = harness(0, SR, 256);
+    controlled.plugin_mut().handle_midi(cc(1.0));
+    controlled.set_parameter("drive", 0.6);
+    let output = controlled.process(&input, &[256]);
+
+    let mut reference = harness(0, SR, 256);
+    reference.set_parameter("drive", 0.6);
+    assert_eq!(
+        max_difference(&output, &reference.process(&input, &[256])),
+        0.0
+    );
+    let edits = controlled.plugin().param_edits();
+    edits.drain(|_, _| panic!("CC value should be dropped by automation"));
+
+    // 다음 CC는 다시 적용
+    controlled.plugin_mut().handle_midi(cc(0.9));
+    controlled.process(&input, &[256]);
+    controlled.report_edits(&edits);
+    assert_eq!(controlled.parameter("drive"), 0.9);
+}
+
+#[test]
+fn test_preset_selection_pushes_fields() {
+    let mut harness = harness(0, SR, 256);
+    let input = program(2, SR, 256);
+    harness.set_parameter("drive", 0.0);
+
+    // Vinyl 선택: 다음 버퍼부터 프리셋 값으로 처리되고, 보고 후 파라미터에 반영
+    harness.set_parameter("preset", 0.0);
+    harness.process(&input, &[256]);
+    let edits = harness.plugin().param_edits();
+    harness.report_edits(&edits);
+    // WarmTapeParams::preset_fields() 순서의 파라미터 ID
+    let ids = [
+        "drive",
+        "warmth",
+        "drywet",
+        "lowpass_hz",
+        "lowpass_q",
+        "limiter_ceiling",
+        "limiter_release",
+    ];
+    let params = WarmTapeParams::default();
+    let values = preset_values(&ChainPreset::vinyl());
+    for ((id, param), value) in ids.into_iter().zip(params.preset_fields()).zip(values) {
+        let expected = param.preview_normalized(value);
+        assert!((harness.parameter(id) - expected).abs() < 1e-4, "{id}");
+    }
+
+    // 같은 프리셋을 다시 처리해도 보내지 않음 (세션 값 유지)
+    harness.set_parameter("drive", 0.0);
+    harness.process(&input, &[256]);
+    edits.drain(|_, _| panic!("preset was not changed"));
+    assert_eq!(harness.parameter("drive"), 0.0);
+}
+
+#[test]
+fn test_automation_overrides_unreported_preset() {
+    // 에디터를 열지 않은 세션: 프리셋 값이 보고되지 않은 채 남아 있음
+    let input = program(2, SR, 256);
+    let render = |drive: Option<f32>| {
+        let mut harness = harness(0, SR, 256);
+        harness.set_parameter("preset", 0.0);
+        harness.process(&input, &[256]);
+        if let Some(drive) = drive {
+            harness.set_parameter("drive", drive);
+        }
+        let output = harness.process(&input, &[256]);
+        (harness, output)
+    };
+    let (mut automated, output) = render(Some(1.0));
+    let (_, held) = render(None);
+    assert!(max_difference(&output, &held) > 1e-3);
+
+    // 자동화된 필드만 버려지고 나머지 프리셋 필드는 그대로 보고
+    let edits = automated.plugin().param_edits();
+    automated.report_edits(&edits);
+    assert_eq!(automated.parameter("drive"), 1.0);
+    let vinyl = ChainPreset::vinyl();
+    let warmth = WarmTapeParams::default()
+        .warmth
+        .preview_normalized(vinyl.hardness);
+    assert!((automated.parameter("warmth") - warmth).abs() < 1e-4);
+}
+
+#[test]
+fn test_unreported_preset_survives_session_reload() {
+    let input = program(2, SR, 256);
+    let mut session = harness(0, SR, 256);
+    session.set_parameter("preset", 0.0);
+    session.process(&input, &[256]);
+    let state = session.plugin().params().serialize_fields();
+
+    // 호스트 복원 순서: 파라미터 값 -> 저장 필드 -> initialize
+    let mut restored = Harness::new(WarmTapePlugin::default(), |plugin, buffer, _| {
+        plugin.render(buffer)
+    });
+    restored.set_parameter("preset", 0.0);
+    restored.plugin().params().deserialize_fields(&state);
+    let layout = &WarmTapePlugin::AUDIO_IO_LAYOUTS[0];
+    assert!(restored.initialize(layout, SR, 256, ProcessMode::Realtime));
+    restored.process(&input, &[256]);
+
+    let edits = restored.plugin().param_edits();
+    restored.report_edits(&edits);
+    let vinyl = ChainPreset::vinyl();
+    let drive = WarmTapeParams::default()
+        .drive
+        .preview_normalized(vinyl.drive_db);
+    assert!((restored.parameter("drive") - drive).abs() < 1e-4);
+}
+
+#[test]
+fn test_user_preset_slot_change_pushes_fields() {
+    let mut harness = harness(0, SR, 256);
+    let input = program(2, SR, 256);
+    harness.set_parameter("preset", 1.0);
+    harness.process(&input, &[256]);
+
+    // 에디터에서 사용자 프리셋 파일 선택 (User 선택 중 슬롯이 바뀌면 필드를 보냄)
+    let chain = ChainPreset {
+        drive_db: 7.5,
+        ..ChainPreset::vinyl()
+    };
+    let slot = harness.plugin().user_preset();
+    *slot.write().unwrap() = Some(PresetFile::new("Dusty", chain));
+    harness.process(&input, &[256]);
+    let edits = harness.plugin().param_edits();
+    harness.report_edits(&edits);
+    let expected = WarmTapeParams::default()
+        .drive
+        .preview_normalized(chain.drive_db);
+    assert!((harness.parameter("drive") - expected).abs() < 1e-4);
+}
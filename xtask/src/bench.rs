@@ -0,0 +1,243 @@
+//! criterion 결과 수집 + 기준선 비교
+//! 벤치마크 ID `<이름>/<샘플레이트>/<블록 크기>`에서 ns/sample과 실시간 배율을 계산
+
+use serde_json::Value;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 기준선 대비 이 비율 이상 느려지면 회귀
+pub const DEFAULT_THRESHOLD: f64 = 0.10;
+
+/// 벤치마크 하나의 평균 시간
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchResult {
+    pub id: String,
+    /// 반복(블록) 한 번의 평균 시간
+    pub mean_ns: f64,
+}
+
+impl BenchResult {
+    /// ID 끝의 `<샘플레이트>/<블록 크기>`
+    fn rate_and_block(&self) -> Option<(f64, f64)> {
+        let mut parts = self.id.rsplit('/');
+        let block = parts.next()?.parse::<f64>().ok()?;
+        let sample_rate = parts.next()?.parse::<f64>().ok()?;
+        (block > 0.0 && sample_rate > 0.0).then_some((sample_rate, block))
+    }
+
+    pub fn ns_per_sample(&self) -> Option<f64> {
+        self.rate_and_block().map(|(_, block)| self.mean_ns / block)
+    }
+
+    /// 블록 길이(오디오 시간) / 처리 시간 (1.0 미만이면 실시간 불가)
+    pub fn realtime_factor(&self) -> Option<f64> {
+        self.rate_and_block()
+            .map(|(sample_rate, block)| block / sample_rate * 1e9 / self.mean_ns)
+    }
+}
+
+/// 기준선과 현재 결과 비교
+#[derive(Debug)]
+pub struct Comparison {
+    pub current: BenchResult,
+    pub baseline_ns: Option<f64>,
+}
+
+impl Comparison {
+    /// 시간 변화율 (+0.1 = 10% 느려짐)
+    pub fn change(&self) -> Option<f64> {
+        self.baseline_ns
+            .map(|baseline| self.current.mean_ns / baseline - 1.0)
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+/// target/criterion 아래의 최신(new) 결과를 ID 순으로 수집
+pub fn collect(criterion_dir: &Path) -> Result<Vec<BenchResult>, String> {
+    let mut results = Vec::new();
+    visit(criterion_dir, &mut results)?;
+    results.sort_by(|a, b| sort_key(&a.id).cmp(&sort_key(&b.id)));
+    Ok(results)
+}
+
+/// 숫자 구간은 숫자로 비교 (64 < 512 < 2048)
+fn sort_key(id: &str) -> Vec<Result<u64, &str>> {
+    id.split('/')
+        .map(|part| part.parse().map_err(|_| part))
+        .collect()
+}
+
+fn visit(dir: &Path, results: &mut Vec<BenchResult>) -> Result<(), String> {
+    let entries = fs::read_dir(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let new = path.join("new");
+        if new.join("benchmark.json").is_file() {
+            results.push(read_result(&new)?);
+        } else if path.file_name().is_some_and(|name| name != "report") {
+            visit(&path, results)?;
+        }
+    }
+    Ok(())
+}
+
+fn read_result(dir: &Path) -> Result<BenchResult, String> {
+    let read = |name: &str| -> Result<Value, String> {
+        let path = dir.join(name);
+        let text = fs::read_to_string(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+        serde_json::from_str(&text).map_err(|err| format!("{}: {err}", path.display()))
+    };
+    let benchmark = read("benchmark.json")?;
+    let estimates = read("estimates.json")?;
+    let id = benchmark["full_id"]
+        .as_str()
+        .ok_or_else(|| format!("{}: missing full_id", dir.display()))?;
+    let mean_ns = estimates["mean"]["point_estimate"]
+        .as_f64()
+        .ok_or_else(|| format!("{}: missing mean estimate", dir.display()))?;
+    Ok(BenchResult {
+        id: id.to_string(),
+        mean_ns,
+    })
+}
+
+/// 기준선 파일: 한 줄에 `<ID>\t<평균 ns>`, '#' 주석
+pub fn load_baseline(path: &Path) -> Result<Vec<BenchResult>, String> {
+    let text = fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (id, mean_ns) = line
+                .split_once('\t')
+                .ok_or_else(|| format!("{}: malformed line '{line}'", path.display()))?;
+            let mean_ns = mean_ns
+                .trim()
+                .parse()
+                .map_err(|_| format!("{}: bad time in '{line}'", path.display()))?;
+            Ok(BenchResult {
+                id: id.to_string(),
+                mean_ns,
+            })
+        })
+        .collect()
+}
+
+pub fn save_baseline(path: &Path, results: &[BenchResult]) -> Result<(), String> {
+    let mut text = String::from("# cargo xtask bench-compare --save\n# id\tmean_ns\n");
+    for result in results {
+        let _ = writeln!(text, "{}\t{:.1}", result.id, result.mean_ns);
+    }
+    fs::write(path, text).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+pub fn compare(current: &[BenchResult], baseline: &[BenchResult]) -> Vec<Comparison> {
+    current
+        .iter()
+        .map(|result| Comparison {
+            current: result.clone(),
+            baseline_ns: baseline
+                .iter()
+                .find(|base| base.id == result.id)
+                .map(|base| base.mean_ns),
+        })
+        .collect()
+}
+
+/// 비교 표 (ns/sample, 실시간 배율, 기준선 대비 변화)
+pub fn report(comparisons: &[Comparison], threshold: f64) -> String {
+    let width = comparisons
+        .iter()
+        .map(|c| c.current.id.len())
+        .max()
+        .unwrap_or(0)
+        .max("benchmark".len());
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:<width$}  {:>12}  {:>10}  {:>10}  {:>8}",
+        "benchmark", "time", "ns/sample", "realtime", "change"
+    );
+    for comparison in comparisons {
+        let result = &comparison.current;
+        let optional = |value: Option<f64>, format: fn(f64) -> String| {
+            value.map_or_else(|| "-".to_string(), format)
+        };
+        let change = optional(comparison.change(), |c| format!("{:+.1}%", c * 100.0));
+        let flag = if comparison.is_regression(threshold) {
+            "  REGRESSION"
+        } else if comparison.baseline_ns.is_none() {
+            "  (new)"
+        } else {
+            ""
+        };
+        let _ = writeln!(
+            out,
+            "{:<width$}  {:>12}  {:>10}  {:>10}  {:>8}{flag}",
+            result.id,
+            format_time(result.mean_ns),
+            optional(result.ns_per_sample(), |ns| format!("{ns:.2}")),
+            optional(result.realtime_factor(), |rtf| format!("{rtf:.0}x")),
+            change,
+        );
+    }
+    out
+}
+
+fn format_time(ns: f64) -> String {
+    match ns {
+        ns if ns >= 1e6 => format!("{:.2} ms", ns / 1e6),
+        ns if ns >= 1e3 => format!("{:.2} µs", ns / 1e3),
+        ns => format!("{ns:.1} ns"),
+    }
+}
+
+/// criterion 출력 디렉터리
+pub fn criterion_dir(workspace: &Path) -> PathBuf {
+    crate::target_dir(workspace).join("criterion")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(id: &str, mean_ns: f64) -> BenchResult {
+        BenchResult {
+            id: id.to_string(),
+            mean_ns,
+        }
+    }
+
+    #[test]
+    fn test_per_sample_metrics_from_id() {
+        let riaa = result("riaa_f32/48000/512", 5120.0);
+        assert_eq!(riaa.ns_per_sample(), Some(10.0));
+        // 512 / 48000 s = 10.67 ms 오디오를 5.12 µs에 처리
+        assert!((riaa.realtime_factor().unwrap() - 2083.3).abs() < 0.1);
+        assert_eq!(result("riaa_4ch/simd", 100.0).ns_per_sample(), None);
+    }
+
+    #[test]
+    fn test_compare_flags_regressions() {
+        let baseline = [result("a/48000/64", 100.0), result("b/48000/64", 100.0)];
+        let current = [
+            result("a/48000/64", 105.0),
+            result("b/48000/64", 130.0),
+            result("c/48000/64", 1.0),
+        ];
+        let comparisons = compare(&current, &baseline);
+        let regressed: Vec<bool> = comparisons
+            .iter()
+            .map(|c| c.is_regression(DEFAULT_THRESHOLD))
+            .collect();
+        assert_eq!(regressed, [false, true, false]);
+        assert!(report(&comparisons, DEFAULT_THRESHOLD).contains("(new)"));
+    }
+}
@@ -0,0 +1,242 @@
+//! CLAP / VST3 번들 생성 (nih_plug_xtask와 같은 디렉터리 구조)
+//! - Linux / Windows CLAP: 공유 라이브러리 한 파일 (`<이름>.clap`)
+//! - VST3: `<이름>.vst3/Contents/<아키텍처>-<OS>/<이름>.{so,vst3}`
+//! - macOS: 두 형식 모두 `Contents/MacOS/<이름>` + Info.plist + PkgInfo 번들
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 번들 가능한 플러그인
+pub struct Plugin {
+    pub package: &'static str,
+    /// 번들 / 바이너리 이름
+    pub name: &'static str,
+    /// macOS 번들 식별자 (플러그인의 CLAP_ID)
+    pub id: &'static str,
+}
+
+pub const PLUGINS: &[Plugin] = &[
+    Plugin {
+        package: "rustrover_ai_plugin",
+        name: "Rustrover AI",
+        id: "com.rustrover.ai-patent-2026",
+    },
+    Plugin {
+        package: "warm_tape_plugin",
+        name: "Warm Tape",
+        id: "com.rustrover.warm-tape",
+    },
+];
+
+pub fn find(package: &str) -> Option<&'static Plugin> {
+    PLUGINS.iter().find(|plugin| plugin.package == package)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Os {
+    Linux,
+    Macos,
+    Windows,
+}
+
+impl Os {
+    pub fn host() -> Result<Self, String> {
+        match std::env::consts::OS {
+            "linux" => Ok(Os::Linux),
+            "macos" => Ok(Os::Macos),
+            "windows" => Ok(Os::Windows),
+            other => Err(format!("bundling is not supported on {other}")),
+        }
+    }
+
+    /// cargo가 만드는 cdylib 파일 이름
+    pub fn library_name(self, crate_name: &str) -> String {
+        match self {
+            Os::Linux => format!("lib{crate_name}.so"),
+            Os::Macos => format!("lib{crate_name}.dylib"),
+            Os::Windows => format!("{crate_name}.dll"),
+        }
+    }
+}
+
+/// 번들 하나: 최상위 경로와 그 안의 바이너리 경로 (bundled 디렉터리 기준)
+#[derive(Debug, PartialEq)]
+pub struct Layout {
+    pub bundle: PathBuf,
+    pub binary: PathBuf,
+    /// macOS 번들 (Info.plist / PkgInfo 필요)
+    pub macos: bool,
+}
+
+pub fn clap_layout(name: &str, os: Os) -> Layout {
+    let bundle = PathBuf::from(format!("{name}.clap"));
+    match os {
+        Os::Macos => Layout {
+            binary: bundle.join("Contents/MacOS").join(name),
+            bundle,
+            macos: true,
+        },
+        Os::Linux | Os::Windows => Layout {
+            binary: bundle.clone(),
+            bundle,
+            macos: false,
+        },
+    }
+}
+
+/// arch: std::env::consts::ARCH 값
+pub fn vst3_layout(name: &str, os: Os, arch: &str) -> Layout {
+    let bundle = PathBuf::from(format!("{name}.vst3"));
+    let contents = bundle.join("Contents");
+    let (binary, macos) = match os {
+        Os::Linux => (
+            contents
+                .join(format!("{arch}-linux"))
+                .join(format!("{name}.so")),
+            false,
+        ),
+        Os::Windows => {
+            // VST3 SDK 아키텍처 이름: x86_64-win, arm64-win
+            let arch = if arch == "aarch64" { "arm64" } else { arch };
+            (
+                contents
+                    .join(format!("{arch}-win"))
+                    .join(format!("{name}.vst3")),
+                false,
+            )
+        }
+        Os::Macos => (contents.join("MacOS").join(name), true),
+    };
+    Layout {
+        bundle,
+        binary,
+        macos,
+    }
+}
+
+/// 빌드된 라이브러리를 bundled 디렉터리에 CLAP / VST3 번들로 복사
+pub fn create(
+    library: &Path,
+    bundled: &Path,
+    plugin: &Plugin,
+    version: &str,
+    os: Os,
+) -> Result<Vec<PathBuf>, String> {
+    let layouts = [
+        clap_layout(plugin.name, os),
+        vst3_layout(plugin.name, os, std::env::consts::ARCH),
+    ];
+    let mut created = Vec::new();
+    for layout in layouts {
+        let bundle = bundled.join(&layout.bundle);
+        // 이전 번들 제거 (파일 또는 디렉터리)
+        if bundle.is_dir() {
+            fs::remove_dir_all(&bundle).map_err(|err| format!("{}: {err}", bundle.display()))?;
+        } else if bundle.exists() {
+            fs::remove_file(&bundle).map_err(|err| format!("{}: {err}", bundle.display()))?;
+        }
+
+        let binary = bundled.join(&layout.binary);
+        if let Some(parent) = binary.parent() {
+            fs::create_dir_all(parent).map_err(|err| format!("{}: {err}", parent.display()))?;
+        }
+        fs::copy(library, &binary).map_err(|err| {
+            format!(
+                "copying {} to {}: {err}",
+                library.display(),
+                binary.display()
+            )
+        })?;
+        if layout.macos {
+            let contents = bundle.join("Contents");
+            let write = |file: &str, text: String| {
+                let path = contents.join(file);
+                fs::write(&path, text).map_err(|err| format!("{}: {err}", path.display()))
+            };
+            write("Info.plist", info_plist(plugin, version))?;
+            write("PkgInfo", "BNDL????".to_string())?;
+        }
+        created.push(bundle);
+    }
+    Ok(created)
+}
+
+fn info_plist(plugin: &Plugin, version: &str) -> String {
+    let (name, identifier) = (plugin.name, plugin.id);
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>CFBundleExecutable</key>
+    <string>{name}</string>
+    <key>CFBundleIdentifier</key>
+    <string>{identifier}</string>
+    <key>CFBundleName</key>
+    <string>{name}</string>
+    <key>CFBundlePackageType</key>
+    <string>BNDL</string>
+    <key>CFBundleSignature</key>
+    <string>????</string>
+    <key>CFBundleShortVersionString</key>
+    <string>{version}</string>
+    <key>CFBundleVersion</key>
+    <string>{version}</string>
+</dict>
+</plist>
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layouts_match_nih_plug_xtask() {
+        let name = "Warm Tape";
+        assert_eq!(
+            clap_layout(name, Os::Linux).binary,
+            PathBuf::from("Warm Tape.clap")
+        );
+        assert_eq!(
+            clap_layout(name, Os::Macos).binary,
+            PathBuf::from("Warm Tape.clap/Contents/MacOS/Warm Tape")
+        );
+        assert_eq!(
+            vst3_layout(name, Os::Linux, "x86_64").binary,
+            PathBuf::from("Warm Tape.vst3/Contents/x86_64-linux/Warm Tape.so")
+        );
+        assert_eq!(
+            vst3_layout(name, Os::Windows, "aarch64").binary,
+            PathBuf::from("Warm Tape.vst3/Contents/arm64-win/Warm Tape.vst3")
+        );
+        assert!(vst3_layout(name, Os::Macos, "aarch64").macos);
+    }
+
+    #[test]
+    fn test_create_copies_library_into_bundles() {
+        let dir = std::env::temp_dir().join(format!("xtask-bundle-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let library = dir.join("libwarm_tape_plugin.so");
+        fs::write(&library, b"binary").unwrap();
+        let bundled = dir.join("bundled");
+
+        for os in [Os::Linux, Os::Macos] {
+            // 두 번 실행해도 (기존 번들 교체) 같은 결과
+            for _ in 0..2 {
+                let plugin = find("warm_tape_plugin").unwrap();
+                let created = create(&library, &bundled, plugin, "0.1.0", os).unwrap();
+                assert_eq!(created.len(), 2);
+            }
+            let clap = bundled.join(clap_layout("Warm Tape", os).binary);
+            assert_eq!(fs::read(clap).unwrap(), b"binary");
+        }
+        let plist = bundled.join("Warm Tape.vst3/Contents/Info.plist");
+        let plist = fs::read_to_string(plist).unwrap();
+        assert!(plist.contains("<string>com.rustrover.warm-tape</string>"));
+        assert!(plist.contains("<string>0.1.0</string>"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
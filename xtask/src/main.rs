@@ -1,3 +1,352 @@
-fn main() {
-    println!("xtask build system");
+//! 개발용 작업 (`cargo xtask <명령>`)
+
+mod bench;
+mod bundle;
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+
+const USAGE: &str = "\
+usage:
+  cargo xtask bundle <plugin>... [--release]
+      CLAP / VST3 번들 생성 (target/bundled, nih_plug_xtask와 같은 구조)
+      플러그인: rustrover_ai_plugin, warm_tape_plugin
+  cargo xtask test-all [--embedded]
+      CI와 같은 검사: 워크스페이스 빌드 / clippy / 테스트, dsp-core 기능 조합, no_std 빌드
+      --embedded   Cortex-M 타깃 no_std 빌드 포함 (rustup target add 필요)
+  cargo xtask bench [bench-compare 옵션] [-- <criterion 인자>]
+      cargo bench 실행 후 기준선과 비교
+  cargo xtask bench-compare [--baseline FILE] [--threshold PCT] [--save]
+      criterion 결과(target/criterion)를 기준선과 비교, 느려진 항목이 있으면 실패
+      --baseline   기준선 파일 (기본: bench_baseline.tsv)
+      --threshold  회귀 판정 비율 % (기본: 10)
+      --save       현재 결과를 기준선으로 저장
+  cargo xtask golden [--bless]
+      골든 파일 회귀 테스트 실행 (dsp-core/tests/golden/*.wav)
+      --bless      소리를 의도적으로 바꾼 경우: 현재 출력으로 골든 파일을 다시 생성
+  cargo xtask validate [--release]
+      모든 플러그인을 헤드리스 하네스로 검사 (각 플러그인의 tests/headless.rs)";
+
+/// CI embedded 잡과 같은 타깃 (FPU 있는 Cortex-M4F/M7, FPU 없는 Cortex-M3)
+const EMBEDDED_TARGETS: [&str; 2] = ["thumbv7em-none-eabihf", "thumbv7m-none-eabi"];
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let result = match args.next().as_deref() {
+        Some("bundle") => bundle(args),
+        Some("test-all") => test_all(args),
+        Some("bench") => bench(args),
+        Some("bench-compare") => CompareOptions::parse(args).and_then(bench_compare),
+        Some("golden") => golden(args),
+        Some("validate") => validate(args),
+        None | Some("help" | "-h" | "--help") => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        Some(other) => Err(format!("unknown command '{other}'\n\n{USAGE}")),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// 워크스페이스 루트 (xtask 크레이트의 상위 디렉터리)
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives in the workspace root")
+}
+
+/// 빌드 출력 디렉터리 (CARGO_TARGET_DIR 존중)
+fn target_dir(root: &Path) -> PathBuf {
+    std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| root.join("target"))
+}
+
+fn bundle(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut release = false;
+    let mut plugins = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--release" => release = true,
+            other if other.starts_with('-') => return Err(format!("unknown argument '{other}'")),
+            package => plugins.push(bundle::find(package).ok_or_else(|| {
+                let known: Vec<_> = bundle::PLUGINS.iter().map(|p| p.package).collect();
+                format!(
+                    "unknown plugin '{package}' (expected one of: {})",
+                    known.join(", ")
+                )
+            })?),
+        }
+    }
+    if plugins.is_empty() {
+        return Err(format!("bundle expects at least one plugin\n\n{USAGE}"));
+    }
+
+    let root = workspace_root();
+    let os = bundle::Os::host()?;
+    let profile = if release { "release" } else { "debug" };
+    let bundled = target_dir(root).join("bundled");
+    for plugin in plugins {
+        let mut command = cargo();
+        command.args(["build", "-p", plugin.package]);
+        if release {
+            command.arg("--release");
+        }
+        run(&mut command)?;
+
+        let library = target_dir(root)
+            .join(profile)
+            .join(os.library_name(&plugin.package.replace('-', "_")));
+        let version = package_version(&root.join(plugin.package).join("Cargo.toml"))?;
+        for created in bundle::create(&library, &bundled, plugin, &version, os)? {
+            println!("created {}", created.display());
+        }
+    }
+    Ok(())
+}
+
+/// Cargo.toml [package] 의 version (xtask는 toml 파서 없이 첫 version 줄만 읽음)
+fn package_version(manifest: &Path) -> Result<String, String> {
+    let text = std::fs::read_to_string(manifest)
+        .map_err(|err| format!("{}: {err}", manifest.display()))?;
+    text.lines()
+        .filter_map(|line| line.trim().strip_prefix("version"))
+        .filter_map(|rest| rest.trim_start().strip_prefix('='))
+        .map(|value| value.trim().trim_matches('"').to_string())
+        .next()
+        .ok_or_else(|| format!("{}: no package version", manifest.display()))
+}
+
+fn test_all(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut embedded = false;
+    for arg in args {
+        match arg.as_str() {
+            "--embedded" => embedded = true,
+            other => return Err(format!("unknown argument '{other}'")),
+        }
+    }
+
+    let no_std = ["-p", "dsp-core", "--no-default-features", "--features"];
+    let mut steps: Vec<(String, Vec<&str>)> = vec![
+        ("build".into(), vec!["build", "--workspace"]),
+        (
+            "clippy".into(),
+            vec![
+                "clippy",
+                "--workspace",
+                "--all-targets",
+                "--",
+                "-D",
+                "warnings",
+            ],
+        ),
+        ("test".into(), vec!["test", "--workspace"]),
+        (
+            "dsp-core fixed-point + preset-files".into(),
+            vec![
+                "test",
+                "-p",
+                "dsp-core",
+                "--features",
+                "fixed-point,preset-files",
+            ],
+        ),
+        (
+            "dsp-core no_std".into(),
+            [&["build"][..], &no_std, &["no_std"]].concat(),
+        ),
+        (
+            "dsp-core no_std + fixed-point".into(),
+            [&["build"][..], &no_std, &["no_std,fixed-point"]].concat(),
+        ),
+    ];
+    if embedded {
+        for target in EMBEDDED_TARGETS {
+            steps.push((
+                format!("dsp-core {target}"),
+                [
+                    &["build"][..],
+                    &no_std,
+                    &["no_std,fixed-point", "--release", "--target", target],
+                ]
+                .concat(),
+            ));
+        }
+    }
+
+    for (name, args) in &steps {
+        println!("==> {name}");
+        run(cargo().args(args))?;
+    }
+    println!("all {} checks passed", steps.len());
+    Ok(())
+}
+
+fn bench(args: impl Iterator<Item = String>) -> Result<(), String> {
+    // `--` 뒤는 criterion 인자 (벤치 필터 등)
+    let args: Vec<String> = args.collect();
+    let (compare, criterion) = match args.iter().position(|arg| arg == "--") {
+        Some(split) => (&args[..split], &args[split..]),
+        None => (&args[..], &[][..]),
+    };
+    let options = CompareOptions::parse(compare.iter().cloned())?;
+
+    let mut command = cargo();
+    command.args(["bench", "--workspace"]).args(criterion);
+    run(&mut command)?;
+    bench_compare(options)
+}
+
+struct CompareOptions {
+    baseline_path: PathBuf,
+    threshold: f64,
+    save: bool,
+}
+
+impl CompareOptions {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self {
+            baseline_path: workspace_root().join("bench_baseline.tsv"),
+            threshold: bench::DEFAULT_THRESHOLD,
+            save: false,
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{arg} expects a value"));
+            match arg.as_str() {
+                "--baseline" => options.baseline_path = PathBuf::from(value()?),
+                "--threshold" => {
+                    let raw = value()?;
+                    let percent: f64 = raw
+                        .parse()
+                        .map_err(|_| format!("--threshold expects a percentage, got '{raw}'"))?;
+                    options.threshold = percent / 100.0;
+                }
+                "--save" => options.save = true,
+                other => return Err(format!("unknown argument '{other}'")),
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn bench_compare(options: CompareOptions) -> Result<(), String> {
+    let CompareOptions {
+        baseline_path,
+        threshold,
+        save,
+    } = options;
+    let root = workspace_root();
+    let criterion_dir = bench::criterion_dir(root);
+    let current =
+        bench::collect(&criterion_dir).map_err(|err| format!("{err}\nrun `cargo bench` first"))?;
+    if current.is_empty() {
+        return Err(format!(
+            "no benchmark results in {}",
+            criterion_dir.display()
+        ));
+    }
+    if save {
+        bench::save_baseline(&baseline_path, &current)?;
+        println!(
+            "saved {} results to {}",
+            current.len(),
+            baseline_path.display()
+        );
+        return Ok(());
+    }
+
+    let baseline = if baseline_path.is_file() {
+        bench::load_baseline(&baseline_path)?
+    } else {
+        eprintln!(
+            "warning: no baseline at {} (create one with --save)",
+            baseline_path.display()
+        );
+        Vec::new()
+    };
+    let comparisons = bench::compare(&current, &baseline);
+    let report = bench::report(&comparisons, threshold);
+    print!("{report}");
+    let output = root.join("bench_output.txt");
+    std::fs::write(&output, &report).map_err(|err| format!("{}: {err}", output.display()))?;
+
+    let regressions = comparisons
+        .iter()
+        .filter(|comparison| comparison.is_regression(threshold))
+        .count();
+    if regressions > 0 {
+        return Err(format!(
+            "{regressions} benchmark(s) regressed more than {:.0}%",
+            threshold * 100.0
+        ));
+    }
+    Ok(())
+}
+
+fn validate(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut release = false;
+    for arg in args {
+        match arg.as_str() {
+            "--release" => release = true,
+            other => return Err(format!("unknown argument '{other}'")),
+        }
+    }
+
+    let mut command = cargo();
+    command.arg("test");
+    for plugin in bundle::PLUGINS {
+        command.args(["-p", plugin.package]);
+    }
+    command.args(["--test", "headless"]);
+    if release {
+        command.arg("--release");
+    }
+    run(&mut command)?;
+    println!("validated {} plugins", bundle::PLUGINS.len());
+    Ok(())
+}
+
+fn golden(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut bless = false;
+    for arg in args {
+        match arg.as_str() {
+            "--bless" => bless = true,
+            other => return Err(format!("unknown argument '{other}'")),
+        }
+    }
+
+    let mut command = cargo();
+    command.args(["test", "-p", "dsp-core", "--test", "golden_tests"]);
+    if bless {
+        command.env("GOLDEN_BLESS", "1").args(["--", "--nocapture"]);
+    }
+    run(&mut command)?;
+    if bless {
+        println!("golden files updated: review `git diff --stat dsp-core/tests/golden` before committing");
+    }
+    Ok(())
+}
+
+/// xtask를 실행한 cargo (없으면 PATH의 cargo)
+fn cargo() -> Command {
+    let mut command = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(workspace_root());
+    command
+}
+
+fn run(command: &mut Command) -> Result<(), String> {
+    let status = command
+        .status()
+        .map_err(|err| format!("failed to run {command:?}: {err}"))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("{command:?} failed ({status})"))
+    }
 }